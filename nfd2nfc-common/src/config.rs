@@ -1,29 +1,201 @@
 use crate::constants::CONFIG_PATH;
+use crate::ignore::{is_glob_pattern, segment_matches};
 use crate::utils::expand_tilde;
 use log::{debug, error, info, warn};
+use nfd2nfc_core::normalizer::{CollisionStrategy, NormalizationTarget};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 use toml;
 
+/// How the TUI's directory browser orders entries, persisted across
+/// restarts. Mirrors yazi's `config/manager/sorting`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrowserSortMode {
+    Name,
+    ModifiedTime,
+    Size,
+    /// Groups NFD/Mixed names together so everything needing conversion is
+    /// visible at a glance.
+    UnicodeForm,
+}
+
+impl Default for BrowserSortMode {
+    fn default() -> Self {
+        BrowserSortMode::Name
+    }
+}
+
+impl BrowserSortMode {
+    pub fn cycle(&self) -> Self {
+        match self {
+            BrowserSortMode::Name => BrowserSortMode::ModifiedTime,
+            BrowserSortMode::ModifiedTime => BrowserSortMode::Size,
+            BrowserSortMode::Size => BrowserSortMode::UnicodeForm,
+            BrowserSortMode::UnicodeForm => BrowserSortMode::Name,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BrowserSortMode::Name => "Name",
+            BrowserSortMode::ModifiedTime => "Modified",
+            BrowserSortMode::Size => "Size",
+            BrowserSortMode::UnicodeForm => "Unicode",
+        }
+    }
+}
+
+/// A single directory bookmark: the label key a user pressed to save it,
+/// and the path it points to.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BookmarkEntry {
+    pub key: char,
+    pub path: String,
+}
+
+/// A path that is poll-watched rather than watched via native FS events, as
+/// stored in the config file, along with its scan interval in seconds.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollWatchEntry {
+    pub path: String,
+    pub interval_secs: u64,
+}
+
+/// A poll-watched path refined to a canonical location and its scan
+/// interval. Used for network shares and other filesystems where FSEvents
+/// delivers no reliable notifications.
+#[derive(Debug, Clone)]
+pub struct PollWatchPath {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+/// A watch path as carried by the refined `Config`. `unresolved` is the
+/// original (trimmed) string from the config file; `resolved` is its
+/// canonical location once `fs::canonicalize` succeeds and it names a
+/// directory. Entries that can't yet be resolved (a removable drive that
+/// isn't mounted, a directory not created yet) are kept with `resolved:
+/// None` instead of being dropped, so `start_watcher` can retry them and
+/// `Config::save_to_file` doesn't silently erase them from the config file.
+#[derive(Debug, Clone)]
+pub struct WatchEntry {
+    pub unresolved: String,
+    pub resolved: Option<PathBuf>,
+}
+
+impl WatchEntry {
+    pub fn is_resolved(&self) -> bool {
+        self.resolved.is_some()
+    }
+}
+
+/// Default for `debounce_ms`, matching the watcher's previous fixed
+/// debounce window.
+fn default_debounce_ms() -> u64 {
+    75
+}
+
+/// Default for `tranquility`: run flat-out, no throttling.
+fn default_tranquility() -> f64 {
+    0.0
+}
+
 /// Raw configuration with unprocessed path strings from the config file.
-#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RawConfig {
+    /// May contain glob patterns (e.g. `~/Projects/*/node_modules`) in
+    /// addition to literal directories; `refine_watch_paths` expands each
+    /// one against the filesystem into the directories it currently
+    /// matches.
     #[serde(default)]
     pub recursive_watch_paths: Vec<String>,
+    /// Same glob support as `recursive_watch_paths`.
     #[serde(default)]
     pub non_recursive_watch_paths: Vec<String>,
     #[serde(default)]
     pub recursive_ignore_paths: Vec<String>,
+    /// Paths rescanned on an interval instead of watched via native FS
+    /// events (for network shares and other FSEvents-blind filesystems).
+    #[serde(default)]
+    pub poll_watch_paths: Vec<PollWatchEntry>,
+    /// Browser tab: how entries are currently sorted.
+    #[serde(default)]
+    pub browser_sort_mode: BrowserSortMode,
+    /// Browser tab: whether `browser_sort_mode` is applied in reverse.
+    #[serde(default)]
+    pub browser_sort_reverse: bool,
+    /// Browser tab: whether dotfiles are shown.
+    #[serde(default)]
+    pub browser_show_hidden: bool,
+    /// Browser tab: directories bookmarked under a single-character label.
+    #[serde(default)]
+    pub browser_bookmarks: Vec<BookmarkEntry>,
+    /// Worker-thread count for the browser's directory-conversion actions.
+    /// `None` falls back to a default derived from the number of logical
+    /// CPUs; `Some(0)` or `Some(1)` runs the conversion sequentially.
+    #[serde(default)]
+    pub normalizer_threads: Option<usize>,
+    /// How the browser's conversion actions handle a converted name that
+    /// already exists as a different file.
+    #[serde(default)]
+    pub collision_strategy: CollisionStrategy,
+    /// Normalization form the watcher daemon converts FSEvents-reported
+    /// names to.
+    #[serde(default)]
+    pub watcher_target: NormalizationTarget,
+    /// How long (in milliseconds) an event must sit quietly -- no newer
+    /// event for the same file -- before the watcher dispatches it for
+    /// normalization. See `nfd2nfc-watcher`'s debounce map.
+    #[serde(default = "default_debounce_ms")]
+    pub debounce_ms: u64,
+    /// How deliberately the watcher throttles itself during a large burst of
+    /// renames: after each batch it sleeps for `tranquility` times however
+    /// long that batch took, trading completion speed for a quieter machine.
+    /// `0.0` (the default) runs flat-out.
+    #[serde(default = "default_tranquility")]
+    pub tranquility: f64,
+}
+
+impl Default for RawConfig {
+    fn default() -> Self {
+        RawConfig {
+            recursive_watch_paths: Vec::new(),
+            non_recursive_watch_paths: Vec::new(),
+            recursive_ignore_paths: Vec::new(),
+            poll_watch_paths: Vec::new(),
+            browser_sort_mode: BrowserSortMode::default(),
+            browser_sort_reverse: false,
+            browser_show_hidden: false,
+            browser_bookmarks: Vec::new(),
+            normalizer_threads: None,
+            collision_strategy: CollisionStrategy::default(),
+            watcher_target: NormalizationTarget::default(),
+            debounce_ms: default_debounce_ms(),
+            tranquility: default_tranquility(),
+        }
+    }
 }
 
 /// Refined configuration with validated and canonical PathBuf entries.
 #[derive(Debug, Clone, Default)]
 pub struct Config {
-    pub recursive_watch_paths: Vec<PathBuf>,
-    pub non_recursive_watch_paths: Vec<PathBuf>,
+    pub recursive_watch_paths: Vec<WatchEntry>,
+    pub non_recursive_watch_paths: Vec<WatchEntry>,
     pub recursive_ignore_paths: Vec<PathBuf>,
+    pub poll_watch_paths: Vec<PollWatchPath>,
+    pub browser_sort_mode: BrowserSortMode,
+    pub browser_sort_reverse: bool,
+    pub browser_show_hidden: bool,
+    pub browser_bookmarks: Vec<BookmarkEntry>,
+    pub normalizer_threads: Option<usize>,
+    pub collision_strategy: CollisionStrategy,
+    pub watcher_target: NormalizationTarget,
+    pub debounce_ms: u64,
+    pub tranquility: f64,
 }
 
 #[derive(Debug, Error)]
@@ -101,22 +273,186 @@ fn remove_duplicates(mut paths: Vec<PathBuf>, section: &str) -> Vec<PathBuf> {
     output
 }
 
-/// Filters out any paths from `paths` that are subpaths of any path in `prefixes`.
-fn filter_by_prefixes(
-    paths: Vec<PathBuf>,
-    prefixes: &Vec<PathBuf>,
+/// Refines a watch-path section into `WatchEntry`s. Unlike
+/// `canonicalize_paths`, an entry that fails to resolve is not dropped: it's
+/// kept as `resolved: None` so it round-trips back out on save and
+/// `start_watcher` can retry it once the target appears. A glob entry that
+/// currently matches nothing is kept the same way, but `start_watcher`'s
+/// periodic retry can't re-expand it (that loop resolves one entry to one
+/// path); it picks up newly matching directories on the next full reload
+/// instead (config-file edit or SIGHUP).
+fn refine_watch_paths(raw_paths: &[String], section: &str) -> Vec<WatchEntry> {
+    let mut resolved: Vec<(PathBuf, String)> = Vec::new();
+    let mut unresolved: Vec<String> = Vec::new();
+
+    for s in raw_paths {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            warn!(" - Removed empty {} path.", section);
+            continue;
+        }
+        if is_glob_pattern(trimmed) {
+            let matches = expand_watch_glob(trimmed);
+            if matches.is_empty() {
+                info!(
+                    " - {} glob '{}' currently matches nothing; will retry on the next reload.",
+                    section, trimmed
+                );
+                unresolved.push(trimmed.to_string());
+            } else {
+                for path in matches {
+                    resolved.push((path, trimmed.to_string()));
+                }
+            }
+            continue;
+        }
+        match process_path(trimmed) {
+            Some(p) => resolved.push((p, trimmed.to_string())),
+            None => {
+                info!(
+                    " - {} path '{}' could not be resolved yet; will retry once it appears.",
+                    section, trimmed
+                );
+                unresolved.push(trimmed.to_string());
+            }
+        }
+    }
+
+    // Dedup/subpath-filter the resolved entries exactly like the old
+    // PathBuf-only pipeline did, keeping each one's original string.
+    resolved.sort_by_key(|(p, _)| p.to_string_lossy().to_string());
+    resolved.dedup_by(|a, b| {
+        if a.0 == b.0 {
+            info!(" - Removed duplicate {} path: {}", section, a.0.display());
+            true
+        } else {
+            false
+        }
+    });
+
+    let mut deduped: Vec<(PathBuf, String)> = Vec::new();
+    for (path, original) in resolved {
+        if let Some((prev, _)) = deduped.last() {
+            if path.starts_with(prev) {
+                warn!(
+                    " - Removed {} path '{}' because it is a subpath of '{}'.",
+                    section,
+                    path.to_string_lossy(),
+                    prev.to_string_lossy()
+                );
+                continue;
+            }
+        }
+        deduped.push((path, original));
+    }
+
+    unresolved.sort();
+    unresolved.dedup();
+
+    let mut entries: Vec<WatchEntry> = deduped
+        .into_iter()
+        .map(|(path, original)| WatchEntry {
+            unresolved: original,
+            resolved: Some(path),
+        })
+        .collect();
+    entries.extend(unresolved.into_iter().map(|original| WatchEntry {
+        unresolved: original,
+        resolved: None,
+    }));
+    entries
+}
+
+/// Collects the resolved `PathBuf`s out of a list of watch entries, for
+/// cross-set prefix comparisons against another section.
+fn resolved_paths(entries: &[WatchEntry]) -> Vec<PathBuf> {
+    entries.iter().filter_map(|e| e.resolved.clone()).collect()
+}
+
+/// Converts a refined watch entry back to the string `RawConfig` stores:
+/// the canonical path if it resolved, or the original unresolved string if
+/// it didn't, so it isn't lost on the next save. A glob entry always saves
+/// back as the pattern itself, even once it has matched a directory, since
+/// flattening it to that one match's canonical path would stop later
+/// reloads from picking up new directories the glob starts matching.
+fn watch_entry_to_string(entry: WatchEntry) -> String {
+    if is_glob_pattern(&entry.unresolved) {
+        return entry.unresolved;
+    }
+    match entry.resolved {
+        Some(path) => path.to_string_lossy().into_owned(),
+        None => entry.unresolved,
+    }
+}
+
+/// Expands a glob watch path (`*` within a single path segment, same
+/// semantics as `ignore::segment_matches`) against the filesystem,
+/// returning every currently-existing directory it matches. A segment
+/// containing `?` or `[` is scanned like a wildcard segment, but those
+/// characters are matched literally against directory names. `**` is treated
+/// as an ordinary single-segment wildcard rather than a recursive descent,
+/// since every watch-glob example so far (`~/Projects/*/node_modules`) only
+/// needs one directory level to vary.
+fn expand_watch_glob(pattern: &str) -> Vec<PathBuf> {
+    let expanded = expand_tilde(pattern);
+    let mut candidates = vec![PathBuf::from(
+        if expanded.is_absolute() { "/" } else { "." },
+    )];
+
+    for component in expanded.components() {
+        let seg = match component {
+            Component::RootDir | Component::CurDir => continue,
+            Component::Normal(os) => os.to_string_lossy().into_owned(),
+            Component::ParentDir => "..".to_string(),
+            Component::Prefix(_) => continue,
+        };
+
+        let mut next = Vec::new();
+        for dir in &candidates {
+            if seg.contains(['*', '?', '[']) {
+                for entry in fs::read_dir(dir).into_iter().flatten().filter_map(|e| e.ok()) {
+                    let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+                    if is_dir && segment_matches(&seg, &entry.file_name().to_string_lossy()) {
+                        next.push(entry.path());
+                    }
+                }
+            } else {
+                let candidate = dir.join(&seg);
+                if candidate.is_dir() {
+                    next.push(candidate);
+                }
+            }
+        }
+        candidates = next;
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|p| fs::canonicalize(&p).ok())
+        .collect()
+}
+
+/// Filters out any watch entries whose resolved path is a subpath of any
+/// path in `prefixes`. Unresolved entries have nothing to compare yet and
+/// are always kept.
+fn filter_watch_entries_by_prefixes(
+    entries: Vec<WatchEntry>,
+    prefixes: &[PathBuf],
     section: &str,
     conflict_with: &str,
-) -> Vec<PathBuf> {
-    paths
+) -> Vec<WatchEntry> {
+    entries
         .into_iter()
-        .filter(|p| {
+        .filter(|entry| {
+            let Some(path) = &entry.resolved else {
+                return true;
+            };
             for prefix in prefixes {
-                if p.starts_with(prefix) {
+                if path.starts_with(prefix) {
                     warn!(
                         " - Removed {} path '{}' as it is a subpath of {} path '{}'.",
                         section,
-                        p.to_string_lossy(),
+                        path.to_string_lossy(),
                         conflict_with,
                         prefix.to_string_lossy()
                     );
@@ -133,28 +469,42 @@ fn filter_by_prefixes(
 impl From<RawConfig> for Config {
     fn from(raw: RawConfig) -> Self {
         // Step 1: Refine each section individually.
-        let rwp = canonicalize_paths(&raw.recursive_watch_paths, "recursive watch");
-        let nrwp = canonicalize_paths(&raw.non_recursive_watch_paths, "non-recursive watch");
-        let rip = canonicalize_paths(&raw.recursive_ignore_paths, "ignore");
-
-        // Step 2: Remove subpaths within each section.
-        let rwp = remove_duplicates(rwp, "recursive watch");
-        let rwp = remove_subpaths(rwp, "recursive watch");
-        let nrwp = remove_duplicates(nrwp, "non-recursive watch");
-        let rip = remove_duplicates(rip, "ignore");
-        let rip = remove_subpaths(rip, "ignore");
-
-        // Step 3: Cross-set filtering.
+        let rwp = refine_watch_paths(&raw.recursive_watch_paths, "recursive watch");
+        let nrwp = refine_watch_paths(&raw.non_recursive_watch_paths, "non-recursive watch");
+        let rip = refine_ignore_paths(&raw.recursive_ignore_paths);
+        let pwp = refine_poll_watch_paths(&raw.poll_watch_paths);
+
+        // Step 2: Cross-set filtering. Only resolved entries can meaningfully
+        // conflict with another section; unresolved entries pass through
+        // untouched and get re-checked once they resolve.
         // For recursive watch paths, remove any that conflict with ignore paths.
-        let rwp = filter_by_prefixes(rwp, &rip, "recursive watch", "ignore");
+        let rwp = filter_watch_entries_by_prefixes(rwp, &rip, "recursive watch", "ignore");
         // For non-recursive watch paths, remove those that conflict with recursive watch or ignore paths.
-        let nrwp = filter_by_prefixes(nrwp, &rwp, "non-recursive watch", "recursive watch");
-        let nrwp = filter_by_prefixes(nrwp, &rip, "non-recursive watch", "ignore");
+        let nrwp = filter_watch_entries_by_prefixes(
+            nrwp,
+            &resolved_paths(&rwp),
+            "non-recursive watch",
+            "recursive watch",
+        );
+        let nrwp = filter_watch_entries_by_prefixes(nrwp, &rip, "non-recursive watch", "ignore");
+        // Poll watch paths are independent of native watch paths, but still
+        // shouldn't poll something explicitly ignored.
+        let pwp = filter_poll_by_prefixes(pwp, &rip);
 
         Config {
             recursive_watch_paths: rwp,
             non_recursive_watch_paths: nrwp,
             recursive_ignore_paths: rip,
+            poll_watch_paths: pwp,
+            browser_sort_mode: raw.browser_sort_mode,
+            browser_sort_reverse: raw.browser_sort_reverse,
+            browser_show_hidden: raw.browser_show_hidden,
+            browser_bookmarks: raw.browser_bookmarks,
+            normalizer_threads: raw.normalizer_threads,
+            collision_strategy: raw.collision_strategy,
+            watcher_target: raw.watcher_target,
+            debounce_ms: raw.debounce_ms,
+            tranquility: raw.tranquility,
         }
     }
 }
@@ -166,18 +516,35 @@ impl From<Config> for RawConfig {
             recursive_watch_paths: config
                 .recursive_watch_paths
                 .into_iter()
-                .map(|p| p.to_string_lossy().into_owned())
+                .map(watch_entry_to_string)
                 .collect(),
             non_recursive_watch_paths: config
                 .non_recursive_watch_paths
                 .into_iter()
-                .map(|p| p.to_string_lossy().into_owned())
+                .map(watch_entry_to_string)
                 .collect(),
             recursive_ignore_paths: config
                 .recursive_ignore_paths
                 .into_iter()
                 .map(|p| p.to_string_lossy().into_owned())
                 .collect(),
+            poll_watch_paths: config
+                .poll_watch_paths
+                .into_iter()
+                .map(|p| PollWatchEntry {
+                    path: p.path.to_string_lossy().into_owned(),
+                    interval_secs: p.interval.as_secs(),
+                })
+                .collect(),
+            browser_sort_mode: config.browser_sort_mode,
+            browser_sort_reverse: config.browser_sort_reverse,
+            browser_show_hidden: config.browser_show_hidden,
+            browser_bookmarks: config.browser_bookmarks,
+            normalizer_threads: config.normalizer_threads,
+            collision_strategy: config.collision_strategy,
+            watcher_target: config.watcher_target,
+            debounce_ms: config.debounce_ms,
+            tranquility: config.tranquility,
         }
     }
 }
@@ -218,6 +585,110 @@ pub fn load_config() -> Result<Config, ConfigError> {
     Ok(config)
 }
 
+/// Refines the ignore section, which may mix literal paths with glob
+/// patterns (see `crate::ignore`). Literal entries are canonicalized,
+/// deduplicated, and have their subpaths removed exactly like the watch
+/// sections; glob entries are passed through verbatim (deduplicated only),
+/// since `fs::canonicalize` would reject them and `starts_with` can't
+/// meaningfully compare one pattern against another.
+fn refine_ignore_paths(raw_paths: &[String]) -> Vec<PathBuf> {
+    let mut literal_strings = Vec::new();
+    let mut patterns = Vec::new();
+
+    for s in raw_paths {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            warn!(" - Removed empty ignore path.");
+            continue;
+        }
+        if is_glob_pattern(trimmed) {
+            patterns.push(trimmed.to_string());
+        } else {
+            literal_strings.push(trimmed.to_string());
+        }
+    }
+
+    let literals = canonicalize_paths(&literal_strings, "ignore");
+    let literals = remove_duplicates(literals, "ignore");
+    let literals = remove_subpaths(literals, "ignore");
+
+    patterns.sort();
+    patterns.dedup();
+
+    let mut refined = literals;
+    refined.extend(patterns.into_iter().map(PathBuf::from));
+    refined
+}
+
+/// Refines the poll watch section: validates and canonicalizes each entry's
+/// path, clamps the interval to a minimum of one second, deduplicates, and
+/// removes subpaths exactly like the native watch sections.
+fn refine_poll_watch_paths(raw_entries: &[PollWatchEntry]) -> Vec<PollWatchPath> {
+    let mut entries: Vec<(PathBuf, u64)> = Vec::new();
+    for entry in raw_entries {
+        let trimmed = entry.path.trim();
+        if trimmed.is_empty() {
+            warn!(" - Removed empty poll watch path.");
+            continue;
+        }
+        match process_path(trimmed) {
+            Some(p) => entries.push((p, entry.interval_secs.max(1))),
+            None => warn!(" - Removed invalid poll watch path: {}", entry.path),
+        }
+    }
+
+    entries.sort_by_key(|(p, _)| p.to_string_lossy().to_string());
+    entries.dedup_by(|a, b| {
+        if a.0 == b.0 {
+            info!(" - Removed duplicate poll watch path: {}", a.0.display());
+            true
+        } else {
+            false
+        }
+    });
+
+    let mut output = Vec::new();
+    let mut refined = Vec::new();
+    for (path, interval_secs) in entries {
+        if let Some(prev) = output.last() {
+            if path.starts_with(prev) {
+                warn!(
+                    " - Removed poll watch path '{}' because it is a subpath of '{}'.",
+                    path.to_string_lossy(),
+                    prev.to_string_lossy()
+                );
+                continue;
+            }
+        }
+        output.push(path.clone());
+        refined.push(PollWatchPath {
+            path,
+            interval: Duration::from_secs(interval_secs),
+        });
+    }
+    refined
+}
+
+/// Filters out any poll watch paths that are subpaths of an ignore entry.
+fn filter_poll_by_prefixes(paths: Vec<PollWatchPath>, prefixes: &[PathBuf]) -> Vec<PollWatchPath> {
+    paths
+        .into_iter()
+        .filter(|p| {
+            for prefix in prefixes {
+                if p.path.starts_with(prefix) {
+                    warn!(
+                        " - Removed poll watch path '{}' as it is a subpath of ignore path '{}'.",
+                        p.path.to_string_lossy(),
+                        prefix.to_string_lossy()
+                    );
+                    return false;
+                }
+            }
+            true
+        })
+        .collect()
+}
+
 /// Refines a list of raw path strings for a given section (e.g., "recursive watch").
 /// Invalid or empty paths are skipped with a warning.
 fn canonicalize_paths(raw_paths: &Vec<String>, section: &str) -> Vec<PathBuf> {
@@ -236,6 +707,13 @@ fn canonicalize_paths(raw_paths: &Vec<String>, section: &str) -> Vec<PathBuf> {
     valid_paths
 }
 
+/// Attempts to resolve a watch entry's original string to a canonical
+/// directory, exactly as done when the config is first loaded. Exposed so
+/// `start_watcher` can retry entries that were left unresolved.
+pub fn resolve_watch_path(unresolved: &str) -> Option<PathBuf> {
+    process_path(unresolved)
+}
+
 /// Converts a path string to its canonical PathBuf.
 /// Returns None if conversion fails.
 fn process_path(path_str: &str) -> Option<PathBuf> {
@@ -27,3 +27,20 @@ pub static HOME_DIR: Lazy<PathBuf> = Lazy::new(|| match dirs::home_dir() {
         process::exit(0);
     }
 });
+
+/// Directory holding the watcher's control-channel FIFOs, so a script or
+/// editor can drive the daemon without the TUI.
+pub static CONTROL_DIR: Lazy<PathBuf> = Lazy::new(|| expand_tilde("~/.config/nfd2nfc/control"));
+
+/// FIFO commands are written to, one per line (`status`, `reload-config`,
+/// `convert <path> <nfc|nfd> <recursive|children|name-only>`, `stop`,
+/// `add-recursive <path>`, `add-exclude <path>`, `remove <path>`, `pause`,
+/// `resume`).
+pub const CONTROL_MSG_IN_FILE: &str = "msg_in";
+/// Plain file rewritten with the latest watcher snapshot after every
+/// change, so a reader can check current status without round-tripping a
+/// command through `msg_in`.
+pub const CONTROL_STATUS_OUT_FILE: &str = "status_out";
+/// FIFO one JSON response line is written to per command read from
+/// `msg_in`.
+pub const CONTROL_RESULT_OUT_FILE: &str = "result_out";
@@ -0,0 +1,131 @@
+//! Gitignore-style matching for `recursive_ignore_paths` entries.
+//!
+//! A pattern is a sequence of `/`-separated segments: `*` matches any run of
+//! characters within a single segment, `**` matches across segment
+//! boundaries (including zero segments), a trailing `/` restricts the rule
+//! to directories, a leading `!` negates an earlier match, and a leading `/`
+//! anchors the pattern to the watch root instead of matching at any depth.
+//! Rules are evaluated in order and the last matching rule wins, so a later
+//! negation can re-include a path an earlier rule excluded.
+//!
+//! Patterns are evaluated relative to whichever watch root contains the
+//! tested path (the longest matching root, for roots nested inside one
+//! another), not the filesystem root, so `/build` ignores `<root>/build`
+//! regardless of where `<root>` itself lives on disk.
+
+use std::path::{Path, PathBuf};
+
+use nfd2nfc_core::glob::segments_match;
+
+/// Re-exported so `config::refine_watch_paths` can reuse the same
+/// single-segment semantics when expanding a glob watch path against the
+/// filesystem, without reaching past this module into `nfd2nfc_core`
+/// directly.
+pub(crate) use nfd2nfc_core::glob::segment_matches;
+
+/// True if `path_str` is a glob pattern (contains `*`, `?`, `[`, `]`, or a
+/// leading `!` negation) rather than a literal, canonicalizable path.
+pub fn is_glob_pattern(path_str: &str) -> bool {
+    let trimmed = path_str.trim();
+    trimmed.starts_with('!') || trimmed.contains(['*', '?', '[', ']'])
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    negate: bool,
+    dir_only: bool,
+    anchored: bool,
+    segments: Vec<String>,
+}
+
+impl Rule {
+    fn parse(pattern: &str) -> Option<Rule> {
+        let mut p = pattern.trim();
+        if p.is_empty() {
+            return None;
+        }
+
+        let negate = if let Some(rest) = p.strip_prefix('!') {
+            p = rest;
+            true
+        } else {
+            false
+        };
+
+        let dir_only = if let Some(rest) = p.strip_suffix('/') {
+            p = rest;
+            true
+        } else {
+            false
+        };
+
+        let anchored = p.starts_with('/');
+        let p = p.trim_start_matches('/');
+        if p.is_empty() {
+            return None;
+        }
+
+        Some(Rule {
+            negate,
+            dir_only,
+            anchored,
+            segments: p.split('/').map(str::to_string).collect(),
+        })
+    }
+
+    fn matches(&self, path_segments: &[&str]) -> bool {
+        if self.anchored {
+            segments_match(&self.segments, path_segments)
+        } else {
+            (0..path_segments.len()).any(|start| segments_match(&self.segments, &path_segments[start..]))
+        }
+    }
+}
+
+/// A compiled set of ignore rules, ready to test event paths against.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreMatcher {
+    rules: Vec<Rule>,
+    /// Watch roots patterns are evaluated relative to, longest first so the
+    /// most specific containing root wins when roots are nested.
+    roots: Vec<PathBuf>,
+}
+
+impl IgnoreMatcher {
+    /// Compiles `patterns` (as stored in `Config::recursive_ignore_paths`,
+    /// literal paths and glob patterns alike) into a matcher that evaluates
+    /// them relative to `roots` (the configured watch paths).
+    pub fn compile(patterns: &[PathBuf], roots: &[PathBuf]) -> Self {
+        let rules = patterns
+            .iter()
+            .filter_map(|p| Rule::parse(&p.to_string_lossy()))
+            .collect();
+        let mut roots = roots.to_vec();
+        roots.sort_by_key(|r| std::cmp::Reverse(r.as_os_str().len()));
+        IgnoreMatcher { rules, roots }
+    }
+
+    /// Returns true if `path` should be ignored, given whether it names a
+    /// directory.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let relative = self.roots.iter().find_map(|root| path.strip_prefix(root).ok());
+        let path_segments: Vec<&str> = match relative {
+            Some(rel) => rel.iter().filter_map(|c| c.to_str()).collect(),
+            None => path.iter().filter_map(|c| c.to_str()).collect(),
+        };
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule.dir_only && !is_dir {
+                continue;
+            }
+            // A literal absolute entry (a canonical directory) also ignores
+            // everything under it, matched against the full path.
+            let literal_prefix = rule.anchored
+                && path.starts_with(Path::new("/").join(rule.segments.join("/")));
+            if literal_prefix || rule.matches(&path_segments) {
+                ignored = !rule.negate;
+            }
+        }
+        ignored
+    }
+}
@@ -27,6 +27,15 @@ pub static HEARTBEAT_PATH: Lazy<PathBuf> = Lazy::new(|| {
         .join("heartbeat")
 });
 
+/// Directory holding per-run rename journals (see [`crate::journal`]), so a
+/// bulk conversion can be reverted later.
+pub static JOURNAL_DIR: Lazy<PathBuf> = Lazy::new(|| {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join("nfd2nfc")
+        .join("journals")
+});
+
 pub static HOME_DIR: Lazy<PathBuf> = Lazy::new(|| match dirs::home_dir() {
     Some(path) => path,
     None => {
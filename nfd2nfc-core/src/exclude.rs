@@ -0,0 +1,138 @@
+//! Glob/gitignore-style exclusion for directory normalization.
+//!
+//! `normalize_directory` walks every entry under a folder; an `ExcludeSet`
+//! lets a caller spare specific files or subtrees (build artifacts, caches,
+//! `.git`) from being renamed. Patterns use the same segment-glob syntax as
+//! a `.gitignore` file: `*` matches any run of characters within a single
+//! path segment, `**` matches across segment boundaries, and a trailing `/`
+//! restricts a pattern to directories.
+
+use std::fs;
+use std::path::Path;
+
+use crate::glob::segments_match;
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    dir_only: bool,
+    segments: Vec<String>,
+}
+
+impl Pattern {
+    fn parse(raw: &str) -> Option<Pattern> {
+        let mut p = raw.trim();
+        if p.is_empty() || p.starts_with('#') {
+            return None;
+        }
+
+        let dir_only = if let Some(rest) = p.strip_suffix('/') {
+            p = rest;
+            true
+        } else {
+            false
+        };
+        let p = p.trim_start_matches('/');
+        if p.is_empty() {
+            return None;
+        }
+
+        Some(Pattern {
+            dir_only,
+            segments: p.split('/').map(str::to_string).collect(),
+        })
+    }
+
+    /// True if this pattern matches `path_segments` anchored at any depth.
+    fn matches(&self, path_segments: &[&str]) -> bool {
+        (0..path_segments.len()).any(|start| segments_match(&self.segments, &path_segments[start..]))
+    }
+}
+
+/// A compiled set of exclusion patterns, ready to test entry paths against
+/// during a `normalize_directory` pass.
+#[derive(Debug, Clone, Default)]
+pub struct ExcludeSet {
+    patterns: Vec<Pattern>,
+}
+
+impl ExcludeSet {
+    /// Compile `patterns` (e.g. `**/node_modules/**`, `*.tmp`) into a set
+    /// ready for matching. Invalid or blank patterns are dropped.
+    pub fn compile<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let patterns = patterns
+            .iter()
+            .filter_map(|p| Pattern::parse(p.as_ref()))
+            .collect();
+        ExcludeSet { patterns }
+    }
+
+    /// Like [`ExcludeSet::compile`], but also folds in the patterns from
+    /// `root`'s `.gitignore`, if one exists, so a normalization run
+    /// automatically spares whatever the repo itself ignores.
+    pub fn with_gitignore<S: AsRef<str>>(root: &Path, patterns: &[S]) -> Self {
+        let mut set = Self::compile(patterns);
+        if let Ok(contents) = fs::read_to_string(root.join(".gitignore")) {
+            set.patterns
+                .extend(contents.lines().filter_map(Pattern::parse));
+        }
+        set
+    }
+
+    /// Returns true if `path` should be spared from normalization.
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        let is_dir = path.is_dir();
+        let path_segments: Vec<&str> = path.iter().filter_map(|c| c.to_str()).collect();
+        self.patterns.iter().any(|pattern| {
+            (!pattern.dir_only || is_dir) && pattern.matches(&path_segments)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, File};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_matches_simple_extension_glob() {
+        let set = ExcludeSet::compile(&["*.tmp"]);
+        assert!(set.is_excluded(Path::new("/a/b/cache.tmp")));
+        assert!(!set.is_excluded(Path::new("/a/b/cache.txt")));
+    }
+
+    #[test]
+    fn test_matches_double_star_at_any_depth() {
+        let set = ExcludeSet::compile(&["**/node_modules/**"]);
+        assert!(set.is_excluded(Path::new("/project/node_modules/lib/index.js")));
+        assert!(!set.is_excluded(Path::new("/project/src/index.js")));
+    }
+
+    #[test]
+    fn test_dir_only_pattern_skips_files() {
+        let set = ExcludeSet::compile(&[".git/"]);
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join(".git");
+        fs::create_dir(&dir).unwrap();
+        let file = temp.path().join(".gitfile");
+        File::create(&file).unwrap();
+
+        assert!(set.is_excluded(&dir));
+        assert!(!set.is_excluded(&file));
+    }
+
+    #[test]
+    fn test_with_gitignore_folds_in_root_patterns() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join(".gitignore"), "*.log\n# comment\ntarget/\n").unwrap();
+
+        let set = ExcludeSet::with_gitignore(temp.path(), &[] as &[&str]);
+        assert!(set.is_excluded(&temp.path().join("debug.log")));
+    }
+
+    #[test]
+    fn test_no_patterns_excludes_nothing() {
+        let set = ExcludeSet::default();
+        assert!(!set.is_excluded(Path::new("/anything")));
+    }
+}
@@ -0,0 +1,39 @@
+//! Shared segment-glob matching, underlying both `exclude::ExcludeSet` here
+//! and `nfd2nfc_common::ignore::IgnoreMatcher` (which depends on this crate
+//! already). `*` matches any run of characters within a single path
+//! segment; `**` matches across segment boundaries, including zero
+//! segments.
+
+/// True if `pattern` (as `/`-separated segments, `**` already expanded to
+/// its own segment) matches `path` anchored at its start -- callers that
+/// need an unanchored match should try every `path[start..]` themselves, the
+/// way `exclude::Pattern::matches` and `ignore::Rule::matches` do.
+pub fn segments_match(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(seg) if seg == "**" => {
+            if pattern.len() == 1 {
+                return true;
+            }
+            (0..=path.len()).any(|skip| segments_match(&pattern[1..], &path[skip..]))
+        }
+        Some(seg) => match path.first() {
+            Some(name) => segment_matches(seg, name) && segments_match(&pattern[1..], &path[1..]),
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path segment against a pattern segment where `*` matches
+/// any run of characters.
+pub fn segment_matches(pattern: &str, name: &str) -> bool {
+    fn helper(p: &[u8], s: &[u8]) -> bool {
+        match (p.first(), s.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => helper(&p[1..], s) || (!s.is_empty() && helper(p, &s[1..])),
+            (Some(pc), Some(sc)) if pc == sc => helper(&p[1..], &s[1..]),
+            _ => false,
+        }
+    }
+    helper(pattern.as_bytes(), name.as_bytes())
+}
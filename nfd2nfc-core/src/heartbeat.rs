@@ -0,0 +1,136 @@
+//! Watcher liveness, reported through a small heartbeat file rather than a
+//! bare "is the process running" check, so a TUI or script can tell an
+//! actively-converting watcher apart from one that's merely alive but wedged
+//! (e.g. stuck in a blocked syscall, FSEvents stream gone stale).
+//!
+//! `start_watcher`'s event loop rewrites [`HEARTBEAT_PATH`] on its own timer
+//! (every [`HEARTBEAT_INTERVAL`]) with the current time and how many renames
+//! it performed since the last write. [`read_health`] reads that file back
+//! and classifies it as [`WatcherHealth::Active`] (renamed something in the
+//! last interval), [`WatcherHealth::Idle`] (alive, nothing to do), or
+//! [`WatcherHealth::Dead`] (the file is older than [`HEARTBEAT_MAX_AGE`], so
+//! whatever's on the other end has stopped updating it). Timestamps are
+//! recorded in milliseconds rather than seconds since both constants are
+//! sub-second (`HEARTBEAT_MAX_AGE` is 750ms by default).
+
+use crate::constants::{HEARTBEAT_MAX_AGE, HEARTBEAT_PATH};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct HeartbeatFile {
+    last_beat_unix_millis: u64,
+    recent_rename_count: u32,
+}
+
+/// Watcher liveness as seen from the heartbeat file, mirroring the
+/// active/idle/dead states a task-manager-style view would show for a
+/// worker. `Unknown` covers the gap before the watcher's first heartbeat
+/// write (or no watcher ever having run at all), distinct from `Dead`'s
+/// "it was running and stopped reporting in".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherHealth {
+    Active,
+    Idle,
+    Dead,
+    Unknown,
+}
+
+impl WatcherHealth {
+    /// Whether the watcher should be treated as up for keybind gating
+    /// (start vs. stop/restart) -- true for `Active`/`Idle`, false for
+    /// `Dead`/`Unknown`.
+    pub fn is_up(self) -> bool {
+        matches!(self, WatcherHealth::Active | WatcherHealth::Idle)
+    }
+}
+
+/// Rewrites the heartbeat file with the current time and `recent_rename_count`
+/// (renames performed since the previous call). Called once per
+/// `HEARTBEAT_INTERVAL` tick from `start_watcher`'s event loop; failures are
+/// swallowed the same way `control::write_status` treats them -- a missed
+/// heartbeat just reads back as `Dead` a bit early rather than crashing the
+/// daemon.
+pub fn write_heartbeat(recent_rename_count: u32) {
+    let file = HeartbeatFile {
+        last_beat_unix_millis: now_unix_millis(),
+        recent_rename_count,
+    };
+    let Ok(toml_content) = toml::to_string_pretty(&file) else {
+        return;
+    };
+    if let Some(parent) = HEARTBEAT_PATH.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let tmp_path = HEARTBEAT_PATH.with_extension("tmp");
+    if fs::write(&tmp_path, toml_content).is_ok() {
+        let _ = fs::rename(&tmp_path, &*HEARTBEAT_PATH);
+    }
+}
+
+/// Reads the heartbeat file and classifies the watcher's current health.
+pub fn read_health() -> WatcherHealth {
+    let Ok(content) = fs::read_to_string(&*HEARTBEAT_PATH) else {
+        return WatcherHealth::Unknown;
+    };
+    let Ok(file) = toml::from_str::<HeartbeatFile>(&content) else {
+        return WatcherHealth::Unknown;
+    };
+
+    let age = Duration::from_millis(now_unix_millis().saturating_sub(file.last_beat_unix_millis));
+    if age > HEARTBEAT_MAX_AGE {
+        return WatcherHealth::Dead;
+    }
+
+    if file.recent_rename_count > 0 {
+        WatcherHealth::Active
+    } else {
+        WatcherHealth::Idle
+    }
+}
+
+fn now_unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_heartbeat_with_renames_round_trips() {
+        let file = HeartbeatFile {
+            last_beat_unix_millis: now_unix_millis(),
+            recent_rename_count: 3,
+        };
+        let content = toml::to_string_pretty(&file).unwrap();
+        let parsed: HeartbeatFile = toml::from_str(&content).unwrap();
+        assert_eq!(parsed.recent_rename_count, 3);
+        assert_eq!(parsed.last_beat_unix_millis, file.last_beat_unix_millis);
+    }
+
+    #[test]
+    fn stale_heartbeat_is_past_max_age() {
+        let stale_millis = HEARTBEAT_MAX_AGE.as_millis() as u64 + 1000;
+        let file = HeartbeatFile {
+            last_beat_unix_millis: now_unix_millis().saturating_sub(stale_millis),
+            recent_rename_count: 0,
+        };
+        let age = Duration::from_millis(now_unix_millis().saturating_sub(file.last_beat_unix_millis));
+        assert!(age > HEARTBEAT_MAX_AGE);
+    }
+
+    #[test]
+    fn fresh_heartbeat_is_within_max_age() {
+        let file = HeartbeatFile {
+            last_beat_unix_millis: now_unix_millis(),
+            recent_rename_count: 0,
+        };
+        let age = Duration::from_millis(now_unix_millis().saturating_sub(file.last_beat_unix_millis));
+        assert!(age <= HEARTBEAT_MAX_AGE);
+    }
+}
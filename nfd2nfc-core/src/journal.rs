@@ -0,0 +1,285 @@
+//! Rename journal for a single conversion run.
+//!
+//! `normalize_single_file` and `normalize_directory` can be given a
+//! [`RunJournal`] to append every successful rename to, keyed by a run id and
+//! start time. The journal is rewritten atomically (temp file + `fs::rename`)
+//! after each append, so a crash mid-write never corrupts a prior entry.
+//! [`revert_run`] replays a finished run's journal in reverse, giving users a
+//! safety net for bulk NFC/NFD conversions.
+
+use crate::constants::JOURNAL_DIR;
+use crate::normalizer::{get_actual_file_name, NormalizationTarget};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Errors that can occur while recording or reverting a rename journal.
+#[derive(Debug, Error)]
+pub enum JournalError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse journal: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Failed to serialize journal: {0}")]
+    Serialize(#[from] toml::ser::Error),
+
+    #[error("No journal found for run '{0}'")]
+    NotFound(String),
+}
+
+/// One renamed entry recorded during a conversion run, in the order it
+/// happened.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub target: NormalizationTarget,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalFile {
+    run_id: String,
+    started_at_unix_secs: u64,
+    entries: Vec<JournalEntry>,
+}
+
+/// An append-only record of renames performed during one conversion run.
+/// Entries are buffered in memory and the whole file is rewritten atomically
+/// after each append, so the journal on disk is never left half-written.
+pub struct RunJournal {
+    run_id: String,
+    started_at_unix_secs: u64,
+    path: PathBuf,
+    entries: Vec<JournalEntry>,
+}
+
+impl RunJournal {
+    /// Start a new journal for a conversion run, under [`JOURNAL_DIR`].
+    pub fn start() -> Self {
+        let started_at_unix_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let run_id = format!("{:x}-{:x}", started_at_unix_secs, std::process::id());
+        let path = JOURNAL_DIR.join(format!("{run_id}.toml"));
+        Self {
+            run_id,
+            started_at_unix_secs,
+            path,
+            entries: Vec::new(),
+        }
+    }
+
+    /// The id this run's journal is stored under, for later use with
+    /// [`revert_run`].
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Append a rename and persist the journal immediately.
+    pub fn record_rename(
+        &mut self,
+        from: &Path,
+        to: &Path,
+        target: NormalizationTarget,
+    ) -> Result<(), JournalError> {
+        self.entries.push(JournalEntry {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+            target,
+        });
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), JournalError> {
+        let file = JournalFile {
+            run_id: self.run_id.clone(),
+            started_at_unix_secs: self.started_at_unix_secs,
+            entries: self.entries.clone(),
+        };
+        let toml_content = toml::to_string_pretty(&file)?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("toml.tmp");
+        fs::write(&tmp_path, toml_content)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Reverts every rename recorded for `run_id`, in reverse order, renaming
+/// each `to` back to `from`. An entry is skipped (with a warning) if the
+/// name currently on disk no longer matches the journaled `to`, since the
+/// user has likely moved or renamed it since the run.
+pub fn revert_run(run_id: &str) -> Result<(), JournalError> {
+    let path = JOURNAL_DIR.join(format!("{run_id}.toml"));
+    let content = fs::read_to_string(&path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            JournalError::NotFound(run_id.to_string())
+        } else {
+            JournalError::Io(e)
+        }
+    })?;
+    let file: JournalFile = toml::from_str(&content)?;
+
+    info!("Reverting {} rename(s) from run {}", file.entries.len(), run_id);
+
+    for entry in file.entries.iter().rev() {
+        let expected_name = entry.to.file_name().and_then(|n| n.to_str());
+        match get_actual_file_name(&entry.to) {
+            Ok(actual_name) if Some(actual_name.as_str()) == expected_name => {
+                match fs::rename(&entry.to, &entry.from) {
+                    Ok(()) => debug!(
+                        "Reverted {} back to {}",
+                        entry.to.display(),
+                        entry.from.display()
+                    ),
+                    Err(e) => warn!(
+                        "Failed to revert {} back to {}: {}",
+                        entry.to.display(),
+                        entry.from.display(),
+                        e
+                    ),
+                }
+            }
+            _ => warn!(
+                "Skipping {}: no longer matches the journaled name, likely moved since the run",
+                entry.to.display()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the id of the most recently started run with a journal still on
+/// disk under [`JOURNAL_DIR`], for an `undo --last` that doesn't know the id
+/// up front. Returns `None` if no journal exists yet; a journal that fails
+/// to parse (e.g. left over from an incompatible version) is skipped rather
+/// than treated as an error.
+pub fn latest_run_id() -> Result<Option<String>, JournalError> {
+    let entries = match fs::read_dir(JOURNAL_DIR.as_path()) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(JournalError::Io(e)),
+    };
+
+    let mut latest: Option<(u64, String)> = None;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(file) = toml::from_str::<JournalFile>(&content) else {
+            continue;
+        };
+        if latest.as_ref().map_or(true, |(started_at, _)| file.started_at_unix_secs > *started_at) {
+            latest = Some((file.started_at_unix_secs, file.run_id));
+        }
+    }
+
+    Ok(latest.map(|(_, run_id)| run_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_rename_persists_and_run_id_round_trips() {
+        let temp = TempDir::new().unwrap();
+        let mut journal = RunJournal::start();
+        journal.path = temp.path().join("run.toml");
+
+        let from = temp.path().join("a.txt");
+        let to = temp.path().join("b.txt");
+        journal
+            .record_rename(&from, &to, NormalizationTarget::NFC)
+            .unwrap();
+
+        let content = fs::read_to_string(&journal.path).unwrap();
+        let file: JournalFile = toml::from_str(&content).unwrap();
+        assert_eq!(file.entries.len(), 1);
+        assert_eq!(file.entries[0].from, from);
+        assert_eq!(file.entries[0].to, to);
+    }
+
+    #[test]
+    fn test_revert_run_renames_back_and_skips_moved_entries() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = JOURNAL_DIR.join("test-revert-run.toml");
+        fs::create_dir_all(JOURNAL_DIR.as_path()).unwrap();
+
+        let kept_from = temp.path().join("원본.txt");
+        let kept_to = temp.path().join("renamed.txt");
+        File::create(&kept_to).unwrap();
+
+        let moved_from = temp.path().join("other_original.txt");
+        let moved_to = temp.path().join("moved_away.txt");
+        // `moved_to` is not created, simulating the user having since
+        // removed or renamed it; revert_run should skip it rather than fail.
+
+        let file = JournalFile {
+            run_id: "test-revert-run".to_string(),
+            started_at_unix_secs: 0,
+            entries: vec![
+                JournalEntry {
+                    from: kept_from.clone(),
+                    to: kept_to.clone(),
+                    target: NormalizationTarget::NFC,
+                },
+                JournalEntry {
+                    from: moved_from,
+                    to: moved_to,
+                    target: NormalizationTarget::NFC,
+                },
+            ],
+        };
+        fs::write(&journal_path, toml::to_string_pretty(&file).unwrap()).unwrap();
+
+        revert_run("test-revert-run").unwrap();
+        fs::remove_file(&journal_path).unwrap();
+
+        assert!(!kept_to.exists());
+        assert!(kept_from.exists());
+    }
+
+    #[test]
+    fn test_latest_run_id_picks_the_most_recently_started_run() {
+        fs::create_dir_all(JOURNAL_DIR.as_path()).unwrap();
+
+        let older = JournalFile {
+            run_id: "test-latest-older".to_string(),
+            started_at_unix_secs: 100,
+            entries: vec![],
+        };
+        let newer = JournalFile {
+            run_id: "test-latest-newer".to_string(),
+            started_at_unix_secs: 200,
+            entries: vec![],
+        };
+        let older_path = JOURNAL_DIR.join("test-latest-older.toml");
+        let newer_path = JOURNAL_DIR.join("test-latest-newer.toml");
+        fs::write(&older_path, toml::to_string_pretty(&older).unwrap()).unwrap();
+        fs::write(&newer_path, toml::to_string_pretty(&newer).unwrap()).unwrap();
+
+        let latest = latest_run_id().unwrap();
+
+        fs::remove_file(&older_path).unwrap();
+        fs::remove_file(&newer_path).unwrap();
+
+        assert_eq!(latest, Some("test-latest-newer".to_string()));
+    }
+}
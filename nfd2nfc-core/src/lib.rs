@@ -1,8 +1,13 @@
 pub mod config;
 pub mod constants;
+pub mod exclude;
+pub mod glob;
+pub mod heartbeat;
+pub mod journal;
 pub mod logger;
 pub mod normalizer;
 pub mod utils;
+pub mod volumes;
 
 // Re-export unicode normalization check functions
 pub use unicode_normalization::{is_nfc, is_nfd};
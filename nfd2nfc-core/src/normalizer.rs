@@ -1,24 +1,64 @@
+use crate::exclude::ExcludeSet;
+use crate::journal::RunJournal;
 use crate::utils::abbreviate_home_path;
 use log::{debug, error, info};
 use rayon::prelude::*;
-use std::collections::VecDeque;
+use rayon::ThreadPoolBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 use std::ffi::CStr;
-use std::fs::{self, File};
+use std::fs::{self, DirEntry, File};
 use std::os::fd::AsRawFd;
 #[cfg(unix)]
 use std::os::unix::fs::MetadataExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
 use unicode_normalization::{is_nfc, is_nfd, UnicodeNormalization};
 
+/// Worker-thread count for `normalize_directory`'s parallel walk. `0` means
+/// "unset": fall back to a default derived from the number of logical CPUs.
+/// Read fresh on every call so a config reload takes effect immediately.
+static NORMALIZER_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the worker-thread count used by `normalize_directory`'s parallel
+/// directory walk. `None` falls back to the number of logical CPUs;
+/// `Some(0)` and `Some(1)` run the walk sequentially on the calling thread
+/// instead of spinning up a pool, which keeps a bulk conversion from
+/// saturating the machine or competing with a running watcher. Shared by
+/// both the one-shot CLI conversion and any future batch tooling built on
+/// this crate.
+pub fn set_normalizer_threads(threads: Option<usize>) {
+    NORMALIZER_THREADS.store(threads.map_or(0, |n| n.max(1)), Ordering::Relaxed);
+}
+
+/// Resolves the configured thread count, substituting the number of logical
+/// CPUs when unset.
+fn normalizer_thread_count() -> usize {
+    match NORMALIZER_THREADS.load(Ordering::Relaxed) {
+        0 => std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        n => n,
+    }
+}
+
 /// Target normalization form for filename conversion.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum NormalizationTarget {
     NFC,
     NFD,
 }
 
+impl Default for NormalizationTarget {
+    fn default() -> Self {
+        NormalizationTarget::NFC
+    }
+}
+
 impl NormalizationTarget {
     /// Returns the string representation of the normalization target.
     pub fn as_str(&self) -> &'static str {
@@ -45,6 +85,24 @@ impl NormalizationTarget {
     }
 }
 
+/// How to handle a rename whose destination name already exists as a
+/// distinct file. This happens on case- or normalization-sensitive APFS
+/// volumes, where an NFC and an NFD spelling of the same name can coexist
+/// as separate inodes; renaming one onto the other would otherwise silently
+/// destroy the existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CollisionStrategy {
+    /// Leave the source entry untouched and move on.
+    #[default]
+    Skip,
+    /// Rename to a suffixed variant (`name (nfc).txt`, then `name (2).txt`,
+    /// ...) instead of overwriting the existing destination.
+    SuffixRename,
+    /// Abort with `NormalizerError::Collision`.
+    Fail,
+}
+
 /// Errors that can occur during normalization operations.
 #[derive(Debug, Error)]
 pub enum NormalizerError {
@@ -69,6 +127,9 @@ pub enum NormalizerError {
 
     #[error("Failed to convert path to UTF-8")]
     Utf8Error,
+
+    #[error("Rename target '{existing}' already exists as a different file (renaming '{incoming}')")]
+    Collision { existing: String, incoming: String },
 }
 
 impl NormalizerError {
@@ -106,10 +167,16 @@ pub fn get_actual_file_name(path: &Path) -> Result<String, NormalizerError> {
 /// Normalize a single file/folder name to the target normalization form.
 ///
 /// This function uses `get_actual_file_name` to get the real filename from disk,
-/// then renames it if conversion is needed.
+/// then renames it if conversion is needed. If `journal` is given, the rename
+/// is appended to it so it can later be undone via [`crate::journal::revert_run`].
+/// If the converted name already exists as a different file, `collision`
+/// decides whether the rename is skipped, redirected to a suffixed name, or
+/// fails with [`NormalizerError::Collision`].
 pub fn normalize_single_file(
     target_path: &Path,
     target: NormalizationTarget,
+    journal: Option<&Mutex<RunJournal>>,
+    collision: CollisionStrategy,
 ) -> Result<(), NormalizerError> {
     info!(
         "Starting single file conversion to {} for: {}",
@@ -124,8 +191,10 @@ pub fn normalize_single_file(
         return Ok(());
     }
 
-    let new_name = target.convert(&actual_name);
-    let new_path = target_path.with_file_name(&new_name);
+    let candidate = target_path.with_file_name(target.convert(&actual_name));
+    let Some(new_path) = resolve_rename_target(target_path, candidate, target, collision)? else {
+        return Ok(());
+    };
 
     fs::rename(target_path, &new_path).map_err(|e| NormalizerError::RenameError {
         from: target_path.display().to_string(),
@@ -133,6 +202,8 @@ pub fn normalize_single_file(
         source: e,
     })?;
 
+    record_rename(journal, target_path, &new_path, target);
+
     info!(
         "Converted {} to {}",
         abbreviate_home_path(&new_path),
@@ -142,14 +213,71 @@ pub fn normalize_single_file(
     Ok(())
 }
 
+/// Append a rename to `journal`, if given, logging (but not failing the
+/// caller) if the journal itself couldn't be written.
+fn record_rename(
+    journal: Option<&Mutex<RunJournal>>,
+    from: &Path,
+    to: &Path,
+    target: NormalizationTarget,
+) {
+    let Some(journal) = journal else { return };
+    let mut journal = match journal.lock() {
+        Ok(journal) => journal,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Err(e) = journal.record_rename(from, to, target) {
+        error!("Failed to record rename in journal: {}", e);
+    }
+}
+
+/// A progress snapshot from a running `normalize_directory` walk, sent
+/// periodically over an optional channel so a caller (the TUI) can render a
+/// live counter without polling the filesystem itself.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryProgress {
+    pub dirs_scanned: usize,
+    pub files_converted: usize,
+    pub current_path: PathBuf,
+}
+
+/// Outcome of processing a single entry: where to recurse next (if
+/// anywhere) and whether it was renamed, so a caller can tally
+/// `files_converted` without re-deriving it from the recursion result.
+struct EntryOutcome {
+    next_dir: Option<PathBuf>,
+    renamed: bool,
+}
+
 /// Normalize filenames in a directory to the target normalization form.
 ///
 /// If `recursive` is true, subdirectories are also processed.
-/// Symlinks and directories on different filesystems are skipped.
+/// Symlinks and directories on different filesystems are skipped, as is a
+/// directory already reached by an earlier `(dev, ino)`-identical path --
+/// guarding against a same-filesystem hardlink loop or bind-mount cycle
+/// looping the walk forever. Entries matched by `exclude` are left untouched
+/// and, for directories, not descended into.
+///
+/// `progress`, when given, receives a [`DirectoryProgress`] snapshot after
+/// each directory is processed. `cancel`, when given, is checked at the top
+/// of every queue iteration and inside the per-entry closure; once set, the
+/// walk stops picking up new work and returns `Ok(())` without completing
+/// the remaining queue. `journal`, when given, records every successful
+/// rename so the run can later be undone via [`crate::journal::revert_run`].
+/// `collision` decides what happens when a converted name already exists as
+/// a different file; with `CollisionStrategy::Fail`, the walk finishes the
+/// directory it's currently on and then returns
+/// `Err(NormalizerError::Collision)`.
+#[allow(clippy::too_many_arguments)]
 pub fn normalize_directory(
     target_folder: &Path,
     recursive: bool,
     target: NormalizationTarget,
+    exclude: &ExcludeSet,
+    progress: Option<&Sender<DirectoryProgress>>,
+    cancel: Option<&Arc<AtomicBool>>,
+    journal: Option<&Mutex<RunJournal>>,
+    collision: CollisionStrategy,
 ) -> Result<(), NormalizerError> {
     info!(
         "Starting folder conversion to {} for: {} (recursive: {})",
@@ -161,7 +289,31 @@ pub fn normalize_directory(
     let mut queue = VecDeque::new();
     queue.push_back(target_folder.to_path_buf());
 
+    // (dev, ino) of every directory already enqueued, so a directory reached
+    // twice through hardlinked directories or bind mounts is only walked
+    // once instead of looping forever -- mirrors the CLI's own planner in
+    // `nfd2nfc::normalizer::plan_normalize`. Shared across the Rayon worker
+    // pool below, since a wave's entries are checked concurrently.
+    let visited_dirs: Mutex<HashSet<(u64, u64)>> = Mutex::new(HashSet::new());
+    if let Ok(metadata) = fs::metadata(target_folder) {
+        visited_dirs
+            .lock()
+            .unwrap()
+            .insert((metadata.dev(), metadata.ino()));
+    }
+
+    let mut dirs_scanned = 0usize;
+    let mut files_converted = 0usize;
+
     while let Some(current_dir) = queue.pop_front() {
+        if cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+            info!(
+                "Folder conversion cancelled for: {}",
+                abbreviate_home_path(target_folder)
+            );
+            return Ok(());
+        }
+
         debug!(
             "Processing directory: {}",
             abbreviate_home_path(&current_dir)
@@ -179,83 +331,55 @@ pub fn normalize_directory(
             }
         };
 
-        let subdirs: Vec<_> = entries
-            .par_iter()
-            .filter_map(|entry| {
-                let path = entry.path();
-
-                let name = match path.file_name() {
-                    Some(n) => n,
-                    None => return None,
-                };
+        let process = |entry: &DirEntry| {
+            process_entry(
+                entry,
+                target_folder,
+                recursive,
+                target,
+                exclude,
+                cancel,
+                journal,
+                collision,
+                &visited_dirs,
+            )
+        };
 
-                if name == "." || name == ".." {
-                    debug!("Skipping dot entry: {}", path.display());
-                    return None;
+        let threads = normalizer_thread_count();
+        let outcomes: Vec<Result<EntryOutcome, NormalizerError>> = if threads <= 1 {
+            entries.iter().map(process).collect()
+        } else {
+            match ThreadPoolBuilder::new().num_threads(threads).build() {
+                Ok(pool) => pool.install(|| entries.par_iter().map(process).collect()),
+                Err(e) => {
+                    error!("Failed to build normalizer thread pool: {}", e);
+                    entries.iter().map(process).collect()
                 }
+            }
+        };
 
-                let original_name = name.to_string_lossy();
-
-                let new_path = if target.needs_conversion(&original_name) {
-                    let new_name = target.convert(&original_name);
-                    let renamed_path = path.with_file_name(&new_name);
-                    match fs::rename(&path, &renamed_path) {
-                        Ok(_) => {
-                            info!(
-                                "Converted {} to {}",
-                                abbreviate_home_path(&renamed_path),
-                                target.as_str()
-                            );
-                            renamed_path
-                        }
-                        Err(e) => {
-                            error!(
-                                "Failed to convert {} to {}: {}",
-                                abbreviate_home_path(&path),
-                                target.as_str(),
-                                e
-                            );
-                            path
-                        }
-                    }
-                } else {
-                    debug!(
-                        "Entry already in {}: {}",
-                        target.as_str(),
-                        abbreviate_home_path(&path)
-                    );
-                    path
-                };
+        let mut next_dirs = Vec::new();
+        for outcome in outcomes {
+            let outcome = outcome?;
+            if outcome.renamed {
+                files_converted += 1;
+            }
+            if let Some(next_dir) = outcome.next_dir {
+                next_dirs.push(next_dir);
+            }
+        }
 
-                // Check if we should recurse into this directory
-                if !(recursive && new_path.is_dir()) {
-                    return None;
-                }
-                let metadata = match fs::symlink_metadata(&new_path) {
-                    Ok(m) => m,
-                    Err(_) => {
-                        error!(
-                            "Failed to get metadata for {}",
-                            abbreviate_home_path(&new_path)
-                        );
-                        return None;
-                    }
-                };
-                if metadata.file_type().is_symlink()
-                    || !is_same_filesystem(target_folder, &new_path)
-                {
-                    debug!(
-                        "Skipping directory (symlink or different FS): {}",
-                        new_path.display()
-                    );
-                    return None;
-                }
-                Some(new_path)
-            })
-            .collect();
+        dirs_scanned += 1;
+        if let Some(tx) = progress {
+            let _ = tx.send(DirectoryProgress {
+                dirs_scanned,
+                files_converted,
+                current_path: current_dir.clone(),
+            });
+        }
 
         if recursive {
-            queue.extend(subdirs);
+            queue.extend(next_dirs);
         }
     }
 
@@ -268,6 +392,131 @@ pub fn normalize_directory(
     Ok(())
 }
 
+/// Renames a single entry (if needed) and, when recursing, reports whether
+/// it should be queued as a subdirectory to walk next. Shared by both the
+/// sequential and Rayon-parallel paths through `normalize_directory`.
+#[allow(clippy::too_many_arguments)]
+fn process_entry(
+    entry: &DirEntry,
+    target_folder: &Path,
+    recursive: bool,
+    target: NormalizationTarget,
+    exclude: &ExcludeSet,
+    cancel: Option<&Arc<AtomicBool>>,
+    journal: Option<&Mutex<RunJournal>>,
+    collision: CollisionStrategy,
+    visited_dirs: &Mutex<HashSet<(u64, u64)>>,
+) -> Result<EntryOutcome, NormalizerError> {
+    let none = EntryOutcome {
+        next_dir: None,
+        renamed: false,
+    };
+
+    if cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+        return Ok(none);
+    }
+
+    let path = entry.path();
+
+    let Some(name) = path.file_name() else {
+        return Ok(none);
+    };
+
+    if name == "." || name == ".." {
+        debug!("Skipping dot entry: {}", path.display());
+        return Ok(none);
+    }
+
+    if exclude.is_excluded(&path) {
+        debug!("Skipping excluded entry: {}", path.display());
+        return Ok(none);
+    }
+
+    let original_name = name.to_string_lossy();
+
+    let mut renamed = false;
+    let new_path = if target.needs_conversion(&original_name) {
+        let candidate = path.with_file_name(target.convert(&original_name));
+        match resolve_rename_target(&path, candidate, target, collision)? {
+            None => path.clone(),
+            Some(renamed_path) => match fs::rename(&path, &renamed_path) {
+                Ok(_) => {
+                    record_rename(journal, &path, &renamed_path, target);
+                    info!(
+                        "Converted {} to {}",
+                        abbreviate_home_path(&renamed_path),
+                        target.as_str()
+                    );
+                    renamed = true;
+                    renamed_path
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to convert {} to {}: {}",
+                        abbreviate_home_path(&path),
+                        target.as_str(),
+                        e
+                    );
+                    path.clone()
+                }
+            },
+        }
+    } else {
+        debug!(
+            "Entry already in {}: {}",
+            target.as_str(),
+            abbreviate_home_path(&path)
+        );
+        path.clone()
+    };
+
+    // Check if we should recurse into this directory
+    if !(recursive && new_path.is_dir()) {
+        return Ok(EntryOutcome {
+            next_dir: None,
+            renamed,
+        });
+    }
+    let metadata = match fs::symlink_metadata(&new_path) {
+        Ok(m) => m,
+        Err(_) => {
+            error!(
+                "Failed to get metadata for {}",
+                abbreviate_home_path(&new_path)
+            );
+            return Ok(EntryOutcome {
+                next_dir: None,
+                renamed,
+            });
+        }
+    };
+    if metadata.file_type().is_symlink() || !is_same_filesystem(target_folder, &new_path) {
+        debug!(
+            "Skipping directory (symlink or different FS): {}",
+            new_path.display()
+        );
+        return Ok(EntryOutcome {
+            next_dir: None,
+            renamed,
+        });
+    }
+    if !visited_dirs
+        .lock()
+        .unwrap()
+        .insert((metadata.dev(), metadata.ino()))
+    {
+        debug!("Cycle detected, skipping directory: {}", new_path.display());
+        return Ok(EntryOutcome {
+            next_dir: None,
+            renamed,
+        });
+    }
+    Ok(EntryOutcome {
+        next_dir: Some(new_path),
+        renamed,
+    })
+}
+
 /// Check if two paths are on the same filesystem.
 #[cfg(unix)]
 fn is_same_filesystem(original_path: &Path, new_path: &Path) -> bool {
@@ -281,6 +530,80 @@ fn is_same_filesystem(_original_path: &Path, _new_path: &Path) -> bool {
     true
 }
 
+/// Check if `a` and `b` are (meta)data for the same inode, e.g. because `b`
+/// is just a different-cased or different-normalization alias for `a` on a
+/// case-insensitive volume.
+#[cfg(unix)]
+fn is_same_inode(a: &Path, b: &Path) -> bool {
+    let a_meta = fs::metadata(a);
+    let b_meta = fs::metadata(b);
+    match (a_meta, b_meta) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_same_inode(_a: &Path, _b: &Path) -> bool {
+    false
+}
+
+/// Picks the path to rename `path` to, given the already-computed
+/// normalized `candidate`. If `candidate` doesn't exist yet (or turns out to
+/// be the same file as `path`, e.g. a case-only alias), renaming straight to
+/// it is safe. Otherwise `candidate` is occupied by a genuinely different
+/// file and `collision` decides what happens: skip the rename (`Ok(None)`),
+/// pick a non-colliding suffixed name (`Ok(Some(..))`), or fail outright.
+fn resolve_rename_target(
+    path: &Path,
+    candidate: PathBuf,
+    target: NormalizationTarget,
+    collision: CollisionStrategy,
+) -> Result<Option<PathBuf>, NormalizerError> {
+    if !candidate.exists() || is_same_inode(path, &candidate) {
+        return Ok(Some(candidate));
+    }
+
+    match collision {
+        CollisionStrategy::Skip => {
+            debug!(
+                "Skipping rename of {} (destination {} already exists as a different file)",
+                path.display(),
+                candidate.display()
+            );
+            Ok(None)
+        }
+        CollisionStrategy::SuffixRename => Ok(Some(suffixed_path(&candidate, target))),
+        CollisionStrategy::Fail => Err(NormalizerError::Collision {
+            existing: candidate.display().to_string(),
+            incoming: path.display().to_string(),
+        }),
+    }
+}
+
+/// Finds a name close to `candidate` that doesn't exist yet, first trying
+/// ` (nfc)`/` (nfd)` (depending on `target`), then ` (2)`, ` (3)`, etc.
+fn suffixed_path(candidate: &Path, target: NormalizationTarget) -> PathBuf {
+    let stem = candidate
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let ext = candidate.extension().and_then(|s| s.to_str());
+
+    let build = |suffix: &str| match ext {
+        Some(ext) => format!("{stem}{suffix}.{ext}"),
+        None => format!("{stem}{suffix}"),
+    };
+
+    let mut name = build(&format!(" ({})", target.as_str().to_lowercase()));
+    let mut n = 2;
+    while candidate.with_file_name(&name).exists() {
+        name = build(&format!(" ({n})"));
+        n += 1;
+    }
+    candidate.with_file_name(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,7 +636,7 @@ mod tests {
         let temp = TempDir::new().unwrap();
         let path = create_nfd_file(temp.path(), "테스트.txt");
 
-        normalize_single_file(&path, NormalizationTarget::NFC).unwrap();
+        normalize_single_file(&path, NormalizationTarget::NFC, None, CollisionStrategy::Skip).unwrap();
 
         let entries: Vec<_> = fs::read_dir(temp.path())
             .unwrap()
@@ -335,7 +658,17 @@ mod tests {
         create_nfd_file(temp.path(), "파일1.txt");
         create_nfd_file(&sub, "파일2.txt");
 
-        normalize_directory(temp.path(), true, NormalizationTarget::NFC).unwrap();
+        normalize_directory(
+            temp.path(),
+            true,
+            NormalizationTarget::NFC,
+            &ExcludeSet::default(),
+            None,
+            None,
+            None,
+            CollisionStrategy::Skip,
+        )
+        .unwrap();
 
         // Verify all entries are NFC
         fn check_all_nfc(dir: &Path) -> bool {
@@ -354,6 +687,29 @@ mod tests {
         assert!(check_all_nfc(temp.path()));
     }
 
+    #[test]
+    fn test_normalize_directory_skips_excluded_entries() {
+        let temp = TempDir::new().unwrap();
+        let excluded_path = create_nfd_file(temp.path(), "숨김.tmp");
+        let included_path = create_nfd_file(temp.path(), "파일.txt");
+
+        let exclude = ExcludeSet::compile(&["*.tmp"]);
+        normalize_directory(
+            temp.path(),
+            false,
+            NormalizationTarget::NFC,
+            &exclude,
+            None,
+            None,
+            None,
+            CollisionStrategy::Skip,
+        )
+        .unwrap();
+
+        assert!(excluded_path.exists(), "Excluded entry should not be renamed");
+        assert!(!included_path.exists(), "Non-excluded entry should be renamed");
+    }
+
     #[test]
     fn test_no_conversion_needed() {
         let temp = TempDir::new().unwrap();
@@ -362,9 +718,149 @@ mod tests {
         File::create(&path).unwrap();
 
         // Should succeed without error
-        normalize_single_file(&path, NormalizationTarget::NFC).unwrap();
+        normalize_single_file(&path, NormalizationTarget::NFC, None, CollisionStrategy::Skip).unwrap();
 
         // File should still exist with same name
         assert!(path.exists());
     }
+
+    #[test]
+    fn test_normalize_directory_runs_sequentially_when_threads_clamped() {
+        let temp = TempDir::new().unwrap();
+        create_nfd_file(temp.path(), "파일.txt");
+
+        set_normalizer_threads(Some(1));
+        let result = normalize_directory(
+            temp.path(),
+            false,
+            NormalizationTarget::NFC,
+            &ExcludeSet::default(),
+            None,
+            None,
+            None,
+            CollisionStrategy::Skip,
+        );
+        set_normalizer_threads(None);
+
+        result.unwrap();
+        let entries: Vec<_> = fs::read_dir(temp.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .collect();
+        assert_eq!(entries.len(), 1);
+        let name = entries[0].file_name().to_string_lossy().to_string();
+        assert!(is_nfc(&name));
+    }
+
+    #[test]
+    fn test_normalize_directory_reports_progress() {
+        let temp = TempDir::new().unwrap();
+        create_nfd_file(temp.path(), "파일1.txt");
+        create_nfd_file(temp.path(), "파일2.txt");
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        normalize_directory(
+            temp.path(),
+            false,
+            NormalizationTarget::NFC,
+            &ExcludeSet::default(),
+            Some(&tx),
+            None,
+            None,
+            CollisionStrategy::Skip,
+        )
+        .unwrap();
+
+        let progress = rx.try_recv().unwrap();
+        assert_eq!(progress.dirs_scanned, 1);
+        assert_eq!(progress.files_converted, 2);
+    }
+
+    #[test]
+    fn test_normalize_directory_stops_early_when_cancelled() {
+        let temp = TempDir::new().unwrap();
+        let sub = temp.path().join("sub");
+        fs::create_dir(&sub).unwrap();
+        create_nfd_file(&sub, "파일.txt");
+
+        let cancel = Arc::new(AtomicBool::new(true));
+        normalize_directory(
+            temp.path(),
+            true,
+            NormalizationTarget::NFC,
+            &ExcludeSet::default(),
+            None,
+            Some(&cancel),
+            None,
+            CollisionStrategy::Skip,
+        )
+        .unwrap();
+
+        // Cancelled before the first directory was even processed, so the
+        // NFD name underneath should be untouched.
+        let entries: Vec<_> = fs::read_dir(&sub).unwrap().filter_map(|e| e.ok()).collect();
+        assert_eq!(entries.len(), 1);
+        let name = entries[0].file_name().to_string_lossy().to_string();
+        assert!(!is_nfc(&name));
+    }
+
+    /// Creates both an NFD and an NFC spelling of the same visible name as
+    /// distinct files, simulating the duplicate-spelling collision that can
+    /// occur on a case/normalization-sensitive APFS volume.
+    fn create_colliding_pair(dir: &Path, name: &str) -> (PathBuf, PathBuf) {
+        let nfd_path = create_nfd_file(dir, name);
+        let nfc_path = dir.join(name);
+        File::create(&nfc_path).unwrap();
+        (nfd_path, nfc_path)
+    }
+
+    #[test]
+    fn test_normalize_single_file_skip_leaves_both_files_untouched() {
+        let temp = TempDir::new().unwrap();
+        let (nfd_path, nfc_path) = create_colliding_pair(temp.path(), "충돌.txt");
+
+        normalize_single_file(&nfd_path, NormalizationTarget::NFC, None, CollisionStrategy::Skip)
+            .unwrap();
+
+        assert!(nfd_path.exists(), "Source should be left alone on Skip");
+        assert!(nfc_path.exists(), "Existing destination should be left alone on Skip");
+    }
+
+    #[test]
+    fn test_normalize_single_file_suffix_rename_avoids_clobbering() {
+        let temp = TempDir::new().unwrap();
+        let (nfd_path, nfc_path) = create_colliding_pair(temp.path(), "충돌.txt");
+
+        normalize_single_file(
+            &nfd_path,
+            NormalizationTarget::NFC,
+            None,
+            CollisionStrategy::SuffixRename,
+        )
+        .unwrap();
+
+        assert!(!nfd_path.exists(), "Source should have been renamed away");
+        assert!(nfc_path.exists(), "Existing destination must survive untouched");
+        assert!(
+            temp.path().join("충돌 (nfc).txt").exists(),
+            "Renamed file should land at a suffixed name instead of clobbering"
+        );
+    }
+
+    #[test]
+    fn test_normalize_single_file_fail_returns_collision_error() {
+        let temp = TempDir::new().unwrap();
+        let (nfd_path, nfc_path) = create_colliding_pair(temp.path(), "충돌.txt");
+
+        let result = normalize_single_file(
+            &nfd_path,
+            NormalizationTarget::NFC,
+            None,
+            CollisionStrategy::Fail,
+        );
+
+        assert!(matches!(result, Err(NormalizerError::Collision { .. })));
+        assert!(nfd_path.exists(), "Source must be untouched when the rename fails");
+        assert!(nfc_path.exists());
+    }
 }
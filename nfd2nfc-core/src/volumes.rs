@@ -0,0 +1,173 @@
+//! Mounted-volume discovery and per-filesystem Unicode-normalization probing.
+//!
+//! This is the data behind the TUI's volumes/mount-point picker: besides
+//! the usual mount point / filesystem type / free space listing, each
+//! volume can be probed by writing an NFD-named file to it and reading
+//! back whatever name actually landed on disk, which is the concrete
+//! reason NFD filenames keep reappearing on some mounts (SMB, exFAT) but
+//! never on others (APFS).
+
+use std::ffi::CStr;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use log::{debug, warn};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::normalizer::get_actual_file_name;
+use crate::utils::abbreviate_home_path;
+
+/// A mounted filesystem, as surfaced to the volumes picker.
+#[derive(Debug, Clone)]
+pub struct VolumeInfo {
+    pub path: PathBuf,
+    pub name: String,
+    pub fs_type: String,
+    pub free_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// How a volume actually stores a Unicode filename once it's written to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeUnicodeBehavior {
+    /// The name comes back exactly as written (NFD in, NFD out).
+    PreservesNfd,
+    /// The filesystem normalizes to NFC on write.
+    NormalizesToNfc,
+    /// Neither of the above; surfaced rather than guessed at so a
+    /// surprising mount doesn't get silently misreported.
+    Other,
+    /// The probe couldn't run (read-only volume, permission error, etc.)
+    Unknown,
+}
+
+/// What converting filenames to NFC is expected to accomplish on a given
+/// filesystem, based purely on its `fs_type` string -- no write probe
+/// involved, so this can be checked cheaply for every configured watch path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsNormalizationPolicy {
+    /// The filesystem stores names as written; converting actually sticks
+    /// (exFAT, FAT, SMB, NFS, and APFS, which is normalization-insensitive).
+    Convert,
+    /// The filesystem canonically decomposes names back to NFD on write
+    /// (HFS+), so converting is immediately undone by the OS itself.
+    Futile,
+    /// Not one of the filesystem types this table knows about.
+    Unknown,
+}
+
+impl FsNormalizationPolicy {
+    /// Classifies a `statfs` `f_fstypename` string (e.g. `"apfs"`, `"hfs"`).
+    pub fn for_fs_type(fs_type: &str) -> Self {
+        match fs_type.to_ascii_lowercase().as_str() {
+            "hfs" => FsNormalizationPolicy::Futile,
+            "apfs" | "exfat" | "msdos" | "smbfs" | "nfs" => FsNormalizationPolicy::Convert,
+            _ => FsNormalizationPolicy::Unknown,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FsNormalizationPolicy::Convert => "convert",
+            FsNormalizationPolicy::Futile => "futile (HFS+ re-decomposes to NFD)",
+            FsNormalizationPolicy::Unknown => "unknown",
+        }
+    }
+}
+
+/// Resolves the `f_fstypename` of whatever filesystem `path` lives on, for
+/// attaching a [`FsNormalizationPolicy`] to a configured watch path. Unlike
+/// `describe_volume`, this takes an arbitrary path rather than a volume's
+/// mount point -- `statfs` reports the filesystem a path resolves onto
+/// either way.
+pub fn fs_type_for_path(path: &Path) -> Option<String> {
+    describe_volume(path, "").map(|v| v.fs_type)
+}
+
+/// List `/` and every volume mounted under `/Volumes`.
+///
+/// Does not probe Unicode behavior; that's a real write+read round trip
+/// (slower still on network mounts) and is left to `probe_unicode_behavior`
+/// so callers can run it off the render thread.
+pub fn list_volumes() -> Vec<VolumeInfo> {
+    let mut volumes = Vec::new();
+
+    if let Some(root) = describe_volume(Path::new("/"), "Macintosh HD") {
+        volumes.push(root);
+    }
+
+    if let Ok(entries) = fs::read_dir("/Volumes") {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path == Path::new("/Volumes/Macintosh HD") {
+                continue; // Usually a symlink back to `/`; already listed above.
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(volume) = describe_volume(&path, &name) {
+                volumes.push(volume);
+            }
+        }
+    }
+
+    volumes
+}
+
+#[cfg(unix)]
+fn describe_volume(path: &Path, name: &str) -> Option<VolumeInfo> {
+    use std::mem::MaybeUninit;
+
+    let c_path = std::ffi::CString::new(path.to_string_lossy().as_bytes()).ok()?;
+    let mut stat = MaybeUninit::<libc::statfs>::uninit();
+    let ret = unsafe { libc::statfs(c_path.as_ptr(), stat.as_mut_ptr()) };
+    if ret != 0 {
+        debug!("statfs failed for {}", abbreviate_home_path(path));
+        return None;
+    }
+    let stat = unsafe { stat.assume_init() };
+
+    let fs_type = unsafe { CStr::from_ptr(stat.f_fstypename.as_ptr()) }
+        .to_string_lossy()
+        .to_string();
+
+    let block_size = stat.f_bsize as u64;
+    Some(VolumeInfo {
+        path: path.to_path_buf(),
+        name: name.to_string(),
+        fs_type,
+        free_bytes: stat.f_bavail as u64 * block_size,
+        total_bytes: stat.f_blocks as u64 * block_size,
+    })
+}
+
+#[cfg(not(unix))]
+fn describe_volume(_path: &Path, _name: &str) -> Option<VolumeInfo> {
+    None
+}
+
+/// Probe how `volume_root` normalizes Unicode filenames by creating a
+/// throwaway NFD-named file there and reading back the name the
+/// filesystem actually stored.
+pub fn probe_unicode_behavior(volume_root: &Path) -> VolumeUnicodeBehavior {
+    let probe_name: String = "._nfd2nfc_probe_\u{ac00}".nfd().collect(); // "가" (Hangul) in NFD
+    let probe_path = volume_root.join(&probe_name);
+
+    if File::create(&probe_path).is_err() {
+        warn!(
+            "Unicode probe: failed to write to {}",
+            abbreviate_home_path(volume_root)
+        );
+        return VolumeUnicodeBehavior::Unknown;
+    }
+
+    let actual_name = get_actual_file_name(&probe_path).ok();
+    let _ = fs::remove_file(&probe_path);
+
+    match actual_name {
+        Some(name) if name == probe_name => VolumeUnicodeBehavior::PreservesNfd,
+        Some(name) if name == probe_name.nfc().collect::<String>() => {
+            VolumeUnicodeBehavior::NormalizesToNfc
+        }
+        Some(_) => VolumeUnicodeBehavior::Other,
+        None => VolumeUnicodeBehavior::Unknown,
+    }
+}
@@ -0,0 +1,541 @@
+//! Named-pipe control interface so another process (an editor, a script)
+//! can query and drive the watcher daemon without going through the TUI,
+//! borrowing the pipe-based IPC model file managers like xplr expose to
+//! shell hooks.
+//!
+//! On startup this creates `CONTROL_DIR` containing `msg_in`, a FIFO
+//! clients write commands to (one per line), and `result_out`, a FIFO we
+//! write one JSON response line to per command processed. `status_out` is
+//! a plain file, not a FIFO: it's rewritten in place with the latest
+//! watcher snapshot after every change, so a reader can check current
+//! status by just reading it, without risking a write that blocks forever
+//! because nothing has `result_out`/`status_out` open for reading. Writes
+//! to `result_out` are best-effort for the same reason: if no client is
+//! currently reading it, the response is dropped rather than stalling the
+//! control thread.
+//!
+//! Supported `msg_in` lines: `status`, `reload-config`,
+//! `convert <path> <nfc|nfd> <recursive|children|name-only>`, `stop`,
+//! `add-recursive <path>`, `add-exclude <path>`, `remove <path>`, `pause`,
+//! and `resume`. The `add-recursive`/`add-exclude`/`remove` trio let a
+//! script or editor integration register or exclude a directory on the fly
+//! instead of editing the config file and sending `reload-config`; `pause`
+//! and `resume` let the TUI suspend NFD->NFC conversion temporarily (e.g.
+//! during a big bulk copy) without tearing the watcher down.
+
+use log::{error, info};
+use nfd2nfc_common::config::{self, Config};
+use nfd2nfc_common::constants::{
+    CONTROL_DIR, CONTROL_MSG_IN_FILE, CONTROL_RESULT_OUT_FILE, CONTROL_STATUS_OUT_FILE,
+};
+use nfd2nfc_core::exclude::ExcludeSet;
+use nfd2nfc_core::normalizer::{
+    normalize_directory, normalize_single_file, CollisionStrategy, NormalizationTarget,
+};
+use serde::Serialize;
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::FromRawFd;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Live snapshot rewritten to `status_out` after every state change.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusSnapshot {
+    pub running: bool,
+    /// Whether the watcher is currently suspending NFD->NFC conversion in
+    /// response to a `pause` control command (see [`ControlHandle::set_paused`]).
+    pub paused: bool,
+    pub recursive_watch_paths: usize,
+    pub non_recursive_watch_paths: usize,
+    /// How many of the watch paths above haven't resolved yet (e.g. a
+    /// removable drive that isn't mounted), and so aren't actually being
+    /// watched until `start_watcher`'s retry loop picks them up.
+    pub unresolved_watch_paths: usize,
+    pub poll_watch_paths: usize,
+    pub watcher_target: NormalizationTarget,
+    /// Events currently sitting in the debounce map, waiting for their
+    /// deadline to pass with no newer event for the same file.
+    pub pending_events: usize,
+    /// Events dropped so far because they were recognized as the watcher's
+    /// own rename echoing back through FSEvents, rather than an external
+    /// change (see `watcher::is_self_generated`). Cumulative since startup.
+    pub suppressed_events: u32,
+    /// Renames completed per second, averaged over the last heartbeat
+    /// interval. Reflects any `tranquility`-driven throttling currently in
+    /// effect (see `watcher::start_watcher`'s debounce tick).
+    pub renames_per_sec: f64,
+}
+
+fn snapshot_from_config(config: &Config, paused: bool) -> StatusSnapshot {
+    let unresolved_watch_paths = config
+        .recursive_watch_paths
+        .iter()
+        .chain(&config.non_recursive_watch_paths)
+        .filter(|entry| !entry.is_resolved())
+        .count();
+
+    StatusSnapshot {
+        running: true,
+        paused,
+        recursive_watch_paths: config.recursive_watch_paths.len(),
+        non_recursive_watch_paths: config.non_recursive_watch_paths.len(),
+        unresolved_watch_paths,
+        poll_watch_paths: config.poll_watch_paths.len(),
+        watcher_target: config.watcher_target,
+        pending_events: 0,
+        suppressed_events: 0,
+        renames_per_sec: 0.0,
+    }
+}
+
+/// Request forwarded from the control thread to `start_watcher`'s event
+/// loop, which owns the watcher, config, and ignore matcher these need.
+pub enum ControlEvent {
+    ReloadConfig,
+    Stop,
+    /// `add-recursive <path>`, already resolved to a canonical directory.
+    AddRecursiveWatch(PathBuf),
+    /// `add-exclude <path>`, already resolved to a canonical directory.
+    AddExclude(PathBuf),
+    /// `remove <path>`, carried as the raw string so it can match either a
+    /// watch entry's original unresolved text or its resolved canonical
+    /// path (the target directory may no longer exist to re-resolve).
+    Remove(String),
+    /// `pause`: keep watching and queuing events, but stop applying
+    /// NFD->NFC renames until a matching `resume` arrives.
+    Pause,
+    Resume,
+}
+
+/// Handle `start_watcher` keeps so it can push a fresh snapshot to
+/// `status_out` whenever the watch/ignore set or paused state changes.
+pub struct ControlHandle {
+    status: Arc<Mutex<StatusSnapshot>>,
+}
+
+impl ControlHandle {
+    pub fn update(&self, config: &Config) {
+        let (paused, pending_events, suppressed_events, renames_per_sec) = {
+            let guard = self
+                .status
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            (
+                guard.paused,
+                guard.pending_events,
+                guard.suppressed_events,
+                guard.renames_per_sec,
+            )
+        };
+        let mut snapshot = snapshot_from_config(config, paused);
+        snapshot.pending_events = pending_events;
+        snapshot.suppressed_events = suppressed_events;
+        snapshot.renames_per_sec = renames_per_sec;
+        if let Ok(mut guard) = self.status.lock() {
+            *guard = snapshot.clone();
+        }
+        write_status(&snapshot);
+    }
+
+    /// Updates just the paused bit in place, in response to a `pause`/
+    /// `resume` control command, without needing the current `Config`.
+    pub fn set_paused(&self, paused: bool) {
+        let snapshot = {
+            let mut guard = self
+                .status
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.paused = paused;
+            guard.clone()
+        };
+        write_status(&snapshot);
+    }
+
+    /// Updates the debounce map's live pending/suppressed counts in place.
+    /// Called from `start_watcher`'s debounce tick, the same cadence events
+    /// are dispatched at, so `status_out` readers see roughly current
+    /// numbers rather than a stale snapshot from the last watch-set change.
+    pub fn update_debounce_stats(&self, pending_events: usize, suppressed_events: u32) {
+        let snapshot = {
+            let mut guard = self
+                .status
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.pending_events = pending_events;
+            guard.suppressed_events = suppressed_events;
+            guard.clone()
+        };
+        write_status(&snapshot);
+    }
+
+    /// Updates the effective rename rate in place, called from
+    /// `start_watcher`'s heartbeat tick alongside `heartbeat::write_heartbeat`.
+    pub fn update_rename_rate(&self, renames_per_sec: f64) {
+        let snapshot = {
+            let mut guard = self
+                .status
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.renames_per_sec = renames_per_sec;
+            guard.clone()
+        };
+        write_status(&snapshot);
+    }
+}
+
+/// Sets up the control directory and FIFOs and spawns the thread that
+/// blocks reading `msg_in`. Returns a handle for pushing status updates and
+/// a channel `start_watcher` selects on for reload/stop requests. If the
+/// control directory or FIFOs can't be created, the channel is left
+/// permanently empty and the daemon runs exactly as it would without one.
+pub fn spawn(config: &Config) -> (ControlHandle, tokio::sync::mpsc::Receiver<ControlEvent>) {
+    let (tx, rx) = tokio::sync::mpsc::channel(16);
+    let status = Arc::new(Mutex::new(snapshot_from_config(config, false)));
+    let handle = ControlHandle {
+        status: status.clone(),
+    };
+
+    if let Err(e) = fs::create_dir_all(&*CONTROL_DIR) {
+        error!(
+            "Failed to create control directory {}: {}. Control channel disabled.",
+            CONTROL_DIR.display(),
+            e
+        );
+        return (handle, rx);
+    }
+
+    let msg_in = CONTROL_DIR.join(CONTROL_MSG_IN_FILE);
+    let result_out = CONTROL_DIR.join(CONTROL_RESULT_OUT_FILE);
+    if let Err(e) = ensure_fifo(&msg_in).and_then(|()| ensure_fifo(&result_out)) {
+        error!("Failed to create control fifos: {}. Control channel disabled.", e);
+        return (handle, rx);
+    }
+
+    write_status(&snapshot_from_config(config, false));
+    info!(" + Control channel listening on {}", msg_in.display());
+
+    std::thread::spawn(move || control_loop(msg_in, result_out, status, tx));
+
+    (handle, rx)
+}
+
+/// Creates `path` as a FIFO if nothing is there yet. Leaves an existing
+/// file alone, including a leftover FIFO from a previous run.
+fn ensure_fifo(path: &Path) -> std::io::Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let ret = unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) };
+    if ret != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Opens `path` for writing without blocking when nothing is reading it
+/// yet. Returns `None` (rather than hanging) if there's no reader, which
+/// `libc::open` on a FIFO reports as `ENXIO`.
+fn open_nonblocking_writer(path: &Path) -> Option<File> {
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let fd = unsafe { libc::open(c_path.as_ptr(), libc::O_WRONLY | libc::O_NONBLOCK) };
+    if fd < 0 {
+        None
+    } else {
+        Some(unsafe { File::from_raw_fd(fd) })
+    }
+}
+
+fn write_status(snapshot: &StatusSnapshot) {
+    let path = CONTROL_DIR.join(CONTROL_STATUS_OUT_FILE);
+    match serde_json::to_string(snapshot) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                error!("Failed to write {}: {}", path.display(), e);
+            }
+        }
+        Err(e) => error!("Failed to serialize status snapshot: {}", e),
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ControlResponse {
+    command: String,
+    success: bool,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<StatusSnapshot>,
+}
+
+fn write_result(result_out: &Path, response: &ControlResponse) {
+    let Some(mut file) = open_nonblocking_writer(result_out) else {
+        info!("No reader on result_out; dropping response for '{}'.", response.command);
+        return;
+    };
+    match serde_json::to_string(response) {
+        Ok(json) => {
+            if let Err(e) = writeln!(file, "{}", json) {
+                error!("Failed to write control response: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to serialize control response: {}", e),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConvertMode {
+    Recursive,
+    Children,
+    NameOnly,
+}
+
+enum ControlCommand {
+    Status,
+    ReloadConfig,
+    Convert {
+        path: PathBuf,
+        target: NormalizationTarget,
+        mode: ConvertMode,
+    },
+    AddRecursiveWatch(String),
+    AddExclude(String),
+    Remove(String),
+    Stop,
+    Pause,
+    Resume,
+}
+
+fn parse_command(line: &str) -> Result<ControlCommand, String> {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+        Some("status") => Ok(ControlCommand::Status),
+        Some("reload-config") => Ok(ControlCommand::ReloadConfig),
+        Some("stop") => Ok(ControlCommand::Stop),
+        Some("pause") => Ok(ControlCommand::Pause),
+        Some("resume") => Ok(ControlCommand::Resume),
+        Some("convert") => {
+            let path = parts.next().ok_or("convert requires a path")?;
+            let target = match parts.next() {
+                Some("nfc") => NormalizationTarget::NFC,
+                Some("nfd") => NormalizationTarget::NFD,
+                other => return Err(format!("unknown conversion target: {}", other.unwrap_or(""))),
+            };
+            let mode = match parts.next() {
+                Some("recursive") => ConvertMode::Recursive,
+                Some("children") => ConvertMode::Children,
+                Some("name-only") => ConvertMode::NameOnly,
+                other => return Err(format!("unknown convert mode: {}", other.unwrap_or(""))),
+            };
+            Ok(ControlCommand::Convert {
+                path: PathBuf::from(path),
+                target,
+                mode,
+            })
+        }
+        Some("add-recursive") => {
+            let path = parts.next().ok_or("add-recursive requires a path")?;
+            Ok(ControlCommand::AddRecursiveWatch(path.to_string()))
+        }
+        Some("add-exclude") => {
+            let path = parts.next().ok_or("add-exclude requires a path")?;
+            Ok(ControlCommand::AddExclude(path.to_string()))
+        }
+        Some("remove") => {
+            let path = parts.next().ok_or("remove requires a path")?;
+            Ok(ControlCommand::Remove(path.to_string()))
+        }
+        Some(other) => Err(format!("unknown command: {}", other)),
+        None => Err("empty command".to_string()),
+    }
+}
+
+fn handle_convert(path: &Path, target: NormalizationTarget, mode: ConvertMode) -> Result<(), String> {
+    let result = match mode {
+        ConvertMode::NameOnly => normalize_single_file(path, target, None, CollisionStrategy::default()),
+        ConvertMode::Recursive | ConvertMode::Children => normalize_directory(
+            path,
+            mode == ConvertMode::Recursive,
+            target,
+            &ExcludeSet::default(),
+            None,
+            None,
+            None,
+            CollisionStrategy::default(),
+        ),
+    };
+    result.map_err(|e| e.to_string())
+}
+
+fn handle_command(
+    line: &str,
+    status: &Mutex<StatusSnapshot>,
+    events: &tokio::sync::mpsc::Sender<ControlEvent>,
+) -> ControlResponse {
+    let command = match parse_command(line) {
+        Ok(c) => c,
+        Err(message) => {
+            return ControlResponse {
+                command: line.to_string(),
+                success: false,
+                message,
+                status: None,
+            }
+        }
+    };
+
+    match command {
+        ControlCommand::Status => {
+            let snapshot = status
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone();
+            ControlResponse {
+                command: "status".to_string(),
+                success: true,
+                message: "ok".to_string(),
+                status: Some(snapshot),
+            }
+        }
+        ControlCommand::ReloadConfig => {
+            let _ = events.blocking_send(ControlEvent::ReloadConfig);
+            ControlResponse {
+                command: "reload-config".to_string(),
+                success: true,
+                message: "reload requested".to_string(),
+                status: None,
+            }
+        }
+        ControlCommand::Stop => {
+            let _ = events.blocking_send(ControlEvent::Stop);
+            ControlResponse {
+                command: "stop".to_string(),
+                success: true,
+                message: "stop requested".to_string(),
+                status: None,
+            }
+        }
+        ControlCommand::Convert { path, target, mode } => match handle_convert(&path, target, mode) {
+            Ok(()) => ControlResponse {
+                command: "convert".to_string(),
+                success: true,
+                message: format!("converted {}", path.display()),
+                status: None,
+            },
+            Err(message) => ControlResponse {
+                command: "convert".to_string(),
+                success: false,
+                message,
+                status: None,
+            },
+        },
+        ControlCommand::AddRecursiveWatch(path) => resolve_and_forward(
+            &path,
+            "add-recursive",
+            "watching",
+            events,
+            ControlEvent::AddRecursiveWatch,
+        ),
+        ControlCommand::AddExclude(path) => resolve_and_forward(
+            &path,
+            "add-exclude",
+            "excluding",
+            events,
+            ControlEvent::AddExclude,
+        ),
+        ControlCommand::Remove(path) => {
+            let _ = events.blocking_send(ControlEvent::Remove(path.clone()));
+            ControlResponse {
+                command: "remove".to_string(),
+                success: true,
+                message: "remove requested".to_string(),
+                status: None,
+            }
+        }
+        ControlCommand::Pause => {
+            let _ = events.blocking_send(ControlEvent::Pause);
+            ControlResponse {
+                command: "pause".to_string(),
+                success: true,
+                message: "pause requested".to_string(),
+                status: None,
+            }
+        }
+        ControlCommand::Resume => {
+            let _ = events.blocking_send(ControlEvent::Resume);
+            ControlResponse {
+                command: "resume".to_string(),
+                success: true,
+                message: "resume requested".to_string(),
+                status: None,
+            }
+        }
+    }
+}
+
+/// Shared by `add-recursive` and `add-exclude`: validates `raw` resolves to
+/// an existing directory (the same `process_path` validation the config
+/// loader applies to watch paths) before forwarding the canonical path to
+/// `start_watcher`'s event loop, which owns the `Config` these mutate.
+fn resolve_and_forward(
+    raw: &str,
+    command: &str,
+    verb: &str,
+    events: &tokio::sync::mpsc::Sender<ControlEvent>,
+    to_event: impl FnOnce(PathBuf) -> ControlEvent,
+) -> ControlResponse {
+    match config::resolve_watch_path(raw) {
+        Some(resolved) => {
+            let message = format!("{} {}", verb, resolved.display());
+            let _ = events.blocking_send(to_event(resolved));
+            ControlResponse {
+                command: command.to_string(),
+                success: true,
+                message,
+                status: None,
+            }
+        }
+        None => ControlResponse {
+            command: command.to_string(),
+            success: false,
+            message: format!("{} is not an existing directory", raw),
+            status: None,
+        },
+    }
+}
+
+/// Blocks reading `msg_in` line by line, dispatching each as a command and
+/// writing its result to `result_out`. Opening a FIFO for reading blocks
+/// until a writer connects, so this runs on its own OS thread rather than
+/// the async runtime; once a client disconnects (EOF), the loop reopens
+/// `msg_in` to accept the next one.
+fn control_loop(
+    msg_in: PathBuf,
+    result_out: PathBuf,
+    status: Arc<Mutex<StatusSnapshot>>,
+    events: tokio::sync::mpsc::Sender<ControlEvent>,
+) {
+    loop {
+        let file = match File::open(&msg_in) {
+            Ok(f) => f,
+            Err(e) => {
+                error!("Failed to open control fifo {}: {}", msg_in.display(), e);
+                return;
+            }
+        };
+
+        for line in BufReader::new(file).lines() {
+            let Ok(line) = line else { break };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            info!("Control command received: {}", line);
+            let response = handle_command(line, &status, &events);
+            write_result(&result_out, &response);
+        }
+    }
+}
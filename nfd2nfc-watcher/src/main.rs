@@ -1,4 +1,6 @@
+mod control;
 mod handler;
+mod poll;
 mod watcher;
 use log::info;
 use nfd2nfc_common::config;
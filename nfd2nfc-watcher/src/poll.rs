@@ -0,0 +1,154 @@
+//! Poll-based watching for filesystems that FSEvents can't see into (SMB/NFS
+//! mounts, some virtualized volumes). Each poll path runs on its own thread
+//! that periodically walks the subtree, diffs it against the previously
+//! seen entries by mtime, and normalizes anything new or changed.
+
+use log::{error, info};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+/// How often a poll thread wakes to check its stop flag while waiting out
+/// the rest of its (potentially much longer) scan interval, so `stop` takes
+/// effect promptly instead of only between full scans.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// A running poll thread, held so it can be stopped and joined when its path
+/// is removed (see `watcher::remove_path`) or the watcher shuts down.
+pub struct PollHandle {
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl PollHandle {
+    /// Signals the poll thread to stop at its next check and blocks until it
+    /// exits.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let _ = self.thread.join();
+    }
+}
+
+/// Spawns a thread that polls `path`'s subtree every `interval`, returning a
+/// handle that can later stop it.
+pub fn spawn(path: PathBuf, interval: Duration) -> PollHandle {
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread_stop = stop.clone();
+    let thread = std::thread::spawn(move || poll_loop(path, interval, thread_stop));
+    PollHandle { stop, thread }
+}
+
+fn poll_loop(root: PathBuf, interval: Duration, stop: Arc<AtomicBool>) {
+    info!(
+        " + Poll-watching {} every {}s.",
+        root.display(),
+        interval.as_secs()
+    );
+
+    let mut known_mtimes: HashMap<PathBuf, SystemTime> = HashMap::new();
+
+    while !stop.load(Ordering::Relaxed) {
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+        walk(&root, &mut known_mtimes, &mut seen);
+
+        // Drop entries that vanished since the last pass.
+        known_mtimes.retain(|path, _| seen.contains(path));
+
+        if sleep_or_stop(interval, &stop) {
+            break;
+        }
+    }
+
+    info!(" - Stopped poll-watching {}.", root.display());
+}
+
+/// Sleeps for `interval`, checking `stop` every `STOP_CHECK_INTERVAL` instead
+/// of in one long sleep, so a stop request lands promptly even when
+/// `interval` is minutes long. Returns true if it woke up early because
+/// `stop` was set.
+fn sleep_or_stop(interval: Duration, stop: &AtomicBool) -> bool {
+    let mut remaining = interval;
+    while remaining > Duration::ZERO {
+        if stop.load(Ordering::Relaxed) {
+            return true;
+        }
+        let tick = remaining.min(STOP_CHECK_INTERVAL);
+        std::thread::sleep(tick);
+        remaining -= tick;
+    }
+    stop.load(Ordering::Relaxed)
+}
+
+fn walk(root: &PathBuf, known_mtimes: &mut HashMap<PathBuf, SystemTime>, seen: &mut HashSet<PathBuf>) {
+    let mut pending = VecDeque::new();
+    pending.push_back(root.clone());
+
+    while let Some(dir) = pending.pop_front() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(rd) => rd,
+            Err(e) => {
+                error!("Failed to read directory {}: {}", dir.display(), e);
+                continue;
+            }
+        };
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(e) => e,
+                Err(e) => {
+                    error!("Failed to read entry in {}: {}", dir.display(), e);
+                    continue;
+                }
+            };
+
+            let entry_path = entry.path();
+            let metadata = match entry.metadata() {
+                Ok(m) => m,
+                Err(e) => {
+                    error!("Failed to get metadata for {}: {}", entry_path.display(), e);
+                    continue;
+                }
+            };
+
+            seen.insert(entry_path.clone());
+
+            let mtime = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            let changed = known_mtimes
+                .get(&entry_path)
+                .map_or(true, |prev| *prev != mtime);
+            known_mtimes.insert(entry_path.clone(), mtime);
+
+            if metadata.is_dir() {
+                pending.push_back(entry_path.clone());
+            }
+
+            if changed {
+                normalize_if_needed(&entry_path);
+            }
+        }
+    }
+}
+
+/// Renames `path` to its NFC form if its file name isn't already NFC.
+fn normalize_if_needed(path: &PathBuf) {
+    let file_name = match path.file_name().and_then(|s| s.to_str()) {
+        Some(name) => name,
+        None => return,
+    };
+
+    if is_nfc(file_name) {
+        return;
+    }
+
+    let nfc_file_name: String = file_name.nfc().collect();
+    let new_path = path.with_file_name(&nfc_file_name);
+
+    match std::fs::rename(path, &new_path) {
+        Ok(()) => info!("Converted to NFC: {}", new_path.display()),
+        Err(e) => error!("Failed to convert {} to NFC: {}", new_path.display(), e),
+    }
+}
@@ -1,14 +1,218 @@
-use crate::config::Config;
+use crate::control::{self, ControlEvent};
 use crate::handler;
-use log::{error, info};
-use nfd2nfc_common::constants::WATCHER_LIVE_MESSAGE;
-use notify::{Error as NotifyError, Event, RecommendedWatcher, RecursiveMode, Watcher};
-use std::sync::Arc;
+use crate::poll;
+use log::{error, info, warn};
+use nfd2nfc_common::config::{self, Config, WatchEntry};
+use nfd2nfc_common::constants::{CONFIG_PATH, WATCHER_LIVE_MESSAGE};
+use nfd2nfc_common::ignore::IgnoreMatcher;
+use nfd2nfc_core::constants::HEARTBEAT_INTERVAL;
+use nfd2nfc_core::heartbeat;
+use nfd2nfc_core::normalizer::NormalizationTarget;
+use nfd2nfc_core::volumes::{fs_type_for_path, FsNormalizationPolicy};
+use notify::event::ModifyKind;
+use notify::{Error as NotifyError, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::signal::unix::{signal, SignalKind};
 use tokio::spawn;
 use tokio::sync::Semaphore;
-use unicode_normalization::is_nfc;
 
-pub async fn start_watcher(rt_handle: tokio::runtime::Handle, config: Config) {
+/// How often the debounce map is checked for entries whose deadline has
+/// passed. Deliberately finer-grained than `config.debounce_ms` itself
+/// (including its 75ms default) so a reload that shortens the window still
+/// dispatches promptly rather than waiting out the old, coarser tick.
+const DEBOUNCE_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// How long a rename's destination path is remembered as "self-generated"
+/// after `handler::handle_event` performs it, so the FSEvents notification
+/// that rename itself triggers is recognized and dropped before it's even
+/// added to the debounce map -- rather than paying for another identity
+/// lookup and a full debounce cycle just to re-discover, via
+/// `needs_conversion`, that there's nothing left to do.
+const SELF_GENERATED_TTL: Duration = Duration::from_millis(2_000);
+
+/// How many renames make up one throttle batch. After dispatching this many,
+/// the debounce tick sleeps for `config.tranquility` times however long the
+/// batch took (see `start_watcher`'s debounce tick), so a large burst leaves
+/// some of the machine's attention free instead of saturating a core.
+const THROTTLE_BATCH_SIZE: u32 = 20;
+
+/// A file's identity for debounce-map purposes: its `(device, inode)` pair
+/// when a `stat` succeeds, falling back to its path when it doesn't (e.g.
+/// the "from" half of a rename, which no longer exists under that name by
+/// the time it's looked up). Keying by inode rather than raw path means a
+/// burst of events against the same underlying file -- create followed by
+/// an editor's atomic rename-over-target, or several rapid modifies --
+/// collapses to one pending entry instead of several, and the entry always
+/// reflects the file's current on-disk name by the time it's dispatched.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum EventIdentity {
+    Inode(u64, u64),
+    Path(PathBuf),
+}
+
+fn identify(path: &Path) -> EventIdentity {
+    match std::fs::metadata(path) {
+        Ok(meta) => EventIdentity::Inode(meta.dev(), meta.ino()),
+        Err(_) => EventIdentity::Path(path.to_path_buf()),
+    }
+}
+
+/// Paths renamed by the watcher itself, not yet echoed back (or expired);
+/// see `SELF_GENERATED_TTL`. Shared with the spawned conversion tasks that
+/// populate it, since the main event loop only ever reads and prunes it.
+type SelfGeneratedSet = Arc<Mutex<HashMap<PathBuf, Instant>>>;
+
+/// Checks whether `path` is a rename the watcher just performed itself,
+/// consuming the entry if so (each self-generated rename should only
+/// suppress one echo).
+fn is_self_generated(set: &SelfGeneratedSet, path: &Path) -> bool {
+    let mut guard = set.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match guard.remove(path) {
+        Some(inserted_at) => inserted_at.elapsed() < SELF_GENERATED_TTL,
+        None => false,
+    }
+}
+
+/// Evicts entries whose `SELF_GENERATED_TTL` has passed without ever being
+/// looked up (e.g. the rename landed outside any watched root), so the set
+/// doesn't grow unbounded.
+fn prune_self_generated(set: &SelfGeneratedSet) {
+    let mut guard = set.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    guard.retain(|_, inserted_at| inserted_at.elapsed() < SELF_GENERATED_TTL);
+}
+
+/// (Re)registers the recursive and non-recursive watch paths from `config`
+/// on `watcher`. Used both at startup and after a SIGHUP-triggered reload.
+/// Entries that haven't resolved yet (see `WatchEntry`) are skipped here;
+/// `retry_unresolved_watch_paths` picks them up once their target appears.
+fn register_watch_paths(watcher: &mut RecommendedWatcher, config: &Config) {
+    for entry in &config.recursive_watch_paths {
+        let Some(path) = &entry.resolved else { continue };
+        if skip_futile_watch(path) {
+            continue;
+        }
+        match watcher.watch(path, RecursiveMode::Recursive) {
+            Ok(()) => info!(" + Watching recursive path: {}", path.display()),
+            Err(e) => error!("Failed to watch recursive path: {} - {}", path.display(), e),
+        }
+    }
+
+    for entry in &config.non_recursive_watch_paths {
+        let Some(path) = &entry.resolved else { continue };
+        if skip_futile_watch(path) {
+            continue;
+        }
+        match watcher.watch(path, RecursiveMode::NonRecursive) {
+            Ok(()) => info!(" + Watching non-recursive path: {}", path.display()),
+            Err(e) => error!(
+                "Failed to watch non-recursive path: {} - {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+}
+
+/// Whether `path` lives on a filesystem that canonically decomposes names
+/// back to NFD on write (HFS+), where watching it would just mean converting
+/// a name and immediately seeing the OS undo it. Logs a warning and returns
+/// `true` so callers can skip registering the watch entirely; unresolvable
+/// filesystem types are never treated as futile.
+fn skip_futile_watch(path: &Path) -> bool {
+    match fs_type_for_path(path) {
+        Some(fs_type) if FsNormalizationPolicy::for_fs_type(&fs_type) == FsNormalizationPolicy::Futile => {
+            warn!(
+                " - Skipping watch path {} ({} re-decomposes names to NFD; converting would just be undone).",
+                path.display(),
+                fs_type
+            );
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Collects the roots ignore patterns are evaluated relative to: every
+/// resolved recursive and non-recursive watch path currently configured.
+fn watch_roots(config: &Config) -> Vec<PathBuf> {
+    config
+        .recursive_watch_paths
+        .iter()
+        .chain(&config.non_recursive_watch_paths)
+        .filter_map(|entry| entry.resolved.clone())
+        .collect()
+}
+
+/// Stops watching every resolved path currently registered from `config`,
+/// ahead of registering a freshly reloaded set.
+fn unregister_watch_paths(watcher: &mut RecommendedWatcher, config: &Config) {
+    for entry in config
+        .recursive_watch_paths
+        .iter()
+        .chain(&config.non_recursive_watch_paths)
+    {
+        let Some(path) = &entry.resolved else { continue };
+        if let Err(e) = watcher.unwatch(path) {
+            error!("Failed to unwatch path {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// How often watch entries that failed to resolve (a removable drive
+/// that's not mounted yet, a directory not created yet) are retried.
+const UNRESOLVED_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Retries resolving any watch entry still missing its canonical path.
+/// Entries that resolve are registered on `watcher` immediately and logged,
+/// and the ignore matcher's roots are rebuilt so root-relative patterns
+/// apply to them too.
+fn retry_unresolved_watch_paths(
+    watcher: &mut RecommendedWatcher,
+    config: &mut Config,
+    ignore_matcher: &mut IgnoreMatcher,
+) {
+    let mut newly_resolved: Vec<(PathBuf, RecursiveMode)> = Vec::new();
+
+    for entry in &mut config.recursive_watch_paths {
+        if entry.resolved.is_none() {
+            if let Some(path) = config::resolve_watch_path(&entry.unresolved) {
+                newly_resolved.push((path.clone(), RecursiveMode::Recursive));
+                entry.resolved = Some(path);
+            }
+        }
+    }
+    for entry in &mut config.non_recursive_watch_paths {
+        if entry.resolved.is_none() {
+            if let Some(path) = config::resolve_watch_path(&entry.unresolved) {
+                newly_resolved.push((path.clone(), RecursiveMode::NonRecursive));
+                entry.resolved = Some(path);
+            }
+        }
+    }
+
+    if newly_resolved.is_empty() {
+        return;
+    }
+
+    for (path, mode) in &newly_resolved {
+        if skip_futile_watch(path) {
+            continue;
+        }
+        info!(" + Watch path now available; watching: {}", path.display());
+        if let Err(e) = watcher.watch(path, *mode) {
+            error!("Failed to watch newly resolved path: {} - {}", path.display(), e);
+        }
+    }
+
+    *ignore_matcher = IgnoreMatcher::compile(&config.recursive_ignore_paths, &watch_roots(config));
+}
+
+pub async fn start_watcher(rt_handle: tokio::runtime::Handle, mut config: Config) {
     let (tx, mut rx) = tokio::sync::mpsc::channel(100);
 
     let mut watcher = match RecommendedWatcher::new(
@@ -36,63 +240,393 @@ pub async fn start_watcher(rt_handle: tokio::runtime::Handle, config: Config) {
         }
     };
 
-    // Register recursive watch paths.
-    for path in &config.recursive_watch_paths {
-        match watcher.watch(&path, RecursiveMode::Recursive) {
-            Ok(()) => info!(" + Watching recursive path: {}", path.display()),
-            Err(e) => error!("Failed to watch recursive path: {} - {}", path.display(), e),
-        }
-    }
+    register_watch_paths(&mut watcher, &config);
 
-    // Register non-recursive watch paths.
-    for path in &config.non_recursive_watch_paths {
-        match watcher.watch(&path, RecursiveMode::NonRecursive) {
-            Ok(()) => info!(" + Watching non-recursive path: {}", path.display()),
-            Err(e) => error!(
-                "Failed to watch non-recursive path: {} - {}",
-                path.display(),
-                e
-            ),
-        }
+    // Poll paths don't go through `RecommendedWatcher` at all; each gets its
+    // own thread since FSEvents can't be trusted to deliver events for them.
+    // Handles are kept so `remove_path` can stop and join a poll thread
+    // whose path is removed over the control channel.
+    let mut poll_handles: HashMap<PathBuf, poll::PollHandle> = HashMap::new();
+    for poll_path in &config.poll_watch_paths {
+        poll_handles.insert(poll_path.path.clone(), poll::spawn(poll_path.path.clone(), poll_path.interval));
     }
 
     info!("{}", WATCHER_LIVE_MESSAGE);
 
+    // Compile the ignore list once; patterns can mix literal paths with
+    // gitignore-style globs (see `nfd2nfc_common::ignore`).
+    let mut ignore_matcher = IgnoreMatcher::compile(&config.recursive_ignore_paths, &watch_roots(&config));
+
+    // Reload configuration on SIGHUP instead of requiring a full
+    // launchd unload/reload, which would tear down and rebuild the FSEvents
+    // stream (and miss any changes in the gap). If the handler can't be
+    // installed, SIGHUP keeps its default action and terminates the watcher
+    // instead of reloading it.
+    let mut sighup = match signal(SignalKind::hangup()) {
+        Ok(s) => Some(s),
+        Err(e) => {
+            error!("Failed to install SIGHUP handler: {}.", e);
+            None
+        }
+    };
+
+    // Named-pipe control channel so another process can query status or
+    // drive the daemon (reload, convert, pause/resume, stop) without the TUI.
+    let (control_handle, mut control_events) = control::spawn(&config);
+
     // Limit the number of concurrently executing tasks using a semaphore.
     let semaphore = Arc::new(Semaphore::new(200));
 
-    // Process events in an asynchronous loop.
-    while let Some(res) = rx.recv().await {
-        match res {
-            Ok(event) => {
-                let event_path = match event.paths.get(0) {
-                    Some(path) => path,
-                    None => continue,
-                };
-
-                // Skip events for paths in the exclusion list.
-                if config
-                    .recursive_ignore_paths
+    // Events that passed the ignore/needs-conversion filters but are still
+    // within their debounce window, keyed by the file's identity (see
+    // `EventIdentity`) so a burst of events against the same file -- even
+    // across a rename -- collapses to the single most recently seen path.
+    let mut pending: HashMap<EventIdentity, (PathBuf, Instant)> = HashMap::new();
+    let mut debounce_tick = tokio::time::interval(DEBOUNCE_POLL_INTERVAL);
+    let mut unresolved_retry_tick = tokio::time::interval(UNRESOLVED_RETRY_INTERVAL);
+
+    // Destination paths of renames the watcher performed itself, so the
+    // FSEvents notification they generate is suppressed instead of treated
+    // as an external edit; see `SELF_GENERATED_TTL`.
+    let self_generated: SelfGeneratedSet = Arc::new(Mutex::new(HashMap::new()));
+    // How many incoming events have been dropped as self-generated so far,
+    // reported to `status_out` alongside the live pending count.
+    let mut suppressed_count: u32 = 0;
+
+    // Renames completed since the last heartbeat write, so `check_watcher_status`
+    // can tell an actively-converting watcher from one that's merely alive
+    // (see `nfd2nfc_core::heartbeat`). Conversions run as detached tasks, so
+    // this is shared rather than a plain local counter.
+    let rename_count = Arc::new(AtomicU32::new(0));
+    let mut heartbeat_tick = tokio::time::interval(HEARTBEAT_INTERVAL);
+
+    // `tranquility` throttle state: how many renames have been dispatched
+    // since the current batch started, when that batch started, and --
+    // while cooling down between batches -- the instant dispatch may resume.
+    // Quiet events just keep accumulating in `pending` during a cooldown;
+    // nothing is lost, dispatch is only ever delayed.
+    let mut batch_dispatched: u32 = 0;
+    let mut batch_start = Instant::now();
+    let mut throttle_until: Option<Instant> = None;
+
+    // Set by the control channel's `pause`/`resume` commands. Watching and
+    // debouncing continue as normal while paused; only the conversion step
+    // is skipped, so a file touched during the pause stays NFD until some
+    // later event (after `resume`) picks it up again.
+    let mut paused = false;
+
+    loop {
+        tokio::select! {
+            res = rx.recv() => {
+                let Some(res) = res else { break };
+                match res {
+                    Ok(event) => {
+                        // Rename/move events can report both the source and
+                        // destination path (notify::Event::paths[0] is the
+                        // `from`, [1] the `to`); the destination is often
+                        // the newly-named NFD file that actually needs
+                        // converting, so check every path instead of only
+                        // the first. Other event kinds are always reported
+                        // with a single path.
+                        let paths: &[PathBuf] = if matches!(event.kind, EventKind::Modify(ModifyKind::Name(_))) {
+                            &event.paths
+                        } else {
+                            &event.paths[..event.paths.len().min(1)]
+                        };
+
+                        for event_path in paths {
+                            // Skip events for paths in the exclusion list.
+                            let is_dir = event_path.is_dir();
+                            if ignore_matcher.is_ignored(event_path, is_dir) {
+                                continue;
+                            }
+
+                            if is_self_generated(&self_generated, event_path) {
+                                suppressed_count = suppressed_count.saturating_add(1);
+                                continue;
+                            }
+
+                            let file_name = match event_path.file_name().and_then(|s| s.to_str()) {
+                                Some(name) => name,
+                                None => continue,
+                            };
+                            if !config.watcher_target.needs_conversion(file_name) {
+                                continue;
+                            }
+
+                            // Each new event for the same file resets its
+                            // deadline and refreshes the path it resolves
+                            // to; a create-then-rename burst collapses to
+                            // whichever path is current when the deadline
+                            // finally passes.
+                            pending.insert(identify(event_path), (event_path.clone(), Instant::now()));
+                        }
+                    }
+                    Err(e) => error!("FS watcher error: {}", e),
+                }
+            }
+            _ = debounce_tick.tick() => {
+                let now = Instant::now();
+
+                if let Some(until) = throttle_until {
+                    if now < until {
+                        prune_self_generated(&self_generated);
+                        control_handle.update_debounce_stats(pending.len(), suppressed_count);
+                        continue;
+                    }
+                    throttle_until = None;
+                    batch_start = now;
+                }
+
+                let debounce_window = Duration::from_millis(config.debounce_ms.max(1));
+                let quiet: Vec<EventIdentity> = pending
                     .iter()
-                    .any(|ignore| event_path.starts_with(ignore))
-                {
-                    continue;
+                    .filter(|(_, (_, seen))| now.duration_since(*seen) >= debounce_window)
+                    .map(|(id, _)| id.clone())
+                    .collect();
+
+                for id in quiet {
+                    let Some((path, _)) = pending.remove(&id) else { continue };
+                    if paused {
+                        continue;
+                    }
+                    let target = config.watcher_target;
+                    let sem_clone = semaphore.clone();
+                    let rename_count = rename_count.clone();
+                    let self_generated = self_generated.clone();
+                    spawn(async move {
+                        let _permit = sem_clone.acquire_owned().await.unwrap();
+                        if let Some(new_path) = handler::handle_event(path, target).await {
+                            rename_count.fetch_add(1, Ordering::Relaxed);
+                            self_generated
+                                .lock()
+                                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                                .insert(new_path, Instant::now());
+                        }
+                    });
+
+                    batch_dispatched += 1;
+                    if batch_dispatched >= THROTTLE_BATCH_SIZE {
+                        if config.tranquility > 0.0 {
+                            let batch_duration = now.duration_since(batch_start);
+                            throttle_until = Some(now + batch_duration.mul_f64(config.tranquility));
+                        }
+                        batch_dispatched = 0;
+                        batch_start = now;
+                        if throttle_until.is_some() {
+                            break;
+                        }
+                    }
                 }
 
-                let file_name = match event_path.file_name().and_then(|s| s.to_str()) {
-                    Some(name) => name,
-                    None => continue,
-                };
-                if is_nfc(file_name) {
-                    continue;
+                prune_self_generated(&self_generated);
+                control_handle.update_debounce_stats(pending.len(), suppressed_count);
+            }
+            _ = unresolved_retry_tick.tick() => {
+                retry_unresolved_watch_paths(&mut watcher, &mut config, &mut ignore_matcher);
+            }
+            _ = heartbeat_tick.tick() => {
+                let recent_renames = rename_count.swap(0, Ordering::Relaxed);
+                heartbeat::write_heartbeat(recent_renames);
+                let renames_per_sec = recent_renames as f64 / HEARTBEAT_INTERVAL.as_secs_f64();
+                control_handle.update_rename_rate(renames_per_sec);
+            }
+            _ = async {
+                match sighup.as_mut() {
+                    Some(sig) => sig.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                info!("Received SIGHUP; reloading configuration.");
+                reload_config(&mut watcher, &mut config, &mut ignore_matcher, &mut poll_handles);
+                control_handle.update(&config);
+            }
+            Some(event) = control_events.recv() => {
+                match event {
+                    ControlEvent::ReloadConfig => {
+                        info!("Control channel requested configuration reload.");
+                        reload_config(&mut watcher, &mut config, &mut ignore_matcher, &mut poll_handles);
+                        control_handle.update(&config);
+                    }
+                    ControlEvent::Stop => {
+                        info!("Control channel requested shutdown.");
+                        break;
+                    }
+                    ControlEvent::AddRecursiveWatch(path) => {
+                        add_recursive_watch(&mut watcher, &mut config, &mut ignore_matcher, path);
+                        control_handle.update(&config);
+                    }
+                    ControlEvent::AddExclude(path) => {
+                        add_exclude_path(&mut config, &mut ignore_matcher, path);
+                        control_handle.update(&config);
+                    }
+                    ControlEvent::Remove(raw) => {
+                        remove_path(&mut watcher, &mut config, &mut ignore_matcher, &mut poll_handles, &raw);
+                        control_handle.update(&config);
+                    }
+                    ControlEvent::Pause => {
+                        info!("Control channel requested pause; NFD->NFC conversion suspended.");
+                        paused = true;
+                        control_handle.set_paused(true);
+                    }
+                    ControlEvent::Resume => {
+                        info!("Control channel requested resume; NFD->NFC conversion re-enabled.");
+                        paused = false;
+                        control_handle.set_paused(false);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Registers a new recursive watch path requested over the control channel
+/// (`add-recursive`), watching it immediately and persisting it so it
+/// survives a restart. `path` is already canonicalized by
+/// `config::resolve_watch_path` before this runs.
+fn add_recursive_watch(
+    watcher: &mut RecommendedWatcher,
+    config: &mut Config,
+    ignore_matcher: &mut IgnoreMatcher,
+    path: PathBuf,
+) {
+    if config
+        .recursive_watch_paths
+        .iter()
+        .any(|e| e.resolved.as_deref() == Some(path.as_path()))
+    {
+        info!(" + {} is already a recursive watch path.", path.display());
+        return;
+    }
+
+    if !skip_futile_watch(&path) {
+        if let Err(e) = watcher.watch(&path, RecursiveMode::Recursive) {
+            error!("Failed to watch recursive path {}: {}", path.display(), e);
+            return;
+        }
+        info!(" + Watching recursive path: {}", path.display());
+    }
+
+    config.recursive_watch_paths.push(WatchEntry {
+        unresolved: path.to_string_lossy().into_owned(),
+        resolved: Some(path),
+    });
+    *ignore_matcher = IgnoreMatcher::compile(&config.recursive_ignore_paths, &watch_roots(config));
+    persist_config(config);
+}
+
+/// Adds a new exclude pattern requested over the control channel
+/// (`add-exclude`) and persists it. `path` is already canonicalized by
+/// `config::resolve_watch_path` before this runs.
+fn add_exclude_path(config: &mut Config, ignore_matcher: &mut IgnoreMatcher, path: PathBuf) {
+    if config.recursive_ignore_paths.contains(&path) {
+        info!(" + {} is already excluded.", path.display());
+        return;
+    }
+
+    info!(" + Excluding: {}", path.display());
+    config.recursive_ignore_paths.push(path);
+    *ignore_matcher = IgnoreMatcher::compile(&config.recursive_ignore_paths, &watch_roots(config));
+    persist_config(config);
+}
+
+/// Removes whichever watch or exclude entry `raw` names (requested over the
+/// control channel as `remove <path>`), matching either the entry's original
+/// unresolved text or its resolved canonical path -- `raw` can't always be
+/// re-resolved itself, since the target directory may already be gone.
+fn remove_path(
+    watcher: &mut RecommendedWatcher,
+    config: &mut Config,
+    ignore_matcher: &mut IgnoreMatcher,
+    poll_handles: &mut HashMap<PathBuf, poll::PollHandle>,
+    raw: &str,
+) {
+    let resolved = config::resolve_watch_path(raw);
+    let matches = |unresolved: &str, entry_resolved: Option<&PathBuf>| {
+        unresolved == raw || (resolved.is_some() && entry_resolved == resolved.as_ref())
+    };
+
+    let mut removed = false;
+    for entries in [&mut config.recursive_watch_paths, &mut config.non_recursive_watch_paths] {
+        entries.retain(|entry| {
+            let keep = !matches(&entry.unresolved, entry.resolved.as_ref());
+            if !keep {
+                removed = true;
+                if let Some(path) = &entry.resolved {
+                    if let Err(e) = watcher.unwatch(path) {
+                        error!("Failed to unwatch {}: {}", path.display(), e);
+                    }
                 }
-                let sem_clone = semaphore.clone();
-                spawn(async move {
-                    let _permit = sem_clone.acquire_owned().await.unwrap();
-                    handler::handle_event(event).await;
-                });
             }
-            Err(e) => error!("FS watcher error: {}", e),
+            keep
+        });
+    }
+
+    let before = config.poll_watch_paths.len();
+    config.poll_watch_paths.retain(|poll_path| {
+        let keep = poll_path.path.to_string_lossy() != raw && Some(&poll_path.path) != resolved.as_ref();
+        if !keep {
+            if let Some(handle) = poll_handles.remove(&poll_path.path) {
+                handle.stop();
+                info!(" - Stopped poll thread for {}.", poll_path.path.display());
+            }
         }
+        keep
+    });
+    removed |= config.poll_watch_paths.len() != before;
+
+    let before = config.recursive_ignore_paths.len();
+    config
+        .recursive_ignore_paths
+        .retain(|p| p.to_string_lossy() != raw && Some(p) != resolved.as_ref());
+    removed |= config.recursive_ignore_paths.len() != before;
+
+    if !removed {
+        info!(" - Control channel asked to remove '{}', but it isn't watched or excluded.", raw);
+        return;
     }
+
+    *ignore_matcher = IgnoreMatcher::compile(&config.recursive_ignore_paths, &watch_roots(config));
+    info!(" + Removed '{}' from the watch/exclude set.", raw);
+    persist_config(config);
+}
+
+/// Persists `config` to `CONFIG_PATH` after a control-channel mutation, the
+/// same way the TUI and `reload_config` keep the file in sync with the live
+/// watch/exclude set.
+fn persist_config(config: &Config) {
+    if let Err(e) = config.save_to_file(&CONFIG_PATH) {
+        error!("Failed to persist configuration after a control-channel change: {}", e);
+    }
+}
+
+/// Re-reads `CONFIG_PATH` and rebuilds the watch/ignore set in place,
+/// keeping the existing FSEvents stream (and poll threads) alive.
+fn reload_config(
+    watcher: &mut RecommendedWatcher,
+    config: &mut Config,
+    ignore_matcher: &mut IgnoreMatcher,
+    poll_handles: &mut HashMap<PathBuf, poll::PollHandle>,
+) {
+    let new_config = match config::load_config() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            error!("Failed to reload configuration: {}. Keeping the current watch set.", e);
+            return;
+        }
+    };
+
+    unregister_watch_paths(watcher, config);
+    register_watch_paths(watcher, &new_config);
+    *ignore_matcher = IgnoreMatcher::compile(&new_config.recursive_ignore_paths, &watch_roots(&new_config));
+
+    let old_poll_paths: Vec<PathBuf> = config.poll_watch_paths.iter().map(|p| p.path.clone()).collect();
+    for poll_path in &new_config.poll_watch_paths {
+        if !old_poll_paths.contains(&poll_path.path) {
+            poll_handles.insert(poll_path.path.clone(), poll::spawn(poll_path.path.clone(), poll_path.interval));
+        }
+    }
+
+    *config = new_config;
+    info!(" + Configuration reloaded; watch and ignore set rebuilt.");
 }
@@ -1,37 +1,32 @@
+use crate::normalizer;
+use crate::service_manager::{self, ServiceManager};
 use log::{error, info};
-use nfd2nfc_common::config::{read_or_default_config, RawConfig};
+use nfd2nfc_common::config::{read_or_default_config, Config, PollWatchEntry, RawConfig};
 use nfd2nfc_common::constants::{
-    CONFIG_PATH, HOME_DIR, NFD2NFC_SERVICE_LABEL, WATCHER_LIVE_MESSAGE,
+    CONFIG_PATH, CONTROL_DIR, CONTROL_MSG_IN_FILE, CONTROL_STATUS_OUT_FILE,
 };
+use nfd2nfc_common::ignore::{is_glob_pattern, IgnoreMatcher};
 use nfd2nfc_common::utils::expand_tilde;
-use once_cell::sync::Lazy;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-use std::process::{Command, Stdio};
-use std::sync::mpsc::{self, Receiver, Sender};
+use nfd2nfc_core::heartbeat::{self, WatcherHealth};
+use nfd2nfc_core::volumes::{fs_type_for_path, FsNormalizationPolicy};
+use serde::Deserialize;
+use std::ffi::CString;
+use std::fs::File;
+use std::io::Write;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::FromRawFd;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::time::Duration;
 
-pub static PLIST_PATH: Lazy<String> = Lazy::new(|| {
-    let path = format!(
-        "{}/Library/LaunchAgents/{}.plist",
-        HOME_DIR.display(),
-        NFD2NFC_SERVICE_LABEL
-    );
-    let plist_path = std::path::Path::new(&path);
-    if !plist_path.exists() {
-        error!("Plist file not found at {}.", path);
-        std::process::exit(1);
-    }
-    path
-});
-
 pub fn cmd_start_watcher() {
-    if check_watcher_status() {
+    let manager = service_manager::current();
+    if manager.status() {
         println!("nfd2nfc-watcher service is already running.");
         std::process::exit(0);
     }
 
-    match launch_watcher_and_confirm() {
+    match manager.start() {
         Ok(_) => {
             println!("nfd2nfc-watcher service started.");
         }
@@ -43,21 +38,29 @@ pub fn cmd_start_watcher() {
 }
 
 pub fn cmd_stop_watcher() {
-    if !check_watcher_status() {
+    let manager = service_manager::current();
+    if !manager.status() {
         println!("nfd2nfc-watcher service is not running.");
         std::process::exit(0);
     }
-    unload_watcher_service();
+    if let Err(e) = manager.stop() {
+        error!("{}", e);
+        std::process::exit(1);
+    }
     println!("nfd2nfc-watcher service stopped.");
 }
 
 pub fn cmd_restart_watcher() {
-    if !check_watcher_status() {
+    let manager = service_manager::current();
+    if !manager.status() {
         println!("nfd2nfc-watcher service is not running.");
         return;
     }
-    unload_watcher_service();
-    match launch_watcher_and_confirm() {
+    if let Err(e) = manager.stop() {
+        error!("{}", e);
+        std::process::exit(1);
+    }
+    match manager.start() {
         Ok(_) => {
             println!("nfd2nfc-watcher service restarted.");
         }
@@ -69,10 +72,142 @@ pub fn cmd_restart_watcher() {
 }
 
 pub fn cmd_status_watcher() {
-    if check_watcher_status() {
-        println!("nfd2nfc-watcher service is running.");
-    } else {
-        println!("nfd2nfc-watcher service is not running.");
+    match check_watcher_status() {
+        WatcherHealth::Active => println!("nfd2nfc-watcher service is running (converting)."),
+        WatcherHealth::Idle => println!("nfd2nfc-watcher service is running (idle)."),
+        WatcherHealth::Dead => println!("nfd2nfc-watcher service is not running."),
+        WatcherHealth::Unknown => println!("nfd2nfc-watcher service status is unknown."),
+    }
+    warn_futile_watch_paths();
+}
+
+/// Watcher liveness for the TUI's header and Home tab, and for `status`.
+/// Distinguishes a watcher that's actively converting from one that's up but
+/// quiescent, and catches a process that's still running but has stopped
+/// reporting in (its heartbeat file, see [`nfd2nfc_core::heartbeat`], gone
+/// stale) rather than reporting it as healthy just because `launchctl`/
+/// `systemctl` still sees the process.
+pub fn check_watcher_status() -> WatcherHealth {
+    if !service_manager::current().status() {
+        return WatcherHealth::Dead;
+    }
+    heartbeat::read_health()
+}
+
+/// The subset of `nfd2nfc-watcher::control::StatusSnapshot` the TUI cares
+/// about. `nfd2nfc-watcher` has no library target, so this mirrors just the
+/// fields read here by field name rather than importing the real type;
+/// unrecognized fields in `status_out` (everything else in the snapshot) are
+/// ignored by serde's default behavior.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct WatcherStats {
+    pub pending_events: usize,
+    pub suppressed_events: u32,
+    pub renames_per_sec: f64,
+}
+
+/// Reads the watcher's live `status_out` file for the Home/Logs tabs to
+/// display alongside `watcher_health`. Returns `None` if the watcher hasn't
+/// written one yet (not running, or the control channel failed to start) or
+/// it can't be parsed.
+pub fn read_watcher_stats() -> Option<WatcherStats> {
+    let path = CONTROL_DIR.join(CONTROL_STATUS_OUT_FILE);
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Long-lived handle to the watcher's FIFO control channel
+/// (`nfd2nfc-watcher::control`), kept open so the TUI can push pause/resume
+/// commands to an already-running watcher one line at a time instead of
+/// reconnecting -- and paying `cmd_restart_watcher`'s full stop/start round
+/// trip -- for each one.
+pub struct WatcherControlSender {
+    msg_in: File,
+}
+
+impl WatcherControlSender {
+    fn send(&mut self, command: &str) -> Result<(), String> {
+        writeln!(self.msg_in, "{}", command).map_err(|e| e.to_string())
+    }
+
+    pub fn pause(&mut self) -> Result<(), String> {
+        self.send("pause")
+    }
+
+    pub fn resume(&mut self) -> Result<(), String> {
+        self.send("resume")
+    }
+}
+
+/// Opens the watcher's `msg_in` FIFO for writing. Returns `None` if the
+/// watcher isn't running to have it open for reading, in which case
+/// pause/resume have nothing to talk to.
+///
+/// Opened non-blocking (mirroring `nfd2nfc-watcher::control`'s own
+/// `open_nonblocking_writer`), since a plain blocking open on a FIFO with no
+/// reader attached hangs forever -- exactly the control thread not having
+/// started reading `msg_in` yet right after a restart, or having died
+/// without the TUI's `watcher_health` catching up yet -- which would freeze
+/// the whole TUI on the UI thread with no way to time out or cancel.
+pub fn connect_control_channel() -> Option<WatcherControlSender> {
+    let msg_in = CONTROL_DIR.join(CONTROL_MSG_IN_FILE);
+    let c_path = CString::new(msg_in.as_os_str().as_bytes()).ok()?;
+    let fd = unsafe { libc::open(c_path.as_ptr(), libc::O_WRONLY | libc::O_NONBLOCK) };
+    if fd < 0 {
+        return None;
+    }
+    let msg_in = unsafe { File::from_raw_fd(fd) };
+    Some(WatcherControlSender { msg_in })
+}
+
+/// Warns about any configured watch path living on a filesystem that
+/// canonically re-decomposes names back to NFD on write (HFS+), where the
+/// watcher's conversions are undone by the OS as soon as they happen.
+fn warn_futile_watch_paths() {
+    let Ok(raw_config) = read_or_default_config(&*CONFIG_PATH) else {
+        return;
+    };
+
+    let futile: Vec<&String> = raw_config
+        .recursive_watch_paths
+        .iter()
+        .chain(&raw_config.non_recursive_watch_paths)
+        .filter(|path| !is_glob_pattern(path))
+        .filter(|path| is_futile(path))
+        .collect();
+
+    if futile.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("Warning: these watch paths are on a filesystem (HFS+) that re-decomposes names back to NFD, so converting them is futile:");
+    for path in futile {
+        println!(" - {}", path);
+    }
+}
+
+/// Whether `path` resolves onto a filesystem where NFC conversion is
+/// immediately undone by the OS. Unresolvable paths (not mounted, typo'd)
+/// are treated as not futile so they aren't flagged spuriously.
+fn is_futile(path: &str) -> bool {
+    fs_type_for_path(&expand_tilde(path))
+        .is_some_and(|fs_type| FsNormalizationPolicy::for_fs_type(&fs_type) == FsNormalizationPolicy::Futile)
+}
+
+/// `" [fs_type, policy]"` suffix for `watch list` output, or empty if `path`
+/// is a glob pattern or doesn't currently resolve to a mounted filesystem
+/// (e.g. a removable volume that's unplugged).
+fn fs_annotation(path: &str) -> String {
+    if is_glob_pattern(path) {
+        return String::new();
+    }
+    match fs_type_for_path(&expand_tilde(path)) {
+        Some(fs_type) => {
+            let policy = FsNormalizationPolicy::for_fs_type(&fs_type);
+            format!(" [{}, {}]", fs_type, policy.as_str())
+        }
+        None => String::new(),
     }
 }
 
@@ -92,13 +227,13 @@ pub fn cmd_list_watch_paths() {
 
     println!("[Recursive Watch Paths]");
     for path in raw_config.recursive_watch_paths {
-        println!(" - {}", path);
+        println!(" - {}{}", path, fs_annotation(&path));
     }
     println!();
 
     println!("[Non-Recursive Watch Paths]");
     for path in raw_config.non_recursive_watch_paths {
-        println!(" - {}", path);
+        println!(" - {}{}", path, fs_annotation(&path));
     }
     println!();
 
@@ -106,6 +241,12 @@ pub fn cmd_list_watch_paths() {
     for path in raw_config.recursive_ignore_paths {
         println!(" - {}", path);
     }
+    println!();
+
+    println!("[Poll Watch Paths]");
+    for entry in raw_config.poll_watch_paths {
+        println!(" - {} (every {}s)", entry.path, entry.interval_secs);
+    }
 }
 
 pub fn cmd_add_watch_path(path: &str, mode: WatchMode) {
@@ -137,6 +278,12 @@ pub fn cmd_add_watch_path(path: &str, mode: WatchMode) {
                 .recursive_ignore_paths
                 .push(canonical_path.clone());
         }
+        WatchMode::Poll { interval } => {
+            raw_config.poll_watch_paths.push(PollWatchEntry {
+                path: canonical_path.clone(),
+                interval_secs: interval.as_secs(),
+            });
+        }
     }
 
     // Update the config file.
@@ -147,13 +294,36 @@ pub fn cmd_add_watch_path(path: &str, mode: WatchMode) {
 
     // Determine a human-friendly description for the mode.
     let mode_desc = match mode {
-        WatchMode::Recursive => "recursive watch",
-        WatchMode::NonRecursive => "non-recursive watch",
-        WatchMode::Ignore => "ignore",
+        WatchMode::Recursive => "recursive watch".to_string(),
+        WatchMode::NonRecursive => "non-recursive watch".to_string(),
+        WatchMode::Ignore => "ignore".to_string(),
+        WatchMode::Poll { interval } => format!("poll watch (every {}s)", interval.as_secs()),
     };
 
     println!("Successfully added {} path: {}", mode_desc, canonical_path);
 
+    // Run an initial normalization sweep so pre-existing NFD names under the
+    // new path don't sit unconverted until the watcher sees an event for
+    // them. Only applies to native watch modes; poll mode bulk-normalizes
+    // the whole subtree on its own first pass.
+    if matches!(mode, WatchMode::Recursive | WatchMode::NonRecursive) {
+        let recursive = matches!(mode, WatchMode::Recursive);
+        let refined_config: Config = raw_config.clone().into();
+        let roots: Vec<PathBuf> = refined_config
+            .recursive_watch_paths
+            .iter()
+            .chain(&refined_config.non_recursive_watch_paths)
+            .filter_map(|entry| entry.resolved.clone())
+            .collect();
+        let ignore_matcher = IgnoreMatcher::compile(&refined_config.recursive_ignore_paths, &roots);
+        let (normalized, total) = normalizer::sweep_normalize_to_nfc(
+            Path::new(&canonical_path),
+            recursive,
+            &ignore_matcher,
+        );
+        println!("normalized {} of {} entries", normalized, total);
+    }
+
     // Reload config to apply changes.
     reload_config();
 }
@@ -189,6 +359,15 @@ pub fn cmd_remove_watch_path(path: &str) {
             &mut raw_config.non_recursive_watch_paths,
         );
         remove_from("ignore", &mut raw_config.recursive_ignore_paths);
+
+        let initial = raw_config.poll_watch_paths.len();
+        raw_config
+            .poll_watch_paths
+            .retain(|entry| entry.path != canonical_path);
+        if raw_config.poll_watch_paths.len() < initial {
+            info!("Removed '{}' from poll watch paths.", canonical_path);
+            found = true;
+        }
     }
 
     // Save the updated config.
@@ -210,97 +389,29 @@ pub fn cmd_remove_watch_path(path: &str) {
     reload_config();
 }
 
-pub fn launch_watcher_and_confirm() -> Result<String, String> {
-    // 1. Start reading the log stream before loading the watcher.
-    let (tx, rx): (Sender<String>, Receiver<String>) = mpsc::channel();
-    std::thread::spawn(move || {
-        log_stream_reader(tx);
-    });
+/// Reloads the watcher's configuration, preferring the backend's in-place
+/// reload (e.g. SIGHUP) so its event stream isn't torn down, and falling
+/// back to a full stop+start if that isn't supported or fails.
+pub fn reload_config() {
+    let manager = service_manager::current();
+    let running = manager.status();
 
-    // 2. Load the watcher service.
-    let plist = &*PLIST_PATH;
-    let status = Command::new("launchctl")
-        .arg("load")
-        .arg("-w")
-        .arg(plist)
-        .status()
-        .map_err(|e| format!("Failed to start watcher: {}", e))?;
-
-    if !status.success() {
-        return Err(format!("Failed to start watcher: {}", status));
-    }
-
-    // Poll logs until live message appears.
-    let timeout = Duration::from_secs_f32(0.3);
-    let mut logs_accumulated = String::new();
-
-    loop {
-        match rx.recv_timeout(timeout) {
-            Ok(msg) => {
-                logs_accumulated.push_str(&msg);
-                logs_accumulated.push('\n');
-                if msg.contains(WATCHER_LIVE_MESSAGE) {
-                    return Ok(logs_accumulated);
-                }
-            }
-            Err(mpsc::RecvTimeoutError::Timeout) => {
-                if check_watcher_status() {
-                    continue;
-                } else {
-                    logs_accumulated.push_str("\nTimeout reached and watcher not running.");
-                    return Err(logs_accumulated);
-                }
-            }
-            Err(mpsc::RecvTimeoutError::Disconnected) => break,
-        }
+    if running && manager.reload() {
+        return;
     }
-    Err(logs_accumulated)
-}
 
-fn unload_watcher_service() {
-    let plist = &*PLIST_PATH;
-    let status = Command::new("launchctl")
-        .arg("unload")
-        .arg("-w")
-        .arg(plist)
-        .status();
-
-    match status {
-        Ok(s) if s.success() => {}
-        Ok(s) => {
-            error!("Failed to stop service: {}", s);
+    let result = if running {
+        if let Err(e) = manager.stop() {
+            error!("{}", e);
             std::process::exit(1);
         }
-        Err(e) => {
-            error!("Failed to stop service: {}", e);
+        manager.start()
+    } else {
+        let res = manager.start();
+        if let Err(e) = manager.stop() {
+            error!("{}", e);
             std::process::exit(1);
         }
-    }
-}
-
-pub fn check_watcher_status() -> bool {
-    let output = Command::new("launchctl")
-        .arg("list")
-        .output()
-        .unwrap_or_else(|e| {
-            error!("Failed to execute launchctl list: {}", e);
-            std::process::exit(1);
-        });
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    if stdout.contains(NFD2NFC_SERVICE_LABEL) {
-        true
-    } else {
-        false
-    }
-}
-
-pub fn reload_config() {
-    let result = if check_watcher_status() {
-        unload_watcher_service();
-        launch_watcher_and_confirm()
-    } else {
-        let res = launch_watcher_and_confirm();
-        unload_watcher_service();
         res
     };
 
@@ -319,81 +430,63 @@ pub fn reload_config() {
     }
 }
 
-pub fn cmd_stream_logs() {
+fn format_log_line(entry: &service_manager::LogEntry) -> String {
+    if entry.category.is_empty() {
+        format!("{} [{}] {}", entry.timestamp, entry.level, entry.message)
+    } else {
+        format!(
+            "{} [{}] ({}) {}",
+            entry.timestamp, entry.level, entry.category, entry.message
+        )
+    }
+}
+
+/// Exits the process with a usage error if `--level` isn't one of the
+/// recognized severity names.
+fn resolve_min_level(min_level: Option<&str>) -> Option<service_manager::Level> {
+    min_level.map(|s| {
+        service_manager::Level::parse(s).unwrap_or_else(|| {
+            error!(
+                "Invalid --level '{}'; expected one of: debug, info, error, fault.",
+                s
+            );
+            std::process::exit(1);
+        })
+    })
+}
+
+pub fn cmd_stream_logs(min_level: Option<&str>) {
     println!("nfd2nfc: Streaming log output... (Press Ctrl+C to exit)");
+    let min_level = resolve_min_level(min_level);
 
-    let (tx, rx): (Sender<String>, Receiver<String>) = mpsc::channel();
+    let (tx, rx) = mpsc::channel();
     std::thread::spawn(move || {
-        log_stream_reader(tx);
+        service_manager::current().stream_logs(tx);
     });
 
-    for message in rx {
-        println!("{}", message);
-    }
-}
-
-fn log_stream_reader(tx: Sender<String>) {
-    let mut child = Command::new("log")
-        .args(&[
-            "stream",
-            "--predicate",
-            &format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL),
-            "--style",
-            "json",
-        ])
-        .stdout(Stdio::piped())
-        .spawn()
-        .expect("Failed to start log streaming");
-
-    let stdout = child.stdout.take().expect("Failed to capture stdout");
-    let reader = BufReader::new(stdout);
-
-    for line in reader.lines() {
-        match line {
-            Ok(l) => {
-                const EVENT_MESSAGE_PREFIX: &str = "\"eventMessage\" : ";
-                const EVENT_MESSAGE_SUFFIX: &str = "\",";
-                if let Some(prefix_idx) = l.find(EVENT_MESSAGE_PREFIX) {
-                    let message_start = prefix_idx + EVENT_MESSAGE_PREFIX.len();
-                    if let Some(relative_end_idx) = l[message_start..].rfind(EVENT_MESSAGE_SUFFIX) {
-                        let message_end = message_start + relative_end_idx + 1;
-                        let message_escaped = &l[message_start..message_end];
-                        let unescaped: String = serde_json::from_str(message_escaped)
-                            .unwrap_or_else(|_| message_escaped.to_string());
-                        if tx.send(unescaped).is_err() {
-                            break;
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                error!("Error reading log: {}", e);
-                break;
-            }
+    for entry in rx {
+        if min_level.is_some_and(|min| entry.level < min) {
+            continue;
         }
+        println!("{}", format_log_line(&entry));
     }
-    let _ = child.wait();
 }
 
-pub fn cmd_log_history(duration: &str) {
-    let predicate = format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL);
-    let output = Command::new("log")
-        .args(&[
-            "show",
-            "--predicate",
-            &predicate,
-            "--last",
-            duration,
-            "--style",
-            "compact",
-        ])
-        .output()
+pub fn cmd_log_history(duration: &str, min_level: Option<&str>) {
+    let min_level = resolve_min_level(min_level);
+    let entries = service_manager::current()
+        .log_history(duration)
         .unwrap_or_else(|e| {
-            error!("Failed to execute log show command: {}", e);
+            error!("Failed to retrieve log history: {}", e);
             std::process::exit(1);
         });
-    let logs = String::from_utf8_lossy(&output.stdout);
-    println!("{}", logs);
+
+    for entry in entries {
+        if min_level.is_some_and(|min| entry.level < min) {
+            continue;
+        }
+        println!("{}", format_log_line(&entry));
+    }
 }
 
 #[derive(Debug)]
@@ -401,9 +494,21 @@ pub enum WatchMode {
     Recursive,
     NonRecursive,
     Ignore,
+    /// Rescan the path every `interval` instead of relying on native FS
+    /// events, for network shares and other FSEvents-blind filesystems.
+    Poll {
+        interval: Duration,
+    },
 }
 
 fn resolve_and_canonicalize(path: &str) -> String {
+    // Glob patterns (e.g. "**/node_modules/") can't be canonicalized since
+    // they don't name a single existing entry; pass them through verbatim
+    // apart from tilde expansion.
+    if is_glob_pattern(path) {
+        return expand_tilde(path).to_string_lossy().into_owned();
+    }
+
     // Expand tilde if present.
     let expanded_path = expand_tilde(path);
 
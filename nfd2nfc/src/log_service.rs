@@ -1,7 +1,9 @@
-use nfd2nfc_core::constants::NFD2NFC_SERVICE_LABEL;
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
 use std::io::{BufRead, BufReader};
 use std::process::{Command, Stdio};
 use std::sync::mpsc::Sender;
+use tokio::sync::watch;
 use unicode_normalization::UnicodeNormalization;
 
 /// Log entry with timestamp and message
@@ -11,66 +13,87 @@ pub struct LogEntry {
     pub full_timestamp: String, // Query use: "2026-01-21 11:23:45.123456+0900"
     pub message: String,
     pub level: String, // macOS unified log messageType: "Default", "Info", "Debug", "Error", "Fault"
+    pub category: Option<String>,
+    pub process_id: Option<i64>,
+    /// `message`'s ANSI SGR ("ESC[...m") color/style codes, tokenized into
+    /// styled runs instead of being left as raw escape bytes; `None` when
+    /// `message` had none, so the common case pays for nothing. `message`
+    /// itself has the escape sequences stripped out, so it stays usable for
+    /// search and copy regardless.
+    pub ansi_spans: Option<Vec<AnsiSpan>>,
+}
+
+/// One contiguous run of `LogEntry::message` sharing a single style, as
+/// produced by `parse_sgr`. `style` carries only the fields an SGR code
+/// actually set (fg/bg/bold/italic/underline); a reset code (`ESC[0m` or a
+/// bare `ESC[m`) clears it back to `Style::default()`, never to a caller's
+/// own base style, so callers should `Style::patch` each run's style onto
+/// whatever default they'd otherwise use (e.g. the Logs tab's per-severity
+/// color) rather than relying on it being theirs already.
+#[derive(Clone, Debug)]
+pub struct AnsiSpan {
+    pub text: String,
+    pub style: Style,
 }
 
 /// Events sent from background threads to the main UI thread
 pub enum LogEvent {
     Live(LogEntry),
-    HistoryChunk { entries: Vec<LogEntry> },
+    /// A batch of historical entries from `LogQuery::stream_history`, in
+    /// chronological order. `done` marks the last chunk of the load (even if
+    /// it's empty), so `LogsState` knows when it's safe to splice in any
+    /// live entries that raced ahead of it.
+    HistoryChunk { entries: Vec<LogEntry>, done: bool },
 }
 
-/// Extract a JSON string field value from ndjson line
-/// Handles both: "field":"value" (ndjson) and "field" : "value" (pretty json)
-fn extract_json_field(line: &str, field: &str) -> Option<String> {
-    // Try ndjson format first (no spaces): "field":"value"
-    let ndjson_prefix = format!("\"{}\":\"", field);
-    // Then try pretty json format (with spaces): "field" : "value"
-    let pretty_prefix = format!("\"{}\" : \"", field);
-
-    let start = if let Some(idx) = line.find(&ndjson_prefix) {
-        idx + ndjson_prefix.len()
-    } else if let Some(idx) = line.find(&pretty_prefix) {
-        idx + pretty_prefix.len()
-    } else {
-        return None;
-    };
-
-    // Find the closing quote, handling escaped characters
-    let rest = &line[start..];
-    let mut end_idx = 0;
-    let mut chars = rest.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        if c == '\\' {
-            // Skip the next character (escaped)
-            if let Some(escaped) = chars.next() {
-                end_idx += 1 + escaped.len_utf8();
-            }
-        } else if c == '"' {
-            break;
-        } else {
-            end_idx += c.len_utf8();
-        }
-    }
+/// One `log show --style ndjson` / `log stream --style ndjson` record.
+/// Unrecognized fields are ignored by serde rather than rejected.
+#[derive(Deserialize)]
+struct RawLogEntry {
+    timestamp: String,
+    #[serde(rename = "eventMessage")]
+    event_message: String,
+    #[serde(rename = "messageType", default)]
+    message_type: String,
+    #[serde(default)]
+    subsystem: Option<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(rename = "processID", default)]
+    process_id: Option<i64>,
+    #[serde(rename = "senderImagePath", default)]
+    sender_image_path: Option<String>,
+}
 
-    let escaped = &rest[..end_idx];
-    // Use serde_json to properly unescape the string
-    let json_str = format!("\"{}\"", escaped);
-    serde_json::from_str(&json_str).ok()
+/// `log show`/`log stream --style ndjson` also emit bookkeeping lines with no
+/// `eventMessage`, e.g. `{"finished":1}` or `{"count":4}`. Parsing into this
+/// untagged enum lets those fall through to `Metadata` and be skipped,
+/// instead of matching them by substring.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NdjsonLine {
+    Entry(RawLogEntry),
+    Metadata {
+        #[serde(default)]
+        finished: Option<u32>,
+        #[serde(default)]
+        count: Option<u32>,
+    },
 }
 
 /// Extract LogEntry from a JSON log line
 pub fn extract_log_entry(line: &str) -> Option<LogEntry> {
-    // Skip metadata lines like {"count":4,"finished":1}
-    if line.contains("\"finished\"") || line.contains("\"count\"") {
-        return None;
-    }
-
-    let full_timestamp = extract_json_field(line, "timestamp")?;
+    let raw = match serde_json::from_str(line).ok()? {
+        NdjsonLine::Entry(raw) => raw,
+        NdjsonLine::Metadata { .. } => return None,
+    };
+    // `subsystem`/`sender_image_path` aren't surfaced yet but are parsed
+    // here so a future column is a one-field change, not a rewrite.
+    let _ = (&raw.subsystem, &raw.sender_image_path);
 
     // "2026-01-21 11:23:45.123456+0900" → "01-21 11:23:45"
     let display_time = {
-        let parts: Vec<&str> = full_timestamp.split_whitespace().collect();
+        let parts: Vec<&str> = raw.timestamp.split_whitespace().collect();
         if parts.len() >= 2 {
             let date_part = parts[0]; // "2026-01-21"
             let time_part = parts[1].split('.').next().unwrap_or(""); // "11:23:45"
@@ -78,66 +101,425 @@ pub fn extract_log_entry(line: &str) -> Option<LogEntry> {
             let date_short = date_part.get(5..).unwrap_or(date_part); // "01-21"
             format!("{} {}", date_short, time_part)
         } else {
-            full_timestamp.clone()
+            raw.timestamp.clone()
         }
     };
 
-    let message: String = extract_json_field(line, "eventMessage")?.nfkc().collect();
-    let level = extract_json_field(line, "messageType").unwrap_or_default();
+    let normalized: String = raw.event_message.nfkc().collect();
+    let (message, ansi_spans) = if normalized.contains('\u{1b}') {
+        let (plain, spans) = parse_sgr(&normalized);
+        (plain, Some(spans))
+    } else {
+        (normalized, None)
+    };
 
     Some(LogEntry {
         display_time,
-        full_timestamp,
+        full_timestamp: raw.timestamp,
         message,
-        level,
+        level: raw.message_type,
+        category: raw.category,
+        process_id: raw.process_id,
+        ansi_spans,
     })
 }
 
-/// Get log history for a duration (e.g., "5m", "30m", "1h")
-pub fn get_log_history(duration: &str) -> Result<Vec<LogEntry>, String> {
-    let predicate = format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL);
-    let output = Command::new("log")
-        .args([
-            "show",
-            "--predicate",
-            &predicate,
-            "--last",
-            duration,
-            "--style",
-            "ndjson",
-        ])
-        .output()
-        .map_err(|e| format!("Failed to execute log show command: {}", e))?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    Ok(stdout.lines().filter_map(extract_log_entry).collect())
-}
-
-/// Stream logs in real-time, sending LogEvent::Live for each entry
-pub fn stream_logs(tx: Sender<LogEvent>) {
-    let predicate = format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL);
-    let mut child = match Command::new("log")
-        .args(["stream", "--predicate", &predicate, "--style", "ndjson"])
-        .stdout(Stdio::piped())
-        .spawn()
-    {
-        Ok(child) => child,
-        Err(_) => return,
-    };
+/// Tokenizes `raw` (already NFKC-normalized) into its plain text, with every
+/// ANSI SGR sequence removed, and the list of styled runs covering it (the
+/// technique `ansi-to-tui`-style crates use, hand-rolled here since this
+/// tree has no dependency manifest to add one to). An SGR sequence cut off
+/// before its terminating `m` (a message truncated mid-escape) is dropped
+/// instead of leaking the partial bytes into the plain text. Non-SGR CSI
+/// sequences (cursor moves, etc.) are also dropped; unified log messages
+/// don't emit them.
+fn parse_sgr(raw: &str) -> (String, Vec<AnsiSpan>) {
+    let mut plain = String::with_capacity(raw.len());
+    let mut runs = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    let mut chars = raw.chars();
 
-    let stdout = match child.stdout.take() {
-        Some(stdout) => stdout,
-        None => return,
-    };
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            current.push(c);
+            continue;
+        }
+
+        let mut lookahead = chars.clone();
+        if lookahead.next() != Some('[') {
+            continue; // Lone/incomplete escape; drop it.
+        }
+        chars = lookahead;
+
+        let mut params = String::new();
+        let mut terminator = None;
+        for c2 in chars.by_ref() {
+            if c2.is_ascii_digit() || c2 == ';' {
+                params.push(c2);
+            } else {
+                terminator = Some(c2);
+                break;
+            }
+        }
 
-    let reader = BufReader::new(stdout);
+        if terminator == Some('m') {
+            if !current.is_empty() {
+                plain.push_str(&current);
+                runs.push(AnsiSpan { text: std::mem::take(&mut current), style });
+            }
+            style = apply_sgr_params(&params, style);
+        }
+        // Anything else (a non-SGR CSI sequence, or one cut off before its
+        // terminator) is silently dropped.
+    }
+
+    if !current.is_empty() {
+        plain.push_str(&current);
+        runs.push(AnsiSpan { text: current, style });
+    }
+
+    (plain, runs)
+}
+
+fn apply_sgr_params(params: &str, mut style: Style) -> Style {
+    if params.is_empty() {
+        return Style::default(); // bare `ESC[m` resets
+    }
+    for part in params.split(';') {
+        let Ok(code) = part.parse::<u8>() else { continue };
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            22 => style.remove_modifier(Modifier::BOLD),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            30..=37 => style.fg(sgr_color(code - 30, false)),
+            39 => style.fg(Color::Reset),
+            40..=47 => style.bg(sgr_color(code - 40, false)),
+            49 => style.bg(Color::Reset),
+            90..=97 => style.fg(sgr_color(code - 90, true)),
+            100..=107 => style.bg(sgr_color(code - 100, true)),
+            _ => style,
+        };
+    }
+    style
+}
+
+fn sgr_color(index: u8, bright: bool) -> Color {
+    match (index, bright) {
+        (0, false) => Color::Black,
+        (1, false) => Color::Red,
+        (2, false) => Color::Green,
+        (3, false) => Color::Yellow,
+        (4, false) => Color::Blue,
+        (5, false) => Color::Magenta,
+        (6, false) => Color::Cyan,
+        (7, false) => Color::Gray,
+        (0, true) => Color::DarkGray,
+        (1, true) => Color::LightRed,
+        (2, true) => Color::LightGreen,
+        (3, true) => Color::LightYellow,
+        (4, true) => Color::LightBlue,
+        (5, true) => Color::LightMagenta,
+        (6, true) => Color::LightCyan,
+        (7, true) => Color::White,
+        _ => Color::Reset,
+    }
+}
+
+/// Slices `spans` (covering a string end to end) down to the runs spanning
+/// byte range `start..end` of that string, splitting a run at either
+/// boundary as needed. Used to carry a message's ANSI styling across
+/// `wrap_text`'s line breaks, since each wrapped piece covers a sub-range
+/// of the original byte string.
+pub fn slice_ansi_spans(spans: &[AnsiSpan], start: usize, end: usize) -> Vec<AnsiSpan> {
+    let mut result = Vec::new();
+    let mut pos = 0;
+    for span in spans {
+        let span_start = pos;
+        let span_end = pos + span.text.len();
+        pos = span_end;
+        if span_end <= start || span_start >= end {
+            continue;
+        }
+        let local_start = start.saturating_sub(span_start).min(span.text.len());
+        let local_end = end.saturating_sub(span_start).min(span.text.len());
+        if local_start < local_end {
+            result.push(AnsiSpan {
+                text: span.text[local_start..local_end].to_string(),
+                style: span.style,
+            });
+        }
+    }
+    result
+}
+
+/// Batch size for `LogQuery::stream_history`'s progressive `HistoryChunk`
+/// sends.
+const HISTORY_CHUNK_SIZE: usize = 200;
+
+/// macOS unified log severities, in ascending order so `LogQuery::min_level`
+/// can compose a `messageType >= <level>` predicate clause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Default,
+    Error,
+    Fault,
+}
+
+impl LogLevel {
+    fn predicate_name(self) -> &'static str {
+        match self {
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Default => "default",
+            LogLevel::Error => "error",
+            LogLevel::Fault => "fault",
+        }
+    }
+}
+
+/// Which `messageType`s the Logs tab currently wants to see, mirroring its
+/// per-level visibility toggles. Threaded down to the background query
+/// threads via a `watch` channel so a level hidden in the UI stops being
+/// fetched at all, instead of arriving and only being filtered client-side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LevelFilter {
+    pub debug: bool,
+    pub info: bool,
+    pub default: bool,
+    pub error: bool,
+    pub fault: bool,
+}
 
-    for line in reader.lines().map_while(Result::ok) {
-        if let Some(entry) = extract_log_entry(&line)
-            && tx.send(LogEvent::Live(entry)).is_err()
-        {
-            break;
+impl Default for LevelFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl LevelFilter {
+    pub fn all() -> Self {
+        Self {
+            debug: true,
+            info: true,
+            default: true,
+            error: true,
+            fault: true,
+        }
+    }
+
+    fn is_all(&self) -> bool {
+        self.debug && self.info && self.default && self.error && self.fault
+    }
+
+    fn enabled(&self) -> Vec<&'static str> {
+        [
+            (self.debug, LogLevel::Debug),
+            (self.info, LogLevel::Info),
+            (self.default, LogLevel::Default),
+            (self.error, LogLevel::Error),
+            (self.fault, LogLevel::Fault),
+        ]
+        .into_iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, level)| level.predicate_name())
+        .collect()
+    }
+}
+
+/// Builder for a `log show`/`log stream` invocation. Replaces the old
+/// `get_log_history`/`stream_logs` pair, which each hardcoded their own
+/// `subsystem == "{}"` predicate; this composes the predicate from whatever
+/// filters the caller chains on, so a subsystem, a minimum severity, and a
+/// text filter can all apply to the same query.
+#[derive(Clone, Debug, Default)]
+pub struct LogQuery {
+    subsystem: Option<String>,
+    duration: Option<String>,
+    since_timestamp: Option<String>,
+    min_level: Option<LogLevel>,
+    levels: Option<LevelFilter>,
+    text_filter: Option<String>,
+}
+
+impl LogQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subsystem(mut self, label: &str) -> Self {
+        self.subsystem = Some(label.to_string());
+        self
+    }
+
+    /// How far back to look (e.g. "5m", "30m", "1h"), passed to `--last`.
+    /// Mutually exclusive with `since_timestamp`; whichever was set last wins.
+    pub fn duration(mut self, duration: &str) -> Self {
+        self.duration = Some(duration.to_string());
+        self.since_timestamp = None;
+        self
+    }
+
+    /// Resume from a specific `full_timestamp`, passed to `--start`. Lets a
+    /// caller whose stream died pick back up without re-fetching everything.
+    pub fn since_timestamp(mut self, full_timestamp: &str) -> Self {
+        self.since_timestamp = Some(full_timestamp.to_string());
+        self.duration = None;
+        self
+    }
+
+    pub fn min_level(mut self, level: LogLevel) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Restricts the query to only the `messageType`s enabled in `filter`.
+    /// A no-op when every level is enabled, so the common "show everything"
+    /// case doesn't grow the predicate for nothing.
+    pub fn levels(mut self, filter: LevelFilter) -> Self {
+        self.levels = (!filter.is_all()).then_some(filter);
+        self
+    }
+
+    pub fn text_filter(mut self, substr: &str) -> Self {
+        self.text_filter = Some(substr.to_string());
+        self
+    }
+
+    fn predicate(&self) -> Option<String> {
+        let mut clauses = Vec::new();
+        if let Some(subsystem) = &self.subsystem {
+            clauses.push(format!("subsystem == \"{}\"", subsystem));
+        }
+        if let Some(level) = self.min_level {
+            clauses.push(format!("messageType >= {}", level.predicate_name()));
+        }
+        if let Some(filter) = &self.levels {
+            let per_level: Vec<String> = filter
+                .enabled()
+                .into_iter()
+                .map(|name| format!("messageType == {}", name))
+                .collect();
+            // Every level toggled off is a valid (if unusual) UI state; match
+            // nothing rather than silently falling back to "no restriction".
+            clauses.push(if per_level.is_empty() {
+                "FALSEPREDICATE".to_string()
+            } else {
+                format!("({})", per_level.join(" || "))
+            });
+        }
+        if let Some(text) = &self.text_filter {
+            clauses.push(format!("eventMessage CONTAINS \"{}\"", text));
+        }
+        (!clauses.is_empty()).then(|| clauses.join(" && "))
+    }
+
+    fn base_args(&self, subcommand: &'static str) -> Vec<String> {
+        let mut args = vec![subcommand.to_string()];
+        if let Some(predicate) = self.predicate() {
+            args.push("--predicate".to_string());
+            args.push(predicate);
+        }
+        args.push("--style".to_string());
+        args.push("ndjson".to_string());
+        args
+    }
+
+    /// Runs `log show` for this query, sending parsed entries back in
+    /// batches of `HISTORY_CHUNK_SIZE` as `LogEvent::HistoryChunk` instead of
+    /// collecting the whole range before returning, so `LogsState` can start
+    /// rendering before a large `--last` range finishes loading. The final
+    /// chunk (possibly empty) is always sent with `done: true`, including on
+    /// a spawn failure, so the caller isn't left waiting forever. Stops
+    /// early if `tx.send` fails (the UI dropped its receiver).
+    pub fn stream_history(&self, tx: Sender<LogEvent>) {
+        let mut args = self.base_args("show");
+        if let Some(duration) = &self.duration {
+            args.push("--last".to_string());
+            args.push(duration.clone());
+        } else if let Some(since) = &self.since_timestamp {
+            args.push("--start".to_string());
+            args.push(since.clone());
+        }
+
+        let mut child = match Command::new("log").args(&args).stdout(Stdio::piped()).spawn() {
+            Ok(child) => child,
+            Err(_) => {
+                let _ = tx.send(LogEvent::HistoryChunk { entries: Vec::new(), done: true });
+                return;
+            }
+        };
+
+        let stdout = match child.stdout.take() {
+            Some(stdout) => stdout,
+            None => {
+                let _ = tx.send(LogEvent::HistoryChunk { entries: Vec::new(), done: true });
+                return;
+            }
+        };
+
+        let reader = BufReader::new(stdout);
+        let mut batch = Vec::with_capacity(HISTORY_CHUNK_SIZE);
+
+        for line in reader.lines().map_while(Result::ok) {
+            let Some(entry) = extract_log_entry(&line) else {
+                continue;
+            };
+            batch.push(entry);
+            if batch.len() >= HISTORY_CHUNK_SIZE {
+                let chunk = std::mem::replace(&mut batch, Vec::with_capacity(HISTORY_CHUNK_SIZE));
+                if tx.send(LogEvent::HistoryChunk { entries: chunk, done: false }).is_err() {
+                    let _ = child.kill();
+                    return;
+                }
+            }
+        }
+
+        let _ = tx.send(LogEvent::HistoryChunk { entries: batch, done: true });
+        let _ = child.wait();
+    }
+
+    /// Runs `log stream` for this query, sending `LogEvent::Live` for each
+    /// entry until the process exits, `tx`'s receiver is dropped, or
+    /// `level_filter` reports a new value (the caller is expected to loop,
+    /// rebuilding its `LogQuery` with `.levels()` from the fresh value and
+    /// calling `stream` again, so a level hidden mid-session stops being
+    /// fetched on the next line rather than waiting for the process to die).
+    pub fn stream(&self, tx: Sender<LogEvent>, mut level_filter: watch::Receiver<LevelFilter>) {
+        let mut args = self.base_args("stream");
+        if let Some(since) = &self.since_timestamp {
+            args.push("--start".to_string());
+            args.push(since.clone());
+        }
+
+        let mut child = match Command::new("log").args(&args).stdout(Stdio::piped()).spawn() {
+            Ok(child) => child,
+            Err(_) => return,
+        };
+
+        let stdout = match child.stdout.take() {
+            Some(stdout) => stdout,
+            None => return,
+        };
+
+        let reader = BufReader::new(stdout);
+
+        for line in reader.lines().map_while(Result::ok) {
+            if let Some(entry) = extract_log_entry(&line)
+                && tx.send(LogEvent::Live(entry)).is_err()
+            {
+                break;
+            }
+            if level_filter.has_changed().unwrap_or(false) {
+                let _ = child.kill();
+                return;
+            }
         }
+        let _ = child.wait();
     }
-    let _ = child.wait();
 }
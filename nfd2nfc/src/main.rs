@@ -1,12 +1,20 @@
 mod daemon_controller;
 mod normalizer;
+mod scheduler;
+mod service_manager;
+mod tui;
+mod watch_mode;
 
 use crate::daemon_controller::WatchMode;
 use crate::normalizer::*;
 use clap::{CommandFactory, Parser, Subcommand};
 use log::{error, info};
+use nfd2nfc_common::ignore::IgnoreMatcher;
 use nfd2nfc_common::logger::{init_logger, LogBackend};
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -45,6 +53,27 @@ watch subcommand. See `\x1b[32mnfd2nfc watch --help\x1b[0m` for details.",
   Reverse conversion (NFC â†’ NFD):
       \x1b[32mnfd2nfc -R\x1b[0m file.txt
 
+  Preview renames without touching anything:
+      \x1b[32mnfd2nfc -n\x1b[0m folder
+
+  Review renames in an interactive tree and choose which to apply:
+      \x1b[32mnfd2nfc -I\x1b[0m folder
+
+  Watch a folder and normalize new/renamed entries as they appear:
+      \x1b[32mnfd2nfc -w\x1b[0m folder
+
+  Skip node_modules and only touch image files:
+      \x1b[32mnfd2nfc -r --exclude\x1b[0m '**/node_modules/' \x1b[32m--ext\x1b[0m jpg,png folder
+
+  Emit NDJSON records for scripting instead of human-readable output:
+      \x1b[32mnfd2nfc -r --json\x1b[0m folder
+
+  Cap the rename pass at 4 concurrent workers:
+      \x1b[32mnfd2nfc -r --jobs\x1b[0m 4 folder
+
+  Undo the most recent conversion run:
+      \x1b[32mnfd2nfc undo --last\x1b[0m
+
   Verbose mode examples:
       \x1b[32mnfd2nfc -v\x1b[0m file.txt          (Warnings only)
       \x1b[32mnfd2nfc -vv\x1b[0m folder           (Detailed info)
@@ -80,6 +109,36 @@ struct Cli {
     #[arg(short = 'R', long = "reverse")]
     reverse: bool,
 
+    /// Print the rename plan and any collisions without renaming anything.
+    #[arg(short = 'n', long = "dry-run", conflicts_with = "watch")]
+    dry_run: bool,
+
+    /// Review the rename plan in an interactive tree before applying it:
+    /// navigate with the arrow keys, fold subtrees with Left, flag/unflag
+    /// entries with Space, and confirm with Enter.
+    #[arg(short = 'I', long = "interactive", conflicts_with_all = &["dry_run", "watch"])]
+    interactive: bool,
+
+    /// Watch the directory in the foreground and normalize new/renamed entries as they appear.
+    #[arg(short = 'w', long = "watch")]
+    watch: bool,
+
+    /// Skip paths matching GLOB (gitignore-style; repeatable).
+    #[arg(long = "exclude", value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Only rename files with one of these extensions (comma-separated, e.g. "jpg,png").
+    #[arg(long = "ext", value_name = "LIST", value_delimiter = ',')]
+    ext: Vec<String>,
+
+    /// Emit one NDJSON record per processed entry on stdout instead of human-oriented output.
+    #[arg(long = "json", conflicts_with = "dry_run")]
+    json: bool,
+
+    /// Cap concurrent rename workers (default: available CPU parallelism).
+    #[arg(long = "jobs", value_name = "N")]
+    jobs: Option<usize>,
+
     /// Increase verbosity (-v warnings, -vv info, -vvv debug, -vvvv trace).
     #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
     verbose: u8,
@@ -112,7 +171,10 @@ For further details, run `\x1b[32mnfd2nfc watch <COMMAND> --help\x1b[0m`.",
   
   Add the folder \"~/Desktop/folder\" to the ignore list:
       \x1b[32mnfd2nfc watch add\x1b[0m ~/Desktop/folder -i
-  
+
+  Pick a path to watch recursively by browsing instead of typing it:
+      \x1b[32mnfd2nfc watch add -r --pick\x1b[0m
+
   Remove the \"Desktop\" folder from the watch list:
       \x1b[32mnfd2nfc watch remove\x1b[0m Desktop
   
@@ -127,6 +189,23 @@ For further details, run `\x1b[32mnfd2nfc watch <COMMAND> --help\x1b[0m`.",
 "
     )]
     Watch(WatchCommand),
+
+    /// Revert filenames renamed during a previous conversion run.
+    ///
+    /// Every `-r`/`-c` conversion (and every interactive-review apply) journals
+    /// its renames as it performs them, identified by a run id printed when the
+    /// run finishes. Replay one in reverse with `--batch <id>`, or `--last` for
+    /// the most recent run still on record. An entry whose current name no
+    /// longer matches what the run left behind (because it was since renamed or
+    /// removed by hand) is skipped rather than clobbered.
+    Undo {
+        /// Revert the run with this id.
+        #[arg(long, value_name = "ID", required_unless_present = "last", conflicts_with = "last")]
+        batch: Option<String>,
+        /// Revert the most recently run batch.
+        #[arg(long)]
+        last: bool,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -154,21 +233,37 @@ enum WatchAction {
 Use one of the mutually exclusive options to control watch behavior:
   --recursive: add the path and its subdirectories.
   --nonrecursive: add only the specified path.
-  --ignore: mark the path to be ignored (recursively applied)."
+  --ignore: mark the path to be ignored (recursively applied).
+  --poll <SECONDS>: rescan the path every SECONDS instead of using native FS events (for network shares).
+
+If PATH is omitted (or --pick is given), an interactive picker opens instead:
+navigate directories with the arrow keys or vim motions and press Enter to
+choose one, or press 'v' to pick a mounted volume's root in one step."
     )]
     Add {
-        /// The path to add.
+        /// The path to add. If omitted, an interactive picker opens.
         #[arg(value_name = "PATH", help = "The path to be added to the watch list.")]
-        path: String,
+        path: Option<String>,
+        /// Open the interactive picker even if PATH is given.
+        #[arg(long)]
+        pick: bool,
         /// Add the path recursively.
-        #[arg(short = 'r', long, conflicts_with_all = &["nonrecursive", "ignore"])]
+        #[arg(short = 'r', long, conflicts_with_all = &["nonrecursive", "ignore", "poll"])]
         recursive: bool,
         /// Add only the specified path (non-recursive).
-        #[arg(short = 'n', long, conflicts_with_all = &["recursive", "ignore"])]
+        #[arg(short = 'n', long, conflicts_with_all = &["recursive", "ignore", "poll"])]
         nonrecursive: bool,
         /// Mark the path to be ignored (recursively).
-        #[arg(short = 'i', long, conflicts_with_all = &["recursive", "nonrecursive"])]
+        #[arg(short = 'i', long, conflicts_with_all = &["recursive", "nonrecursive", "poll"])]
         ignore: bool,
+        /// Poll the path every SECONDS instead of using native FS events (for network/FSEvents-blind volumes).
+        #[arg(
+            short = 'p',
+            long,
+            value_name = "SECONDS",
+            conflicts_with_all = &["recursive", "nonrecursive", "ignore"]
+        )]
+        poll: Option<u64>,
     },
     /// Remove a watch path from the configuration.
     ///
@@ -187,10 +282,14 @@ Use one of the mutually exclusive options to control watch behavior:
     ///
     /// By default, streams live logs in real time.
     /// Use `--last <DURATION>` to show logs from a past period (e.g., --last 2h, --last 5m, --last 30s).
+    /// Use `--level <LEVEL>` to only show entries at or above a severity (e.g., --level error).
     Log {
         /// Specify duration (e.g., 2h, 5m, 30s) for history logs.
         #[arg(long, value_name = "DURATION")]
         last: Option<String>,
+        /// Only show entries at or above this severity (debug, info, error, fault).
+        #[arg(long, value_name = "LEVEL")]
+        level: Option<String>,
     },
 }
 
@@ -199,60 +298,133 @@ fn main() {
 
     init_logger(LogBackend::Terminal, cli.verbose);
 
-    // If a subcommand is provided, handle it via the daemon_controller module.
-    if let Some(Commands::Watch(watch_cmd)) = cli.command {
-        match watch_cmd.action {
-            WatchAction::Start => {
-                daemon_controller::cmd_start_watcher();
-            }
-            WatchAction::Stop => {
-                daemon_controller::cmd_stop_watcher();
-            }
-            WatchAction::Restart => {
-                daemon_controller::cmd_restart_watcher();
-            }
-            WatchAction::Status => {
-                daemon_controller::cmd_status_watcher();
+    // If a subcommand is provided, handle it and return without falling
+    // through to the default conversion behavior below.
+    match cli.command {
+        Some(Commands::Watch(watch_cmd)) => {
+            dispatch_watch_command(watch_cmd);
+            return;
+        }
+        Some(Commands::Undo { batch, last: _ }) => {
+            cmd_undo(batch);
+            return;
+        }
+        None => {}
+    }
+
+    run_conversion(cli);
+}
+
+/// Handles every `nfd2nfc watch <...>` subcommand by delegating to the
+/// matching `daemon_controller` function.
+fn dispatch_watch_command(watch_cmd: WatchCommand) {
+    match watch_cmd.action {
+        WatchAction::Start => {
+            daemon_controller::cmd_start_watcher();
+        }
+        WatchAction::Stop => {
+            daemon_controller::cmd_stop_watcher();
+        }
+        WatchAction::Restart => {
+            daemon_controller::cmd_restart_watcher();
+        }
+        WatchAction::Status => {
+            daemon_controller::cmd_status_watcher();
+        }
+        WatchAction::Log { last, level } => {
+            if let Some(duration) = last {
+                daemon_controller::cmd_log_history(&duration, level.as_deref());
+            } else {
+                daemon_controller::cmd_stream_logs(level.as_deref());
             }
-            WatchAction::Log { last } => {
-                if let Some(duration) = last {
-                    daemon_controller::cmd_log_history(&duration);
-                } else {
-                    daemon_controller::cmd_stream_logs();
+        }
+        WatchAction::Add {
+            path,
+            pick,
+            recursive,
+            nonrecursive,
+            ignore,
+            poll,
+        } => {
+            let path = if pick || path.is_none() {
+                match tui::watch_picker::pick_path() {
+                    Ok(Some(path)) => path.to_string_lossy().into_owned(),
+                    Ok(None) => {
+                        println!("Cancelled.");
+                        return;
+                    }
+                    Err(e) => {
+                        error!("Path picker failed: {}", e);
+                        std::process::exit(1);
+                    }
                 }
+            } else {
+                path.expect("path is Some when --pick is not set")
+            };
+            if ignore {
+                daemon_controller::cmd_add_watch_path(&path, WatchMode::Ignore);
+            } else if recursive {
+                daemon_controller::cmd_add_watch_path(&path, WatchMode::Recursive);
+            } else if let Some(secs) = poll {
+                daemon_controller::cmd_add_watch_path(
+                    &path,
+                    WatchMode::Poll {
+                        interval: Duration::from_secs(secs),
+                    },
+                );
+            } else if nonrecursive {
+                daemon_controller::cmd_add_watch_path(&path, WatchMode::NonRecursive);
+            } else {
+                daemon_controller::cmd_add_watch_path(&path, WatchMode::NonRecursive);
             }
-            WatchAction::Add {
-                path,
-                recursive,
-                nonrecursive,
-                ignore,
-            } => {
-                if ignore {
-                    daemon_controller::cmd_add_watch_path(&path, WatchMode::Ignore);
-                } else if recursive {
-                    daemon_controller::cmd_add_watch_path(&path, WatchMode::Recursive);
-                } else if nonrecursive {
-                    daemon_controller::cmd_add_watch_path(&path, WatchMode::NonRecursive);
-                } else {
-                    daemon_controller::cmd_add_watch_path(&path, WatchMode::NonRecursive);
-                }
+        }
+        WatchAction::Remove { path, all } => {
+            if all {
+                daemon_controller::cmd_remove_watch_path_all();
+            } else {
+                let p = path.expect("A path must be provided when --all is not used.");
+                daemon_controller::cmd_remove_watch_path(&p);
             }
-            WatchAction::Remove { path, all } => {
-                if all {
-                    daemon_controller::cmd_remove_watch_path_all();
-                } else {
-                    let p = path.expect("A path must be provided when --all is not used.");
-                    daemon_controller::cmd_remove_watch_path(&p);
-                }
+        }
+        WatchAction::List => {
+            daemon_controller::cmd_list_watch_paths();
+        }
+    }
+}
+
+/// Reverts the renames recorded by a previous conversion run. `batch` wins
+/// if given; clap's `required_unless_present` on the `Undo` variant already
+/// guarantees `--last` was passed otherwise, so the only remaining case is
+/// to look up the most recently started run.
+fn cmd_undo(batch: Option<String>) {
+    let run_id = match batch {
+        Some(id) => id,
+        None => match nfd2nfc_core::journal::latest_run_id() {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                eprintln!("No conversion run found to undo.");
+                std::process::exit(1);
             }
-            WatchAction::List => {
-                daemon_controller::cmd_list_watch_paths();
+            Err(e) => {
+                error!("Failed to look up the most recent run: {}", e);
+                std::process::exit(1);
             }
+        },
+    };
+
+    match nfd2nfc_core::journal::revert_run(&run_id) {
+        Ok(()) => println!("Reverted run {}.", run_id),
+        Err(e) => {
+            error!("Failed to revert run {}: {}", run_id, e);
+            std::process::exit(1);
         }
-        return;
     }
+}
 
-    // No subcommand provided: perform the default conversion functionality.
+/// Performs the default (no-subcommand) conversion behavior: convert a
+/// single file, or a directory's contents and/or the directory name itself,
+/// according to `cli`'s flags.
+fn run_conversion(cli: Cli) {
     // Ensure that a PATH is provided for conversion.
     let path_str = match cli.path {
         Some(ref p) => p,
@@ -266,37 +438,90 @@ fn main() {
     let path = Path::new(path_str);
 
     let reverse_mode = cli.reverse;
+    let dry_run = cli.dry_run;
+
+    let exclude_patterns: Vec<PathBuf> = cli.exclude.iter().map(PathBuf::from).collect();
+    let extensions = if cli.ext.is_empty() {
+        None
+    } else {
+        Some(
+            cli.ext
+                .iter()
+                .map(|ext| ext.trim().trim_start_matches('.').to_lowercase())
+                .collect::<HashSet<_>>(),
+        )
+    };
+    let filter = NormalizeFilter {
+        ignore: IgnoreMatcher::compile(&exclude_patterns, &[path.to_path_buf()]),
+        extensions,
+    };
+    let json = cli.json;
+    let jobs = cli.jobs.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4)
+    });
 
     if path.is_file() {
-        if reverse_mode {
-            heuristic_normalize_name_to_nfd(path);
+        if dry_run {
+            let plan = if reverse_mode {
+                plan_heuristic_normalize_name_to_nfd(path)
+            } else {
+                plan_heuristic_normalize_name_to_nfc(path)
+            };
+            print_heuristic_plan(&plan);
+        } else if reverse_mode {
+            heuristic_normalize_name_to_nfd(path, json);
         } else {
-            heuristic_normalize_name_to_nfc(path);
+            heuristic_normalize_name_to_nfc(path, json);
         }
     } else if path.is_dir() {
         let process_directory_name = cli.directory;
         let process_contents = cli.contents || (!cli.directory && !cli.recursive);
         let process_recursive = cli.recursive;
 
-        if process_recursive {
-            if reverse_mode {
-                normalize_names_to_nfd(path, true);
-            } else {
-                normalize_names_to_nfc(path, true);
-            }
-        } else if process_contents {
-            if reverse_mode {
-                normalize_names_to_nfd(path, false);
+        if cli.watch {
+            watch_mode::run(path, process_recursive, reverse_mode);
+            return;
+        }
+
+        if process_recursive || process_contents {
+            let recursive = process_recursive;
+            if cli.interactive {
+                let (plan, collisions) = if reverse_mode {
+                    plan_normalize_to_nfd(path, recursive, &filter, None)
+                } else {
+                    plan_normalize_to_nfc(path, recursive, &filter, None)
+                };
+                report_collisions(&collisions, !reverse_mode, json);
+                if let Err(e) = tui::interactive_plan::run(path, plan, !reverse_mode, jobs) {
+                    error!("Interactive review failed: {}", e);
+                    std::process::exit(1);
+                }
+            } else if dry_run {
+                let (plan, collisions) = if reverse_mode {
+                    plan_normalize_to_nfd(path, recursive, &filter, None)
+                } else {
+                    plan_normalize_to_nfc(path, recursive, &filter, None)
+                };
+                print_plan(&plan, &collisions);
             } else {
-                normalize_names_to_nfc(path, false);
+                run_normalize_with_progress(path, recursive, reverse_mode, &filter, json, jobs);
             }
         }
 
         if process_directory_name {
-            if reverse_mode {
-                heuristic_normalize_name_to_nfd(path);
+            if dry_run {
+                let plan = if reverse_mode {
+                    plan_heuristic_normalize_name_to_nfd(path)
+                } else {
+                    plan_heuristic_normalize_name_to_nfc(path)
+                };
+                print_heuristic_plan(&plan);
+            } else if reverse_mode {
+                heuristic_normalize_name_to_nfd(path, json);
             } else {
-                heuristic_normalize_name_to_nfc(path);
+                heuristic_normalize_name_to_nfc(path, json);
             }
         }
     } else {
@@ -307,6 +532,128 @@ fn main() {
     info!("nfd2nfc process completed.");
 }
 
+/// Plans a directory conversion on a background thread, printing a textual
+/// percentage on the main thread as `ProgressData` snapshots arrive for the
+/// tree-walk stage, then executes the resulting plan with a
+/// [`scheduler::ConvertScheduler`] so the renames themselves run across
+/// `jobs` worker threads instead of one at a time.
+fn run_normalize_with_progress(
+    path: &Path,
+    recursive: bool,
+    reverse_mode: bool,
+    filter: &NormalizeFilter,
+    json: bool,
+    jobs: usize,
+) {
+    let (tx, rx) = mpsc::channel();
+    let plan_path = path.to_path_buf();
+    let plan_filter = filter.clone();
+    let handle = std::thread::spawn(move || {
+        if reverse_mode {
+            plan_normalize_to_nfd(&plan_path, recursive, &plan_filter, Some(&tx))
+        } else {
+            plan_normalize_to_nfc(&plan_path, recursive, &plan_filter, Some(&tx))
+        }
+    });
+
+    for progress in rx {
+        if !json {
+            print_progress(&progress);
+        }
+    }
+    let (plan, collisions) = handle
+        .join()
+        .expect("planning thread panicked while walking the directory tree");
+
+    report_collisions(&collisions, !reverse_mode, json);
+
+    let total = plan.len();
+    let mut scheduler =
+        scheduler::ConvertScheduler::spawn(path.to_path_buf(), plan, jobs, !reverse_mode, json);
+    let run_id = scheduler.run_id().to_string();
+
+    if json {
+        // Per-entry NDJSON records already went to stdout as each rename
+        // landed; nothing left to report here but the final tally.
+        let _ = scheduler.join();
+        return;
+    }
+
+    let mut last_printed = usize::MAX;
+    let progress = loop {
+        let progress = scheduler.poll();
+        if progress.completed() != last_printed {
+            last_printed = progress.completed();
+            println!(
+                "Stage 2/2: {}% ({}/{} entries)",
+                if total == 0 {
+                    100
+                } else {
+                    progress.completed() * 100 / total
+                },
+                progress.completed(),
+                total
+            );
+        }
+        if progress.done {
+            break progress;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    if progress.renamed > 0 {
+        println!("Undo this run with: nfd2nfc undo --batch {}", run_id);
+    }
+}
+
+fn print_progress(progress: &ProgressData) {
+    let percent = if progress.entries_to_check == 0 {
+        100
+    } else {
+        progress.entries_checked * 100 / progress.entries_to_check
+    };
+    println!(
+        "Stage {}/{}: {}% ({}/{} entries)",
+        progress.current_stage,
+        progress.max_stage,
+        percent,
+        progress.entries_checked,
+        progress.entries_to_check
+    );
+}
+
+/// Prints a directory conversion plan built with `--dry-run`, without
+/// renaming anything.
+fn print_plan(plan: &[RenamePlan], collisions: &[RenamePlan]) {
+    if plan.is_empty() && collisions.is_empty() {
+        println!("No renames needed.");
+        return;
+    }
+    for entry in plan {
+        println!("{} -> {}", entry.from.display(), entry.to.display());
+    }
+    for collision in collisions {
+        println!(
+            "COLLISION (skipped): {} -> {}",
+            collision.from.display(),
+            collision.to.display()
+        );
+    }
+}
+
+/// Prints a single-entry heuristic plan built with `--dry-run`.
+fn print_heuristic_plan(plan: &Result<Option<RenamePlan>, RenamePlan>) {
+    match plan {
+        Ok(Some(entry)) => println!("{} -> {}", entry.from.display(), entry.to.display()),
+        Ok(None) => println!("No renames needed."),
+        Err(collision) => println!(
+            "COLLISION (skipped): {} -> {}",
+            collision.from.display(),
+            collision.to.display()
+        ),
+    }
+}
+
 pub fn get_styles() -> clap::builder::Styles {
     clap::builder::Styles::styled()
         .usage(
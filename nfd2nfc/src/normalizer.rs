@@ -1,26 +1,140 @@
 use log::{debug, error, info};
-use rayon::prelude::*;
-use std::collections::VecDeque;
+use nfd2nfc_common::ignore::IgnoreMatcher;
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
 use std::fs;
 #[cfg(unix)]
 use std::os::unix::fs::MetadataExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
 use unicode_normalization::{is_nfc, is_nfd, UnicodeNormalization};
 
-/// Heuristically convert a single file/folder name to NFC without scanning the parent directory.
-/// This function does not verify the actual normalization by scanning the parent's contents;
-/// if the name is likely in NFD, it renames it to NFC.
-pub fn heuristic_normalize_name_to_nfc(target_path: &Path) {
-    info!(
-        "Starting heuristic conversion to NFC for: {}",
-        target_path.display()
-    );
+/// A single planned rename, computed without touching the filesystem. Built
+/// up front so a whole directory (or recursive tree) can be checked for
+/// collisions before anything is actually renamed.
+#[derive(Debug, Clone)]
+pub struct RenamePlan {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// A progress snapshot for the tree-walk stage of a `plan_normalize_to_nfc`/
+/// `plan_normalize_to_nfd` run, sent periodically over an optional channel
+/// so a caller (the TUI, the CLI) can render a progress bar while the plan
+/// is being built. `current_stage` 1 is this tree walk; stage 2, the actual
+/// renames, is driven by a [`crate::scheduler::ConvertScheduler`] instead of
+/// this struct.
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressData {
+    pub current_stage: u8,
+    pub max_stage: u8,
+    pub entries_checked: usize,
+    pub entries_to_check: usize,
+}
+
+const PROGRESS_STAGE_COUNT: u8 = 2;
+
+/// What happened to a single entry during a normalization pass, as reported
+/// by `--json`.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NormalizeAction {
+    Renamed,
+    Skipped,
+    Error,
+}
+
+/// One NDJSON record emitted to stdout per processed entry when `--json` is
+/// set, so a calling program can tell exactly which files were touched,
+/// collided, or failed without parsing colored `log` text (which stays on
+/// stderr).
+#[derive(Debug, Clone, Serialize)]
+pub struct NormalizeRecord {
+    pub path: PathBuf,
+    pub from_form: &'static str,
+    pub to_form: &'static str,
+    pub action: NormalizeAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn form_labels(to_nfc: bool) -> (&'static str, &'static str) {
+    if to_nfc {
+        ("nfd", "nfc")
+    } else {
+        ("nfc", "nfd")
+    }
+}
+
+pub(crate) fn print_record(path: &Path, to_nfc: bool, action: NormalizeAction, error: Option<String>) {
+    let (from_form, to_form) = form_labels(to_nfc);
+    let record = NormalizeRecord {
+        path: path.to_path_buf(),
+        from_form,
+        to_form,
+        action,
+        error,
+    };
+    match serde_json::to_string(&record) {
+        Ok(line) => println!("{}", line),
+        Err(e) => error!("Failed to serialize output record: {}", e),
+    }
+}
+
+/// Controls which entries a recursive normalization pass considers. `ignore`
+/// excludes whole paths (and, for a directory, everything under it) the same
+/// way `recursive_ignore_paths` does for the watcher. `extensions`, when
+/// set, restricts renaming to files whose extension (lowercased, without the
+/// leading dot) is in the set; directories are always still descended into
+/// regardless of `extensions`, since it only decides what gets renamed.
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeFilter {
+    pub ignore: IgnoreMatcher,
+    pub extensions: Option<HashSet<String>>,
+}
+
+impl NormalizeFilter {
+    fn allows_rename(&self, path: &Path, is_dir: bool) -> bool {
+        if is_dir {
+            return true;
+        }
+        match &self.extensions {
+            Some(extensions) => path
+                .extension()
+                .map(|ext| extensions.contains(&ext.to_string_lossy().to_lowercase()))
+                .unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
+/// Plans the heuristic (parent-directory-blind) rename of a single
+/// file/folder name to NFC, without performing it. Returns `Ok(None)` if the
+/// name's NFD and NFC forms are identical, and `Err(collision)` instead of a
+/// normal plan if the NFC name already exists under the same parent.
+pub fn plan_heuristic_normalize_name_to_nfc(
+    target_path: &Path,
+) -> Result<Option<RenamePlan>, RenamePlan> {
+    plan_heuristic_rename(target_path, true)
+}
 
+/// Plans the heuristic rename of a single file/folder name to NFD. See
+/// [`plan_heuristic_normalize_name_to_nfc`].
+pub fn plan_heuristic_normalize_name_to_nfd(
+    target_path: &Path,
+) -> Result<Option<RenamePlan>, RenamePlan> {
+    plan_heuristic_rename(target_path, false)
+}
+
+fn plan_heuristic_rename(
+    target_path: &Path,
+    to_nfc: bool,
+) -> Result<Option<RenamePlan>, RenamePlan> {
     let target_name = match target_path.file_name() {
         Some(name) => name,
         None => {
             error!("Invalid file/folder name: {}", target_path.display());
-            return;
+            return Ok(None);
         }
     };
 
@@ -30,72 +144,166 @@ pub fn heuristic_normalize_name_to_nfc(target_path: &Path) {
 
     if nfd_name == nfc_name {
         debug!("No conversion needed for: {}", target_path.display());
-        return;
+        return Ok(None);
     }
 
-    let nfc_path = target_path.with_file_name(nfc_name);
-    let nfd_path = target_path.with_file_name(nfd_name);
+    let (from_name, to_name) = if to_nfc {
+        (nfd_name, nfc_name)
+    } else {
+        (nfc_name, nfd_name)
+    };
+    let from = target_path.with_file_name(from_name);
+    let to = target_path.with_file_name(to_name);
 
-    match fs::rename(&nfd_path, &nfc_path) {
-        Ok(_) => info!("Heuristically converted {} to NFC", nfc_path.display()),
-        Err(e) => error!(
-            "Failed to heuristically convert {} to NFC: {}",
-            target_path.display(),
-            e
-        ),
+    if to.exists() {
+        Err(RenamePlan { from, to })
+    } else {
+        Ok(Some(RenamePlan { from, to }))
     }
 }
 
+/// Heuristically convert a single file/folder name to NFC without scanning the parent directory.
+/// This function does not verify the actual normalization by scanning the parent's contents;
+/// if the name is likely in NFD, it renames it to NFC.
+pub fn heuristic_normalize_name_to_nfc(target_path: &Path, json: bool) {
+    info!(
+        "Starting heuristic conversion to NFC for: {}",
+        target_path.display()
+    );
+    execute_heuristic_rename(
+        target_path,
+        plan_heuristic_normalize_name_to_nfc(target_path),
+        "NFC",
+        true,
+        json,
+    );
+}
+
 /// Heuristically convert a single file/folder name to NFD without scanning the parent directory.
 /// This function does not verify the actual normalization by scanning the parent's contents;
 /// if the name is likely in NFC, it renames it to NFD.
-pub fn heuristic_normalize_name_to_nfd(target_path: &Path) {
+pub fn heuristic_normalize_name_to_nfd(target_path: &Path, json: bool) {
     info!(
         "Starting heuristic conversion to NFD for: {}",
         target_path.display()
     );
+    execute_heuristic_rename(
+        target_path,
+        plan_heuristic_normalize_name_to_nfd(target_path),
+        "NFD",
+        false,
+        json,
+    );
+}
 
-    let target_name = match target_path.file_name() {
-        Some(name) => name,
-        None => {
-            error!("Invalid file/folder name: {}", target_path.display());
-            return;
+fn execute_heuristic_rename(
+    target_path: &Path,
+    plan: Result<Option<RenamePlan>, RenamePlan>,
+    label: &str,
+    to_nfc: bool,
+    json: bool,
+) {
+    match plan {
+        Ok(Some(plan)) => match fs::rename(&plan.from, &plan.to) {
+            Ok(_) => {
+                info!("Heuristically converted {} to {}", plan.to.display(), label);
+                if json {
+                    print_record(&plan.from, to_nfc, NormalizeAction::Renamed, None);
+                }
+            }
+            Err(e) => {
+                error!(
+                    "Failed to heuristically convert {} to {}: {}",
+                    target_path.display(),
+                    label,
+                    e
+                );
+                if json {
+                    print_record(
+                        &plan.from,
+                        to_nfc,
+                        NormalizeAction::Error,
+                        Some(e.to_string()),
+                    );
+                }
+            }
+        },
+        Ok(None) => {}
+        Err(collision) => {
+            error!(
+                "Conflict: both {} and {} exist; skipping to avoid clobbering.",
+                collision.from.display(),
+                collision.to.display()
+            );
+            if json {
+                print_record(
+                    &collision.from,
+                    to_nfc,
+                    NormalizeAction::Skipped,
+                    Some(format!("{} already exists", collision.to.display())),
+                );
+            }
         }
-    };
-
-    let target_name_str = target_name.to_string_lossy();
-    let nfd_name: String = target_name_str.nfd().collect();
-    let nfc_name: String = target_name_str.nfc().collect();
-
-    if nfd_name == nfc_name {
-        debug!("No conversion needed for: {}", target_path.display());
-        return;
     }
+}
 
-    let nfd_path = target_path.with_file_name(nfd_name);
-    let nfc_path = target_path.with_file_name(nfc_name);
+/// Plans the conversion of `target_folder`'s entries to NFC without touching
+/// the filesystem. Returns `(plan, collisions)`, where `collisions` holds
+/// renames that were held back because another entry in the same directory
+/// — whether already on disk or planned by a sibling in this same pass —
+/// already occupies the target name.
+pub fn plan_normalize_to_nfc(
+    target_folder: &Path,
+    recursive: bool,
+    filter: &NormalizeFilter,
+    progress: Option<&Sender<ProgressData>>,
+) -> (Vec<RenamePlan>, Vec<RenamePlan>) {
+    plan_normalize(target_folder, recursive, true, filter, progress)
+}
 
-    match fs::rename(&nfc_path, &nfd_path) {
-        Ok(_) => info!("Heuristically converted {} to NFD", nfd_path.display()),
-        Err(e) => error!(
-            "Failed to heuristically convert {} to NFD: {}",
-            target_path.display(),
-            e
-        ),
-    }
+/// Plans the conversion of `target_folder`'s entries to NFD. See
+/// [`plan_normalize_to_nfc`].
+pub fn plan_normalize_to_nfd(
+    target_folder: &Path,
+    recursive: bool,
+    filter: &NormalizeFilter,
+    progress: Option<&Sender<ProgressData>>,
+) -> (Vec<RenamePlan>, Vec<RenamePlan>) {
+    plan_normalize(target_folder, recursive, false, filter, progress)
 }
 
-pub fn normalize_names_to_nfc(target_folder: &Path, recursive: bool) {
+fn plan_normalize(
+    target_folder: &Path,
+    recursive: bool,
+    to_nfc: bool,
+    filter: &NormalizeFilter,
+    progress: Option<&Sender<ProgressData>>,
+) -> (Vec<RenamePlan>, Vec<RenamePlan>) {
+    let label = if to_nfc { "NFC" } else { "NFD" };
     info!(
-        "Starting folder conversion to NFC for: {} (recursive: {})",
+        "Planning folder conversion to {} for: {} (recursive: {})",
+        label,
         target_folder.display(),
         recursive
     );
+
+    let mut plan = Vec::new();
+    let mut collisions = Vec::new();
     let mut queue = VecDeque::new();
     queue.push_back(target_folder.to_path_buf());
 
+    // (dev, ino) of every directory already enqueued, so a directory reached
+    // twice through hardlinked directories or bind mounts is only walked
+    // once instead of looping or being redundantly reprocessed.
+    let mut visited_dirs: HashSet<(u64, u64)> = HashSet::new();
+    if let Ok(metadata) = fs::metadata(target_folder) {
+        visited_dirs.insert((metadata.dev(), metadata.ino()));
+    }
+
+    let mut entries_to_check = 0usize;
+
     while let Some(current_dir) = queue.pop_front() {
-        debug!("Processing directory: {}", current_dir.display());
+        debug!("Planning directory: {}", current_dir.display());
         let entries: Vec<_> = match fs::read_dir(&current_dir) {
             Ok(entries) => entries.filter_map(|entry| entry.ok()).collect(),
             Err(e) => {
@@ -104,76 +312,153 @@ pub fn normalize_names_to_nfc(target_folder: &Path, recursive: bool) {
             }
         };
 
-        let subdirs: Vec<_> = entries
-            .par_iter()
-            .filter_map(|entry| {
-                let path = entry.path();
-                let mut new_path = path.clone();
+        entries_to_check += entries.len();
+        if let Some(tx) = progress {
+            let _ = tx.send(ProgressData {
+                current_stage: 1,
+                max_stage: PROGRESS_STAGE_COUNT,
+                entries_checked: entries_to_check - entries.len(),
+                entries_to_check,
+            });
+        }
 
-                if let Some(name) = path.file_name() {
-                    if name == "." || name == ".." {
-                        debug!("Skipping dot entry: {}", path.display());
-                        return None;
-                    }
+        // Names already claimed in this directory, whether by an entry
+        // that's staying put or one already added to the plan, so two
+        // distinct sources that normalize to the same name collide even
+        // when neither currently exists under that name on disk.
+        let mut claimed: HashSet<String> = entries
+            .iter()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
 
-                    let original_name = name.to_string_lossy();
-                    if !is_nfc(&original_name) {
-                        let nfc_name: String = original_name.nfc().collect();
-                        new_path = path.with_file_name(&nfc_name);
-                        match fs::rename(&path, &new_path) {
-                            Ok(_) => info!("Converted {} to NFC", new_path.display()),
-                            Err(e) => {
-                                error!("Failed to convert {} to NFC: {}", path.display(), e);
-                                new_path = path.clone();
-                            }
-                        }
-                    } else {
-                        debug!("Entry already in NFC: {}", path.display());
-                    }
+        for entry in &entries {
+            let path = entry.path();
+            let Some(name) = path.file_name() else {
+                continue;
+            };
+            if name == "." || name == ".." {
+                debug!("Skipping dot entry: {}", path.display());
+                continue;
+            }
+
+            let is_dir = path.is_dir();
+            if filter.ignore.is_ignored(&path, is_dir) {
+                debug!("Excluded by filter: {}", path.display());
+                continue;
+            }
+
+            let original_name = name.to_string_lossy();
+            let is_normalized = if to_nfc {
+                is_nfc(&original_name)
+            } else {
+                is_nfd(&original_name)
+            };
+
+            if !is_normalized && filter.allows_rename(&path, is_dir) {
+                let target_name: String = if to_nfc {
+                    original_name.nfc().collect()
+                } else {
+                    original_name.nfd().collect()
+                };
+                let target_path = path.with_file_name(&target_name);
+
+                if claimed.contains(&target_name) {
+                    collisions.push(RenamePlan {
+                        from: path.clone(),
+                        to: target_path,
+                    });
+                } else {
+                    claimed.insert(target_name);
+                    plan.push(RenamePlan {
+                        from: path.clone(),
+                        to: target_path,
+                    });
                 }
+            } else {
+                debug!("Entry already in {}: {}", label, path.display());
+            }
 
-                if recursive && new_path.is_dir() {
-                    if let Ok(metadata) = fs::symlink_metadata(&new_path) {
+            // Nothing has been renamed on disk yet, so the entry is still
+            // reachable (and its directory-ness still checkable) under its
+            // original path regardless of what was just planned for it.
+            if recursive && path.is_dir() {
+                match fs::symlink_metadata(&path) {
+                    Ok(metadata) => {
                         let is_symlink = metadata.file_type().is_symlink();
-                        let is_different_fs = !is_same_filesystem(target_folder, &new_path);
+                        let is_different_fs = !is_same_filesystem(target_folder, &path);
 
-                        if !is_symlink && !is_different_fs {
-                            Some(new_path)
-                        } else {
+                        if is_symlink || is_different_fs {
                             debug!(
                                 "Skipping directory (symlink or different FS): {}",
-                                new_path.display()
+                                path.display()
                             );
-                            None
+                        } else if !visited_dirs.insert((metadata.dev(), metadata.ino())) {
+                            debug!("Cycle detected, skipping directory: {}", path.display());
+                        } else {
+                            queue.push_back(path.clone());
                         }
-                    } else {
-                        error!("Failed to get metadata for {}", new_path.display());
-                        None
                     }
-                } else {
-                    None
+                    Err(e) => error!("Failed to get metadata for {}: {}", path.display(), e),
                 }
-            })
-            .collect();
-
-        if recursive {
-            queue.extend(subdirs);
+            }
         }
     }
+
     info!(
-        "Completed folder conversion to NFC for: {}",
-        target_folder.display()
+        "Completed planning for: {} ({} renames planned, {} collisions)",
+        target_folder.display(),
+        plan.len(),
+        collisions.len()
     );
+
+    (plan, collisions)
 }
 
-pub fn normalize_names_to_nfd(target_folder: &Path, recursive: bool) {
+/// Logs every held-back rename in `collisions` as an error, mirroring the
+/// no-clobber safety `uu_mv` gives `mv --no-clobber`: renaming is skipped
+/// rather than silently overwriting an existing entry.
+pub fn report_collisions(collisions: &[RenamePlan], to_nfc: bool, json: bool) {
+    for collision in collisions {
+        error!(
+            "Conflict: both {} and {} exist; skipping to avoid clobbering.",
+            collision.from.display(),
+            collision.to.display()
+        );
+        if json {
+            print_record(
+                &collision.from,
+                to_nfc,
+                NormalizeAction::Skipped,
+                Some(format!("{} already exists", collision.to.display())),
+            );
+        }
+    }
+}
+
+/// Initial normalization sweep run once when a path is added to the watcher,
+/// so pre-existing NFD names don't sit unconverted until something happens
+/// to touch them. Entries matched by `ignore` are skipped entirely. Returns
+/// `(normalized, total)` where `total` counts every non-ignored entry
+/// visited, not just the ones that needed conversion.
+///
+/// A rename is skipped (and logged as a conflict) rather than performed if
+/// both the NFD and NFC name already exist, since `fs::rename` would
+/// otherwise silently clobber the NFC entry.
+pub fn sweep_normalize_to_nfc(
+    target_folder: &Path,
+    recursive: bool,
+    ignore: &IgnoreMatcher,
+) -> (usize, usize) {
     info!(
-        "Starting folder conversion to NFD for: {} (recursive: {})",
+        "Starting initial normalization sweep for: {} (recursive: {})",
         target_folder.display(),
         recursive
     );
+
     let mut queue = VecDeque::new();
     queue.push_back(target_folder.to_path_buf());
+    let mut normalized = 0;
+    let mut total = 0;
 
     while let Some(current_dir) = queue.pop_front() {
         debug!("Processing directory: {}", current_dir.display());
@@ -185,66 +470,70 @@ pub fn normalize_names_to_nfd(target_folder: &Path, recursive: bool) {
             }
         };
 
-        let subdirs: Vec<_> = entries
-            .par_iter()
-            .filter_map(|entry| {
-                let path = entry.path();
-                let mut new_path = path.clone();
-
-                if let Some(name) = path.file_name() {
-                    if name == "." || name == ".." {
-                        debug!("Skipping dot entry: {}", path.display());
-                        return None;
-                    }
+        for entry in entries {
+            let path = entry.path();
+            let Some(name) = path.file_name() else {
+                continue;
+            };
 
-                    let original_name = name.to_string_lossy();
-                    if !is_nfd(&original_name) {
-                        let nfd_name: String = original_name.nfd().collect();
-                        new_path = path.with_file_name(&nfd_name);
-                        match fs::rename(&path, &new_path) {
-                            Ok(_) => info!("Converted {} to NFD", new_path.display()),
-                            Err(e) => {
-                                error!("Failed to convert {} to NFD: {}", path.display(), e);
-                                new_path = path.clone();
-                            }
-                        }
-                    } else {
-                        debug!("Entry already in NFD: {}", path.display());
-                    }
+            let metadata = match fs::symlink_metadata(&path) {
+                Ok(meta) => meta,
+                Err(e) => {
+                    error!("Failed to get metadata for {}: {}", path.display(), e);
+                    continue;
                 }
+            };
+            let is_dir = metadata.is_dir();
 
-                if recursive && new_path.is_dir() {
-                    if let Ok(metadata) = fs::symlink_metadata(&new_path) {
-                        let is_symlink = metadata.file_type().is_symlink();
-                        let is_different_fs = !is_same_filesystem(target_folder, &new_path);
+            if ignore.is_ignored(&path, is_dir) {
+                debug!("Skipping ignored entry: {}", path.display());
+                continue;
+            }
+            total += 1;
 
-                        if !is_symlink && !is_different_fs {
-                            Some(new_path)
-                        } else {
-                            debug!(
-                                "Skipping directory (symlink or different FS): {}",
-                                new_path.display()
-                            );
-                            None
+            let mut new_path = path.clone();
+            let original_name = name.to_string_lossy();
+            if !is_nfc(&original_name) {
+                let nfc_name: String = original_name.nfc().collect();
+                let nfc_path = path.with_file_name(&nfc_name);
+                if nfc_path.exists() {
+                    error!(
+                        "Conflict: both {} and {} exist; skipping to avoid clobbering.",
+                        path.display(),
+                        nfc_path.display()
+                    );
+                } else {
+                    match fs::rename(&path, &nfc_path) {
+                        Ok(_) => {
+                            info!("Converted {} to NFC", nfc_path.display());
+                            new_path = nfc_path;
+                            normalized += 1;
                         }
-                    } else {
-                        error!("Failed to get metadata for {}", new_path.display());
-                        None
+                        Err(e) => error!("Failed to convert {} to NFC: {}", path.display(), e),
                     }
-                } else {
-                    None
                 }
-            })
-            .collect();
+            } else {
+                debug!("Entry already in NFC: {}", path.display());
+            }
 
-        if recursive {
-            queue.extend(subdirs);
+            if recursive
+                && is_dir
+                && !metadata.file_type().is_symlink()
+                && is_same_filesystem(target_folder, &new_path)
+            {
+                queue.push_back(new_path);
+            }
         }
     }
+
     info!(
-        "Completed folder conversion to NFD for: {}",
-        target_folder.display()
+        "Completed initial normalization sweep for: {} ({} of {} entries normalized)",
+        target_folder.display(),
+        normalized,
+        total
     );
+
+    (normalized, total)
 }
 
 fn is_same_filesystem(original_path: &Path, new_path: &Path) -> bool {
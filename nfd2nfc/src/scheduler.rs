@@ -0,0 +1,365 @@
+//! Parallel, cancellable execution of a rename plan.
+//!
+//! Applying a big recursive rename plan one entry at a time serializes the
+//! whole conversion on an I/O round trip per entry. This spreads the plan
+//! across a bounded pool of worker threads instead -- the same small
+//! task-scheduler shape as `tui::tabs::browser::recursive_convert` (itself
+//! modeled on yazi's task queue), scaled up from one worker to `jobs` of
+//! them.
+//!
+//! The plan is processed in waves, one depth at a time starting from the
+//! shallowest: every entry at a given depth
+//! (relative to the scan root) is independent of every other entry at that
+//! same depth, since none of them can be an ancestor of another, so a whole
+//! wave can be dispatched to the pool at once. Before a wave is dispatched,
+//! each entry's full ancestor chain is resolved against every rename that's
+//! landed so far (see [`resolve_actual_path`]), not just its immediate
+//! parent -- an unconverted directory may sit between a renamed ancestor and
+//! a renamed descendant -- so a directory being renamed and its own contents
+//! being renamed in the same pass never races.
+//!
+//! Every rename that actually lands is also recorded to a
+//! [`nfd2nfc_core::journal::RunJournal`], so a bad run can be undone with
+//! `nfd2nfc undo` afterward.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use log::{error, info};
+
+use nfd2nfc_core::journal::RunJournal;
+use nfd2nfc_core::normalizer::NormalizationTarget;
+
+use crate::normalizer::{print_record, NormalizeAction, RenamePlan};
+
+fn normalization_target(to_nfc: bool) -> NormalizationTarget {
+    if to_nfc {
+        NormalizationTarget::NFC
+    } else {
+        NormalizationTarget::NFD
+    }
+}
+
+/// Running tally reported back to a caller (TUI or CLI) as a scheduled run
+/// proceeds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SchedulerProgress {
+    pub total: usize,
+    pub renamed: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub done: bool,
+}
+
+impl SchedulerProgress {
+    pub fn completed(&self) -> usize {
+        self.renamed + self.skipped + self.failed
+    }
+}
+
+/// One dispatched rename, carrying both its original (pre-rename,
+/// as-planned) path and its actual on-disk path after any ancestor
+/// substitution.
+struct Job {
+    original_from: PathBuf,
+    actual_from: PathBuf,
+    actual_to: PathBuf,
+}
+
+enum JobResult {
+    Renamed {
+        original_from: PathBuf,
+        actual_to: PathBuf,
+    },
+    Skipped {
+        original_from: PathBuf,
+    },
+    Failed {
+        original_from: PathBuf,
+    },
+}
+
+/// A bounded pool of worker threads executing a rename plan, reporting
+/// progress and supporting cooperative cancellation.
+pub struct ConvertScheduler {
+    progress_rx: Receiver<SchedulerProgress>,
+    progress: SchedulerProgress,
+    cancel: Arc<AtomicBool>,
+    run_id: String,
+}
+
+impl ConvertScheduler {
+    /// Spawns a supervisor (and `jobs`, clamped to at least 1, worker
+    /// threads under it) to execute `plan` relative to `root`. Every
+    /// successful rename is recorded to a fresh [`RunJournal`] as it
+    /// happens, so the whole run can later be reverted with
+    /// `nfd2nfc undo --batch <run_id>` (see [`ConvertScheduler::run_id`]).
+    pub fn spawn(root: PathBuf, plan: Vec<RenamePlan>, jobs: usize, to_nfc: bool, json: bool) -> Self {
+        let jobs = jobs.max(1);
+        let (progress_tx, progress_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let journal = Arc::new(Mutex::new(RunJournal::start()));
+        let run_id = journal.lock().unwrap().run_id().to_string();
+
+        let supervisor_cancel = cancel.clone();
+        thread::spawn(move || {
+            run_waves(root, plan, jobs, to_nfc, json, supervisor_cancel, progress_tx, journal)
+        });
+
+        Self {
+            progress_rx,
+            progress: SchedulerProgress::default(),
+            cancel,
+            run_id,
+        }
+    }
+
+    /// The id of this run's journal, for reverting it later via
+    /// `nfd2nfc undo --batch <id>` (or [`nfd2nfc_core::journal::revert_run`]
+    /// directly).
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Drains every progress update sent so far, returning the latest one.
+    pub fn poll(&mut self) -> SchedulerProgress {
+        while let Ok(progress) = self.progress_rx.try_recv() {
+            self.progress = progress;
+        }
+        self.progress
+    }
+
+    /// The most recent progress snapshot without draining the channel, for
+    /// a render pass that redraws more often than it polls.
+    pub fn progress(&self) -> SchedulerProgress {
+        self.progress
+    }
+
+    /// Stops enqueuing further waves and tells in-flight workers to skip
+    /// whatever they're about to pick up next, rather than killing them
+    /// mid-rename.
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Blocks until the run finishes, for a caller (the plain CLI path)
+    /// that isn't interleaving polling with its own render loop.
+    pub fn join(mut self) -> SchedulerProgress {
+        loop {
+            let progress = self.poll();
+            if progress.done {
+                return progress;
+            }
+            thread::sleep(std::time::Duration::from_millis(20));
+        }
+    }
+}
+
+fn relative_depth(root: &Path, path: &Path) -> usize {
+    path.strip_prefix(root)
+        .map(|rel| rel.components().count().saturating_sub(1))
+        .unwrap_or(0)
+}
+
+/// Resolves `path` (an as-planned, pre-rename path) to where it actually
+/// sits on disk, given every rename that's landed so far. Walks the full
+/// ancestor chain component by component rather than only checking the
+/// immediate parent, since an ancestor several levels up -- not just the
+/// direct parent -- may be the one that got renamed (e.g. a directory that
+/// itself doesn't need conversion, sitting between a renamed ancestor and a
+/// renamed descendant).
+fn resolve_actual_path(path: &Path, renamed_paths: &HashMap<PathBuf, PathBuf>) -> PathBuf {
+    let mut original_so_far = PathBuf::new();
+    let mut actual_so_far = PathBuf::new();
+    for component in path.components() {
+        original_so_far.push(component);
+        match renamed_paths.get(&original_so_far) {
+            Some(actual) => actual_so_far = actual.clone(),
+            None => actual_so_far.push(component),
+        }
+    }
+    actual_so_far
+}
+
+fn run_waves(
+    root: PathBuf,
+    plan: Vec<RenamePlan>,
+    jobs: usize,
+    to_nfc: bool,
+    json: bool,
+    cancel: Arc<AtomicBool>,
+    progress_tx: Sender<SchedulerProgress>,
+    journal: Arc<Mutex<RunJournal>>,
+) {
+    let mut progress = SchedulerProgress {
+        total: plan.len(),
+        ..Default::default()
+    };
+
+    let mut waves: HashMap<usize, Vec<RenamePlan>> = HashMap::new();
+    for entry in plan {
+        waves.entry(relative_depth(&root, &entry.from)).or_default().push(entry);
+    }
+    let max_depth = waves.keys().copied().max().unwrap_or(0);
+
+    let (job_tx, job_rx) = mpsc::channel::<Job>();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (result_tx, result_rx) = mpsc::channel::<JobResult>();
+
+    let mut workers = Vec::with_capacity(jobs);
+    for _ in 0..jobs {
+        let job_rx = job_rx.clone();
+        let result_tx = result_tx.clone();
+        let worker_cancel = cancel.clone();
+        let worker_journal = journal.clone();
+        workers.push(thread::spawn(move || {
+            worker_loop(job_rx, result_tx, worker_cancel, to_nfc, json, worker_journal)
+        }));
+    }
+    drop(result_tx);
+
+    // Maps a plan entry's as-planned `from` path to the path it actually
+    // ended up at, so descendants queued in a later wave can find their
+    // parent even though it's already been renamed.
+    let mut renamed_paths: HashMap<PathBuf, PathBuf> = HashMap::new();
+
+    'waves: for depth in 0..=max_depth {
+        let Some(entries) = waves.remove(&depth) else {
+            continue;
+        };
+        if cancel.load(Ordering::Relaxed) {
+            progress.skipped += entries.len();
+            let _ = progress_tx.send(progress);
+            continue;
+        }
+
+        let dispatched = entries.len();
+        for entry in entries {
+            let actual_parent = entry.from.parent().map(|parent| resolve_actual_path(parent, &renamed_paths));
+            let actual_from = match (&actual_parent, entry.from.file_name()) {
+                (Some(parent), Some(name)) => parent.join(name),
+                _ => entry.from.clone(),
+            };
+            let actual_to = match (&actual_parent, entry.to.file_name()) {
+                (Some(parent), Some(name)) => parent.join(name),
+                _ => entry.to.clone(),
+            };
+            let _ = job_tx.send(Job {
+                original_from: entry.from,
+                actual_from,
+                actual_to,
+            });
+        }
+
+        for _ in 0..dispatched {
+            match result_rx.recv() {
+                Ok(JobResult::Renamed { original_from, actual_to }) => {
+                    renamed_paths.insert(original_from, actual_to);
+                    progress.renamed += 1;
+                }
+                Ok(JobResult::Skipped { .. }) => progress.skipped += 1,
+                Ok(JobResult::Failed { .. }) => progress.failed += 1,
+                Err(_) => break 'waves, // Every worker thread died; nothing left to wait for.
+            }
+            let _ = progress_tx.send(progress);
+        }
+    }
+
+    drop(job_tx);
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    progress.done = true;
+    let _ = progress_tx.send(progress);
+}
+
+fn worker_loop(
+    job_rx: Arc<Mutex<Receiver<Job>>>,
+    result_tx: Sender<JobResult>,
+    cancel: Arc<AtomicBool>,
+    to_nfc: bool,
+    json: bool,
+    journal: Arc<Mutex<RunJournal>>,
+) {
+    loop {
+        let job = {
+            let rx = job_rx.lock().unwrap();
+            rx.recv()
+        };
+        let Ok(job) = job else { break };
+
+        if cancel.load(Ordering::Relaxed) {
+            let _ = result_tx.send(JobResult::Skipped {
+                original_from: job.original_from,
+            });
+            continue;
+        }
+
+        if job.actual_to.exists() {
+            error!(
+                "Conflict: both {} and {} exist; skipping to avoid clobbering.",
+                job.actual_from.display(),
+                job.actual_to.display()
+            );
+            if json {
+                print_record(
+                    &job.actual_from,
+                    to_nfc,
+                    NormalizeAction::Skipped,
+                    Some(format!("{} already exists", job.actual_to.display())),
+                );
+            }
+            let _ = result_tx.send(JobResult::Skipped {
+                original_from: job.original_from,
+            });
+            continue;
+        }
+
+        match std::fs::rename(&job.actual_from, &job.actual_to) {
+            Ok(()) => {
+                info!("Converted {}", job.actual_to.display());
+                if json {
+                    print_record(&job.actual_from, to_nfc, NormalizeAction::Renamed, None);
+                }
+                {
+                    let mut journal = match journal.lock() {
+                        Ok(journal) => journal,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+                    if let Err(e) = journal.record_rename(&job.actual_from, &job.actual_to, normalization_target(to_nfc)) {
+                        error!("Failed to record rename in journal: {}", e);
+                    }
+                }
+                let _ = result_tx.send(JobResult::Renamed {
+                    original_from: job.original_from,
+                    actual_to: job.actual_to,
+                });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to convert {} to {}: {}",
+                    job.actual_from.display(),
+                    job.actual_to.display(),
+                    e
+                );
+                if json {
+                    print_record(
+                        &job.actual_from,
+                        to_nfc,
+                        NormalizeAction::Error,
+                        Some(e.to_string()),
+                    );
+                }
+                let _ = result_tx.send(JobResult::Failed {
+                    original_from: job.original_from,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use super::{start_and_wait_for_live_message, Level, LogEntry, ServiceManager};
+use log::{error, warn};
+use nfd2nfc_common::constants::{HOME_DIR, NFD2NFC_SERVICE_LABEL};
+use once_cell::sync::Lazy;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::Sender;
+
+static PLIST_PATH: Lazy<String> = Lazy::new(|| {
+    let path = format!(
+        "{}/Library/LaunchAgents/{}.plist",
+        HOME_DIR.display(),
+        NFD2NFC_SERVICE_LABEL
+    );
+    if !std::path::Path::new(&path).exists() {
+        error!("Plist file not found at {}.", path);
+        std::process::exit(1);
+    }
+    path
+});
+
+/// Controls the watcher via `launchctl` and Apple's unified log (macOS).
+pub struct LaunchdManager;
+
+impl LaunchdManager {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ServiceManager for LaunchdManager {
+    fn start(&self) -> Result<String, String> {
+        start_and_wait_for_live_message(
+            stream_logs,
+            || {
+                let status = Command::new("launchctl")
+                    .arg("load")
+                    .arg("-w")
+                    .arg(&*PLIST_PATH)
+                    .status()
+                    .map_err(|e| format!("Failed to start watcher: {}", e))?;
+
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(format!("Failed to start watcher: {}", status))
+                }
+            },
+            || self.status(),
+        )
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let status = Command::new("launchctl")
+            .arg("unload")
+            .arg("-w")
+            .arg(&*PLIST_PATH)
+            .status()
+            .map_err(|e| format!("Failed to stop service: {}", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to stop service: {}", status))
+        }
+    }
+
+    fn status(&self) -> bool {
+        let output = Command::new("launchctl")
+            .arg("list")
+            .output()
+            .unwrap_or_else(|e| {
+                error!("Failed to execute launchctl list: {}", e);
+                std::process::exit(1);
+            });
+        String::from_utf8_lossy(&output.stdout).contains(NFD2NFC_SERVICE_LABEL)
+    }
+
+    fn stream_logs(&self, tx: Sender<LogEntry>) {
+        stream_logs(tx);
+    }
+
+    fn log_history(&self, duration: &str) -> Result<Vec<LogEntry>, String> {
+        let predicate = format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL);
+        let output = Command::new("log")
+            .args(&[
+                "show",
+                "--predicate",
+                &predicate,
+                "--last",
+                duration,
+                "--style",
+                "ndjson",
+            ])
+            .output()
+            .map_err(|e| format!("Failed to execute log show command: {}", e))?;
+
+        let logs = String::from_utf8_lossy(&output.stdout);
+        Ok(logs.lines().filter_map(parse_log_entry).collect())
+    }
+
+    fn reload(&self) -> bool {
+        let Some(pid) = find_watcher_pid() else {
+            warn!("Could not determine nfd2nfc-watcher PID; falling back to full reload.");
+            return false;
+        };
+
+        match Command::new("kill")
+            .arg("-HUP")
+            .arg(pid.to_string())
+            .status()
+        {
+            Ok(s) if s.success() => {
+                log::info!(
+                    "Sent SIGHUP to nfd2nfc-watcher (pid {}) to reload configuration.",
+                    pid
+                );
+                true
+            }
+            Ok(s) => {
+                warn!("kill -HUP exited with {}; falling back to full reload.", s);
+                false
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to send SIGHUP to pid {}: {}. Falling back to full reload.",
+                    pid, e
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Looks up the running watcher's PID from `launchctl list`'s output, which
+/// is tab-separated `PID\tStatus\tLabel` rows (PID is "-" if not running).
+fn find_watcher_pid() -> Option<u32> {
+    let output = Command::new("launchctl").arg("list").output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .find(|line| line.contains(NFD2NFC_SERVICE_LABEL))
+        .and_then(|line| line.split_whitespace().next())
+        .and_then(|pid| pid.parse().ok())
+}
+
+fn stream_logs(tx: Sender<LogEntry>) {
+    let mut child = match Command::new("log")
+        .args(&[
+            "stream",
+            "--predicate",
+            &format!("subsystem == \"{}\"", NFD2NFC_SERVICE_LABEL),
+            "--style",
+            "ndjson",
+        ])
+        .stdout(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            error!("Failed to start log streaming: {}", e);
+            return;
+        }
+    };
+
+    let Some(stdout) = child.stdout.take() else {
+        error!("Failed to capture log stream stdout.");
+        return;
+    };
+    let reader = BufReader::new(stdout);
+
+    for line in reader.lines() {
+        match line {
+            Ok(l) => {
+                let Some(entry) = parse_log_entry(&l) else {
+                    continue;
+                };
+                if tx.send(entry).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                error!("Error reading log: {}", e);
+                break;
+            }
+        }
+    }
+    let _ = child.wait();
+}
+
+/// Parses a single `log stream`/`log show --style ndjson` record with
+/// `serde_json` rather than hunting for field substrings, so embedded quotes
+/// or newlines in `eventMessage` can't throw the parse off.
+fn parse_log_entry(line: &str) -> Option<LogEntry> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let message = value.get("eventMessage")?.as_str()?.to_string();
+    let timestamp = value
+        .get("timestamp")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let category = value
+        .get("category")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let message_type = value.get("messageType").and_then(|v| v.as_str());
+    let event_type = value.get("eventType").and_then(|v| v.as_str());
+    let level = match message_type.or(event_type).unwrap_or("Default") {
+        "Debug" => Level::Debug,
+        "Error" => Level::Error,
+        "Fault" => Level::Fault,
+        _ => Level::Info,
+    };
+
+    Some(LogEntry {
+        timestamp,
+        message,
+        level,
+        category,
+    })
+}
@@ -0,0 +1,131 @@
+//! Abstracts control of the background watcher process (start/stop/status and
+//! its logs) behind a `ServiceManager` trait, so callers don't hardcode
+//! `launchctl`/`~/Library/LaunchAgents`. NFD filenames show up on Linux boxes
+//! too (e.g. via SMB/AFP shares mounted from a Mac), so the watcher needs a
+//! backend that works there.
+//!
+//! [`current`] picks the implementation for the running platform; everything
+//! else in this module is shared between backends.
+
+mod launchd;
+mod systemd;
+
+pub use launchd::LaunchdManager;
+pub use systemd::SystemdUserManager;
+
+use nfd2nfc_common::constants::WATCHER_LIVE_MESSAGE;
+use std::fmt;
+use std::sync::mpsc::{self, Sender};
+use std::time::Duration;
+
+/// Severity of a service log entry. Ordered least to most severe so a
+/// minimum-level filter can compare with `<`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Error,
+    Fault,
+}
+
+impl Level {
+    /// Parses a `--level` CLI argument; accepts "warn" as a synonym for "error".
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "debug" => Some(Level::Debug),
+            "info" => Some(Level::Info),
+            "error" | "warn" | "warning" => Some(Level::Error),
+            "fault" => Some(Level::Fault),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Level::Debug => "Debug",
+            Level::Info => "Info",
+            Level::Error => "Error",
+            Level::Fault => "Fault",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A single log entry, independent of which backend produced it (unified
+/// log on macOS, the systemd journal on Linux).
+pub struct LogEntry {
+    pub timestamp: String,
+    pub message: String,
+    pub level: Level,
+    pub category: String,
+}
+
+/// Start/stop/status/logs control surface for the watcher background
+/// process, implemented once per service manager (launchd, systemd --user).
+pub trait ServiceManager: Send {
+    /// Starts the watcher and blocks until its live-message log line appears
+    /// (or the attempt times out with the watcher not running), returning
+    /// the accumulated log output either way.
+    fn start(&self) -> Result<String, String>;
+    fn stop(&self) -> Result<(), String>;
+    fn status(&self) -> bool;
+    /// Sends a live feed of log entries to `tx` until the source process
+    /// exits or the receiver is dropped.
+    fn stream_logs(&self, tx: Sender<LogEntry>);
+    fn log_history(&self, duration: &str) -> Result<Vec<LogEntry>, String>;
+    /// Best-effort in-place config reload (e.g. SIGHUP) that avoids tearing
+    /// down the watcher's event stream. Returns false if unsupported or
+    /// unsuccessful, so the caller can fall back to a full stop+start.
+    fn reload(&self) -> bool;
+}
+
+/// Picks the service backend for the running platform.
+pub fn current() -> Box<dyn ServiceManager> {
+    if cfg!(target_os = "macos") {
+        Box::new(LaunchdManager::new())
+    } else {
+        Box::new(SystemdUserManager::new())
+    }
+}
+
+/// Shared `start()` skeleton: spawn a thread reading `stream_logs`, run
+/// `launch` to actually start the process, then block on the log feed until
+/// the live-message entry arrives. Only `stream_logs`/`launch`/`still_running`
+/// differ between backends.
+pub(crate) fn start_and_wait_for_live_message(
+    stream_logs: impl FnOnce(Sender<LogEntry>) + Send + 'static,
+    launch: impl FnOnce() -> Result<(), String>,
+    still_running: impl Fn() -> bool,
+) -> Result<String, String> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || stream_logs(tx));
+
+    launch()?;
+
+    let timeout = Duration::from_secs_f32(0.3);
+    let mut logs_accumulated = String::new();
+
+    loop {
+        match rx.recv_timeout(timeout) {
+            Ok(entry) => {
+                logs_accumulated.push_str(&entry.message);
+                logs_accumulated.push('\n');
+                if entry.message == WATCHER_LIVE_MESSAGE {
+                    return Ok(logs_accumulated);
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if still_running() {
+                    continue;
+                } else {
+                    logs_accumulated.push_str("\nTimeout reached and watcher not running.");
+                    return Err(logs_accumulated);
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    Err(logs_accumulated)
+}
@@ -0,0 +1,216 @@
+use super::{start_and_wait_for_live_message, Level, LogEntry, ServiceManager};
+use log::error;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::Sender;
+
+const UNIT_NAME: &str = "nfd2nfc-watcher.service";
+
+/// Controls the watcher via `systemctl --user` and the systemd journal
+/// (Linux). The unit file itself is expected to already be installed by the
+/// package (analogous to the launchd plist); this only drives it.
+pub struct SystemdUserManager {
+    unit: String,
+}
+
+impl SystemdUserManager {
+    pub fn new() -> Self {
+        Self {
+            unit: UNIT_NAME.to_string(),
+        }
+    }
+
+    /// Resolved through the user's standard config directory rather than a
+    /// hardcoded path, so this works the same wherever `$XDG_CONFIG_HOME`
+    /// points.
+    fn unit_path(&self) -> PathBuf {
+        let Some(config_dir) = dirs::config_dir() else {
+            error!("Could not determine the user config directory.");
+            std::process::exit(1);
+        };
+        config_dir.join("systemd/user").join(&self.unit)
+    }
+
+    fn ensure_unit_installed(&self) -> Result<(), String> {
+        if self.unit_path().exists() {
+            Ok(())
+        } else {
+            Err(format!(
+                "systemd unit not found at {}.",
+                self.unit_path().display()
+            ))
+        }
+    }
+}
+
+impl ServiceManager for SystemdUserManager {
+    fn start(&self) -> Result<String, String> {
+        self.ensure_unit_installed()?;
+        let unit = self.unit.clone();
+        let unit_for_launch = self.unit.clone();
+        start_and_wait_for_live_message(
+            move |tx| stream_logs(&unit, tx),
+            move || {
+                let status = Command::new("systemctl")
+                    .args(["--user", "start", &unit_for_launch])
+                    .status()
+                    .map_err(|e| format!("Failed to start watcher: {}", e))?;
+
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(format!("Failed to start watcher: {}", status))
+                }
+            },
+            || self.status(),
+        )
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let status = Command::new("systemctl")
+            .args(["--user", "stop", &self.unit])
+            .status()
+            .map_err(|e| format!("Failed to stop service: {}", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to stop service: {}", status))
+        }
+    }
+
+    fn status(&self) -> bool {
+        Command::new("systemctl")
+            .args(["--user", "is-active", "--quiet", &self.unit])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    fn stream_logs(&self, tx: Sender<LogEntry>) {
+        stream_logs(&self.unit, tx);
+    }
+
+    fn log_history(&self, duration: &str) -> Result<Vec<LogEntry>, String> {
+        let output = Command::new("journalctl")
+            .args([
+                "--user",
+                "-u",
+                &self.unit,
+                "--since",
+                &since_arg(duration),
+                "-o",
+                "json",
+                "--no-pager",
+            ])
+            .output()
+            .map_err(|e| format!("Failed to execute journalctl: {}", e))?;
+
+        let logs = String::from_utf8_lossy(&output.stdout);
+        Ok(logs.lines().filter_map(parse_journal_entry).collect())
+    }
+
+    fn reload(&self) -> bool {
+        // Units that define `ExecReload=` reload in place; anything else
+        // fails here and the caller falls back to a full stop+start.
+        matches!(
+            Command::new("systemctl")
+                .args(["--user", "reload", &self.unit])
+                .status(),
+            Ok(s) if s.success()
+        )
+    }
+}
+
+fn stream_logs(unit: &str, tx: Sender<LogEntry>) {
+    let mut child = match Command::new("journalctl")
+        .args(["--user", "-u", unit, "-f", "-n", "0", "-o", "json"])
+        .stdout(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            error!("Failed to start journalctl: {}", e);
+            return;
+        }
+    };
+
+    let Some(stdout) = child.stdout.take() else {
+        error!("Failed to capture journalctl stdout.");
+        return;
+    };
+    let reader = BufReader::new(stdout);
+
+    for line in reader.lines() {
+        match line {
+            Ok(l) => {
+                let Some(entry) = parse_journal_entry(&l) else {
+                    continue;
+                };
+                if tx.send(entry).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                error!("Error reading journal: {}", e);
+                break;
+            }
+        }
+    }
+    let _ = child.wait();
+}
+
+/// Parses a `journalctl -o json` record. Unlike the unified log, the journal
+/// has no display-friendly timestamp field; `__REALTIME_TIMESTAMP` is left
+/// as-is (microseconds since the epoch) rather than pulling in a date/time
+/// dependency just to format it.
+fn parse_journal_entry(line: &str) -> Option<LogEntry> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let message = value.get("MESSAGE")?.as_str()?.to_string();
+    let timestamp = value
+        .get("__REALTIME_TIMESTAMP")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let category = value
+        .get("SYSLOG_IDENTIFIER")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let priority: u8 = value
+        .get("PRIORITY")
+        .and_then(|v| v.as_str())
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(6);
+    let level = match priority {
+        0..=2 => Level::Fault,
+        3 => Level::Error,
+        4..=6 => Level::Info,
+        _ => Level::Debug,
+    };
+
+    Some(LogEntry {
+        timestamp,
+        message,
+        level,
+        category,
+    })
+}
+
+/// Translates a `nfd2nfc watch log --last` duration (e.g. "5m", "2h", "30s")
+/// into a `journalctl --since` relative time expression (e.g. "5 minutes ago").
+fn since_arg(duration: &str) -> String {
+    let split_at = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (amount, unit) = duration.split_at(split_at);
+    let unit_word = match unit {
+        "s" => "seconds",
+        "m" => "minutes",
+        "h" => "hours",
+        "d" => "days",
+        other => other,
+    };
+    format!("{} {} ago", amount, unit_word)
+}
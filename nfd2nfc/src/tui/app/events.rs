@@ -1,8 +1,9 @@
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
 use std::time::Instant;
 
-use crossterm::event::{Event, KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Position, Rect};
 use ratatui::text::Span;
 use unicode_width::UnicodeWidthStr;
@@ -28,11 +29,12 @@ pub struct ClickableArea {
 /// Double-click threshold in milliseconds
 const DOUBLE_CLICK_THRESHOLD_MS: u128 = 300;
 
-/// Unified mouse state: click areas and double-click detection
+/// Unified mouse state: click areas, hover position, and double-click detection
 #[derive(Default)]
 pub struct MouseState {
     areas: Vec<ClickableArea>,
     last_click: Option<(u16, u16, Instant)>,
+    hover_pos: Option<(u16, u16)>,
 }
 
 impl MouseState {
@@ -52,6 +54,20 @@ impl MouseState {
             .map(|area| &area.action)
     }
 
+    /// Record the current mouse position, used by `is_hovered` to test
+    /// rects built during the next draw.
+    pub fn set_hover_pos(&mut self, x: u16, y: u16) {
+        self.hover_pos = Some((x, y));
+    }
+
+    /// Whether the current mouse position falls inside `rect`. Renderers
+    /// compute `rect` fresh every frame from that frame's own layout and
+    /// scroll offsets, so this is never tested against stale geometry from
+    /// a previous draw.
+    pub fn is_hovered(&self, rect: Rect) -> bool {
+        self.hover_pos.is_some_and(|(x, y)| rect.contains(Position { x, y }))
+    }
+
     /// Records a click and returns whether it forms a double-click with the previous one.
     fn detect_double_click(&mut self, x: u16, y: u16) -> bool {
         let now = Instant::now();
@@ -96,6 +112,20 @@ impl MouseState {
     }
 }
 
+/// Rect for the scrollable list row at `pos` (0-based index into the list's
+/// full, unscrolled data) inside `area`, whose top `top_margin` rows are a
+/// border/header and not part of the scrollable rows. `offset` is the
+/// list's current scroll offset. Returns `None` if `pos` falls outside the
+/// window currently visible in `area`.
+pub fn list_row_rect(area: Rect, top_margin: u16, offset: usize, pos: usize) -> Option<Rect> {
+    let row_in_view = pos.checked_sub(offset)?;
+    let y = area.y + top_margin + row_in_view as u16;
+    if y >= area.y + area.height {
+        return None;
+    }
+    Some(Rect::new(area.x, y, area.width, 1))
+}
+
 // ─────────────────────────────────────────────────────────────
 // Common key handling (was in tabs/mod.rs)
 // ─────────────────────────────────────────────────────────────
@@ -123,7 +153,7 @@ pub fn handle_event(
     maybe_event: Option<Result<Event, std::io::Error>>,
 ) -> Result<(), std::io::Error> {
     match maybe_event {
-        Some(Ok(Event::Key(key))) => handle_key(app, key.code),
+        Some(Ok(Event::Key(key))) => handle_key_event(app, key),
         Some(Ok(Event::Mouse(mouse))) => handle_mouse(app, mouse),
         Some(Err(e)) => return Err(e),
         _ => {}
@@ -131,6 +161,43 @@ pub fn handle_event(
     Ok(())
 }
 
+/// Top-level key dispatch: the command palette (and its Ctrl-P toggle) sits
+/// in front of everything else so it can be opened from, and closes back
+/// into, any tab.
+fn handle_key_event(app: &mut App, key: KeyEvent) {
+    if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        if app.command_palette.show {
+            app.command_palette.close();
+        } else {
+            app.open_command_palette();
+        }
+        return;
+    }
+
+    if app.command_palette.show {
+        handle_palette_key(app, key.code);
+        return;
+    }
+
+    handle_key(app, key.code);
+}
+
+fn handle_palette_key(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Esc => app.command_palette.close(),
+        KeyCode::Up => app.command_palette.select_previous(),
+        KeyCode::Down => app.command_palette.select_next(),
+        KeyCode::Enter => {
+            if let Some(replay_key) = app.command_palette.confirm() {
+                handle_key(app, replay_key);
+            }
+        }
+        KeyCode::Backspace => app.command_palette.pop_char(),
+        KeyCode::Char(c) => app.command_palette.push_char(c),
+        _ => {}
+    }
+}
+
 pub fn handle_key(app: &mut App, key: KeyCode) {
     // 1. Let current tab handle first
     let shared = app.shared_state();
@@ -155,6 +222,7 @@ pub fn handle_key(app: &mut App, key: KeyCode) {
 fn handle_mouse(app: &mut App, mouse: MouseEvent) {
     let x = mouse.column;
     let y = mouse.row;
+    app.mouse_state.set_hover_pos(x, y);
 
     match mouse.kind {
         MouseEventKind::Down(MouseButton::Left) => match app.mouse_state.resolve_click(x, y) {
@@ -209,10 +277,48 @@ fn dispatch_scroll(app: &mut App, direction: ScrollDirection) {
 
 fn spawn_watcher_op(app: &mut App, op: PendingWatcherOperation, f: fn() -> Result<(), String>) {
     let (tx, rx) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let worker_cancel = cancel.clone();
     thread::spawn(move || {
-        let _ = tx.send(f());
+        // `f` is a single blocking call with no internal cancellation
+        // point, so a cancelled op can't be aborted mid-flight -- the best
+        // it can do cleanly is skip reporting a result nobody's waiting
+        // for anymore once it finally returns.
+        let result = f();
+        if !worker_cancel.load(Ordering::Relaxed) {
+            let _ = tx.send(result);
+        }
     });
-    app.start_async_operation(op, rx);
+    app.start_async_operation(op, rx, cancel);
+}
+
+/// Pushes `pause`/`resume` to the already-running watcher over the
+/// long-lived control-channel sender in `app`, connecting it first if this
+/// is the first pause/resume since the watcher last (re)started. Unlike
+/// `spawn_watcher_op`, this doesn't need a background thread:
+/// `connect_control_channel` opens the FIFO non-blocking and `send` is a
+/// single short line write, so neither step can hang the UI thread waiting
+/// on the control thread the way a blocking open would.
+fn send_pause_resume(app: &mut App, resume: bool) {
+    let op = if resume {
+        PendingWatcherOperation::Resuming
+    } else {
+        PendingWatcherOperation::Pausing
+    };
+
+    if app.control_sender.is_none() {
+        app.control_sender = daemon_controller::connect_control_channel();
+    }
+
+    let result = match app.control_sender.as_mut() {
+        Some(sender) if resume => sender.resume(),
+        Some(sender) => sender.pause(),
+        None => Err("Watcher control channel is not connected.".to_string()),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let _ = tx.send(result);
+    app.start_async_operation(op, rx, Arc::new(AtomicBool::new(false)));
 }
 
 pub fn process_action(app: &mut App, action: Action) {
@@ -244,9 +350,12 @@ pub fn process_action(app: &mut App, action: Action) {
                 daemon_controller::try_restart_watcher,
             );
         }
+        Action::PauseWatcher => send_pause_resume(app, false),
+        Action::ResumeWatcher => send_pause_resume(app, true),
+        Action::CancelWatcherOp => app.cancel_async_operation(),
         Action::ConfigSaved => {
             app.show_toast("Config saved".to_string(), false);
-            if app.watcher_running {
+            if app.watcher_health.is_up() {
                 spawn_watcher_op(
                     app,
                     PendingWatcherOperation::Restarting,
@@ -6,10 +6,11 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 use strum::IntoEnumIterator;
+use unicode_width::UnicodeWidthStr;
 
 use crate::tui::app::state::App;
 use crate::tui::component::TabComponent;
-use crate::tui::styles::{StatusLabels, bold_fg, border_style, key_style, watcher_status_span};
+use crate::tui::styles::{StatusLabels, bold_fg, border_style, hover_style, key_style, watcher_status_span};
 use crate::tui::tabs::Tab;
 
 pub struct AppLayout {
@@ -44,6 +45,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 
     let content_area = layout(f.area()).content;
     app.toast.render(f, content_area);
+    app.command_palette.render(f, content_area);
 }
 
 fn draw_header(f: &mut Frame, app: &mut App) {
@@ -54,11 +56,14 @@ fn draw_header(f: &mut Frame, app: &mut App) {
     // Status indicator (shows pending operation if in progress)
     let status = Line::from(watcher_status_span(
         app.async_operation.as_ref().map(|op| op.kind),
-        app.watcher_running,
+        app.watcher_health,
+        app.paused,
         &StatusLabels {
             pending_prefix: " ◐ ",
             pending_suffix: " ",
             running: " ● Running ",
+            idle: " ● Idle ",
+            paused: " ◑ Paused ",
             stopped: " ○ Stopped ",
         },
     ));
@@ -72,6 +77,25 @@ fn draw_header(f: &mut Frame, app: &mut App) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
+    // Precompute each tab's rect to test hover, matching the left-to-right
+    // layout `add_shortcuts` below will register as click areas, so the
+    // non-active tab under the cursor can be styled before its click area
+    // even exists yet.
+    let mut hover_x = inner.x + 1; // leading space
+    let tab_hovered: Vec<bool> = Tab::iter()
+        .enumerate()
+        .map(|(i, tab)| {
+            if i > 0 {
+                hover_x += divider.width() as u16;
+            }
+            let width = (tab.superscript().width() + tab.title().width()) as u16;
+            let rect = Rect::new(hover_x, inner.y, width, 1);
+            let hovered = tab.index() != current_idx && app.mouse_state.is_hovered(rect);
+            hover_x += width;
+            hovered
+        })
+        .collect();
+
     // Build tab items — add_shortcuts registers click areas and returns spans
     let mut items: Vec<(Vec<Span>, Option<crossterm::event::KeyCode>)> = Vec::new();
     // Leading space to match original Tabs widget padding
@@ -80,6 +104,8 @@ fn draw_header(f: &mut Frame, app: &mut App) {
         let superscript_style = key_style();
         let title_style = if tab.index() == current_idx {
             bold_fg(Color::LightCyan)
+        } else if tab_hovered[i] {
+            Style::default().fg(Color::White).patch(hover_style())
         } else {
             Style::default().fg(Color::White)
         };
@@ -1,11 +1,17 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use nfd2nfc_core::config::load_config;
 use nfd2nfc_core::constants::HEARTBEAT_CHECK_INTERVAL;
+use nfd2nfc_core::heartbeat::WatcherHealth;
 
 use crate::daemon_controller;
-use crate::tui::app::events::MouseState;
+use crate::tui::app::events::{process_action, MouseState};
+use crate::tui::command_palette::{global_commands, CommandPalette};
 use crate::tui::component::{SharedState, TabComponent};
+use crate::tui::inputs::{self, AppEvent};
 use crate::tui::tabs::{BrowserState, ConfigState, HomeState, LogsState, Tab};
 use crate::tui::tick_timer::TickTimer;
 use crate::tui::toast::{ToastLevel, ToastState};
@@ -15,11 +21,23 @@ pub enum PendingWatcherOperation {
     Starting,
     Stopping,
     Restarting,
+    Pausing,
+    Resuming,
 }
 
+/// How long a pending watcher operation is given to report back before
+/// `tick()` gives up on it and surfaces a timeout error instead of leaving
+/// the spinner running forever.
+const WATCHER_OP_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct AsyncOperation {
     pub kind: PendingWatcherOperation,
     pub result_rx: Receiver<Result<(), String>>,
+    /// Set by `tick()`'s timeout check or `App::cancel_async_operation`;
+    /// the worker thread checks it before reporting a result nobody's
+    /// waiting for anymore.
+    cancel: Arc<AtomicBool>,
+    deadline: Instant,
 }
 
 /// Main application state
@@ -27,7 +45,23 @@ pub struct App {
     pub running: bool,
     pub force_redraw: bool,
     pub current_tab: Tab,
-    pub watcher_running: bool,
+    /// Active/idle/dead liveness of the watcher daemon, read from its
+    /// heartbeat file (see [`nfd2nfc_core::heartbeat`]) rather than a bare
+    /// on/off flag, so a wedged-but-still-running process shows up as dead
+    /// instead of masquerading as healthy.
+    pub watcher_health: WatcherHealth,
+    /// Whether the watcher is currently suspending NFD->NFC conversion
+    /// (see [`PendingWatcherOperation::Pausing`]/[`PendingWatcherOperation::Resuming`]).
+    pub paused: bool,
+    /// FIFO connection to the watcher's control channel, opened lazily on
+    /// the first pause/resume and kept around afterward so later ones are a
+    /// single line write instead of a fresh connection.
+    pub control_sender: Option<daemon_controller::WatcherControlSender>,
+    /// Latest reading of the watcher's live `status_out` file (pending/
+    /// suppressed event counts, effective renames/sec under `tranquility`
+    /// throttling), refreshed on the same cadence as `watcher_health`.
+    /// `None` before the first successful read, or once the watcher is down.
+    pub watcher_stats: Option<daemon_controller::WatcherStats>,
     pub home: HomeState,
     pub config: ConfigState,
     pub logs: LogsState,
@@ -35,14 +69,18 @@ pub struct App {
     pub toast: ToastState,
     pub async_operation: Option<AsyncOperation>,
     pub mouse_state: MouseState,
+    pub command_palette: CommandPalette,
+    pub events: inputs::Reader,
     heartbeat_timer: TickTimer,
 }
 
 impl App {
     pub fn new() -> Self {
-        let watcher_running = daemon_controller::check_watcher_status();
+        let watcher_health = daemon_controller::check_watcher_status();
+        let watcher_stats = watcher_health.is_up().then(daemon_controller::read_watcher_stats).flatten();
         let (loaded_config, load_err) = load_config();
-        let mut config = ConfigState::from_config(loaded_config);
+        let (events_tx, events, level_filter_tx) = inputs::spawn();
+        let mut config = ConfigState::from_config(loaded_config, events_tx.clone());
         let mut toast = ToastState::new();
         if let Some(e) = load_err {
             config.has_changes = true;
@@ -53,21 +91,45 @@ impl App {
             running: true,
             force_redraw: false,
             current_tab: Tab::Home,
-            watcher_running,
+            watcher_health,
+            paused: false,
+            control_sender: None,
+            watcher_stats,
             home: HomeState::default(),
             config,
-            logs: LogsState::new(),
-            browser: BrowserState::new(),
+            logs: LogsState::new(level_filter_tx),
+            browser: BrowserState::new(events_tx),
             toast,
             async_operation: None,
             mouse_state: MouseState::default(),
+            command_palette: CommandPalette::new(),
+            events,
             heartbeat_timer: TickTimer::new(HEARTBEAT_CHECK_INTERVAL),
         }
     }
 
+    /// Dispatch one event from the unified background input channel
+    /// (log stream, clock tick, config watcher, browser directory watcher).
+    pub fn handle_app_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::Log(log_event) => self.logs.handle_log_event(log_event),
+            AppEvent::Tick => self.tick(),
+            AppEvent::ConfigChanged => self.config.on_config_changed(),
+            // Routed to every DirBrowser instance (the Browser tab and the
+            // config tab's add-path modal); each ignores it unless it's the
+            // directory it's currently watching.
+            AppEvent::DirChanged(path) => {
+                self.browser.on_dir_changed(path.clone());
+                self.config.modal.browser.handle_watch_event(path);
+            }
+        }
+    }
+
     pub fn shared_state(&self) -> SharedState {
         SharedState {
-            watcher_running: self.watcher_running,
+            watcher_health: self.watcher_health,
+            paused: self.paused,
+            watcher_stats: self.watcher_stats,
             async_op_pending: self.async_operation.is_some(),
             pending_operation: self.async_operation.as_ref().map(|op| op.kind),
             current_tab: self.current_tab,
@@ -82,34 +144,67 @@ impl App {
             if let Ok(result) = op.result_rx.try_recv() {
                 match (&op.kind, result) {
                     (PendingWatcherOperation::Starting, Ok(())) => {
-                        self.watcher_running = true;
+                        self.watcher_health = daemon_controller::check_watcher_status();
+                        self.watcher_stats = daemon_controller::read_watcher_stats();
+                        self.paused = false;
                         self.show_toast("Watcher started".to_string(), false);
                     }
                     (PendingWatcherOperation::Starting, Err(e)) => {
                         self.show_toast(format!("Failed to start: {}", e), true);
                     }
                     (PendingWatcherOperation::Stopping, Ok(())) => {
-                        self.watcher_running = false;
+                        self.watcher_health = WatcherHealth::Dead;
+                        self.watcher_stats = None;
+                        self.paused = false;
+                        self.control_sender = None;
                         self.show_toast("Watcher stopped".to_string(), false);
                     }
                     (PendingWatcherOperation::Stopping, Err(e)) => {
                         self.show_toast(format!("Failed to stop: {}", e), true);
                     }
                     (PendingWatcherOperation::Restarting, Ok(())) => {
-                        self.watcher_running = true;
+                        self.watcher_health = daemon_controller::check_watcher_status();
+                        self.watcher_stats = daemon_controller::read_watcher_stats();
+                        self.paused = false;
+                        self.control_sender = None;
                         self.show_toast("Watcher restarted".to_string(), false);
                     }
                     (PendingWatcherOperation::Restarting, Err(e)) => {
                         self.show_toast(format!("Failed to restart: {}", e), true);
                     }
+                    (PendingWatcherOperation::Pausing, Ok(())) => {
+                        self.paused = true;
+                        self.show_toast("Watcher paused".to_string(), false);
+                    }
+                    (PendingWatcherOperation::Pausing, Err(e)) => {
+                        self.show_toast(format!("Failed to pause: {}", e), true);
+                    }
+                    (PendingWatcherOperation::Resuming, Ok(())) => {
+                        self.paused = false;
+                        self.show_toast("Watcher resumed".to_string(), false);
+                    }
+                    (PendingWatcherOperation::Resuming, Err(e)) => {
+                        self.show_toast(format!("Failed to resume: {}", e), true);
+                    }
                 }
                 self.async_operation = None;
+            } else if Instant::now() >= op.deadline {
+                op.cancel.store(true, Ordering::Relaxed);
+                let kind = op.kind;
+                self.fail_async_operation(kind, "timed out".to_string());
             }
         }
 
         // Update watcher status only when no operation is pending, throttled to 1s interval
         if self.async_operation.is_none() && self.heartbeat_timer.ready() {
-            self.watcher_running = daemon_controller::check_watcher_status();
+            self.watcher_health = daemon_controller::check_watcher_status();
+            if self.watcher_health.is_up() {
+                self.watcher_stats = daemon_controller::read_watcher_stats();
+            } else {
+                self.paused = false;
+                self.control_sender = None;
+                self.watcher_stats = None;
+            }
         }
 
         // Tick all tab components
@@ -117,7 +212,9 @@ impl App {
         self.home.tick(&shared);
         self.config.tick(&shared);
         self.logs.tick(&shared);
-        self.browser.tick(&shared);
+        if let Some(action) = self.browser.tick(&shared) {
+            process_action(self, action);
+        }
     }
 
     pub fn show_toast(&mut self, message: String, is_error: bool) {
@@ -133,13 +230,41 @@ impl App {
         &mut self,
         operation: PendingWatcherOperation,
         rx: Receiver<Result<(), String>>,
+        cancel: Arc<AtomicBool>,
     ) {
         self.async_operation = Some(AsyncOperation {
             kind: operation,
             result_rx: rx,
+            cancel,
+            deadline: Instant::now() + WATCHER_OP_TIMEOUT,
         });
     }
 
+    /// Requested by the user (the cancel keybind) rather than a timeout:
+    /// signals the worker and gives up on the pending operation immediately
+    /// instead of waiting out the rest of `WATCHER_OP_TIMEOUT`.
+    pub fn cancel_async_operation(&mut self) {
+        let Some(op) = &self.async_operation else { return };
+        op.cancel.store(true, Ordering::Relaxed);
+        let kind = op.kind;
+        self.fail_async_operation(kind, "cancelled".to_string());
+    }
+
+    /// Shared by the timeout check and the cancel keybind: reports `reason`
+    /// as an error toast for the named operation and clears it, without
+    /// waiting on a worker thread that may never report back.
+    fn fail_async_operation(&mut self, kind: PendingWatcherOperation, reason: String) {
+        let verb = match kind {
+            PendingWatcherOperation::Starting => "Start",
+            PendingWatcherOperation::Stopping => "Stop",
+            PendingWatcherOperation::Restarting => "Restart",
+            PendingWatcherOperation::Pausing => "Pause",
+            PendingWatcherOperation::Resuming => "Resume",
+        };
+        self.show_toast(format!("{} {}", verb, reason), true);
+        self.async_operation = None;
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
@@ -168,4 +293,18 @@ impl App {
         self.current_tab = tab;
         self.force_redraw = true;
     }
+
+    /// Open the command palette with the current tab's commands plus the
+    /// globals (tab switching, quit).
+    pub fn open_command_palette(&mut self) {
+        let shared = self.shared_state();
+        let mut commands = match self.current_tab {
+            Tab::Home => self.home.commands(&shared),
+            Tab::Config => self.config.commands(&shared),
+            Tab::Logs => self.logs.commands(&shared),
+            Tab::Browser => self.browser.commands(&shared),
+        };
+        commands.extend(global_commands());
+        self.command_palette.open(commands);
+    }
 }
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use ratatui::widgets::ListState;
+
+use crate::tui::component::{next_index, prev_index};
+
+/// One row in the bookmarks popup.
+pub struct BookmarkEntry {
+    pub key: char,
+    pub path: PathBuf,
+}
+
+/// hunter-style bookmark jump popup: lists every directory saved under
+/// `DirBrowser::set_bookmark`, letting the user arrow down to one and jump.
+pub struct BookmarksPicker {
+    pub show: bool,
+    pub entries: Vec<BookmarkEntry>,
+    pub list_state: ListState,
+}
+
+impl BookmarksPicker {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            entries: Vec::new(),
+            list_state: ListState::default(),
+        }
+    }
+
+    /// Snapshot the browser's current bookmarks and show the popup.
+    pub fn open(&mut self, bookmarks: &[(char, PathBuf)]) {
+        self.entries = bookmarks
+            .iter()
+            .map(|(key, path)| BookmarkEntry {
+                key: *key,
+                path: path.clone(),
+            })
+            .collect();
+        self.list_state
+            .select(if self.entries.is_empty() { None } else { Some(0) });
+        self.show = true;
+    }
+
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+
+    pub fn select_next(&mut self) {
+        if let Some(i) = next_index(self.list_state.selected(), self.entries.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(i) = prev_index(self.list_state.selected(), self.entries.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.path.clone())
+    }
+}
+
+impl Default for BookmarksPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
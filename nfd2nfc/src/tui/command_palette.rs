@@ -0,0 +1,196 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+use strum::IntoEnumIterator;
+
+use crate::tui::component::{next_index, prev_index};
+use crate::tui::dir_browser::match_positions;
+use crate::tui::styles::{border_style, key_style};
+use crate::tui::tabs::browser::render::centered_rect;
+use crate::tui::tabs::Tab;
+
+/// One entry in the command palette: a human label, the key that already
+/// invokes it on the owning tab, and the key itself to replay on selection —
+/// the same mechanism `MouseState` uses to fire a shortcut from a click.
+pub struct PaletteCommand {
+    pub label: String,
+    pub key_label: &'static str,
+    pub key: KeyCode,
+}
+
+/// Editor-style command palette: every command reachable from the current
+/// tab plus the always-available globals (tab switching, quit), searchable
+/// by an in-order case-insensitive subsequence match over the label.
+pub struct CommandPalette {
+    pub show: bool,
+    pub query: String,
+    commands: Vec<PaletteCommand>,
+    /// Indices into `commands` that pass the current query, in list order.
+    pub filtered: Vec<usize>,
+    pub list_state: ListState,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            query: String::new(),
+            commands: Vec::new(),
+            filtered: Vec::new(),
+            list_state: ListState::default(),
+        }
+    }
+
+    /// Open the palette with a freshly built command list (tab-specific
+    /// commands plus the globals), resetting any previous query.
+    pub fn open(&mut self, commands: Vec<PaletteCommand>) {
+        self.commands = commands;
+        self.query.clear();
+        self.refilter();
+        self.show = true;
+    }
+
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    fn refilter(&mut self) {
+        self.filtered = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter(|(_, cmd)| self.query.is_empty() || match_positions(&self.query, &cmd.label).is_some())
+            .map(|(i, _)| i)
+            .collect();
+        self.list_state
+            .select(if self.filtered.is_empty() { None } else { Some(0) });
+    }
+
+    pub fn select_next(&mut self) {
+        if let Some(i) = next_index(self.list_state.selected(), self.filtered.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(i) = prev_index(self.list_state.selected(), self.filtered.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    /// Close the palette and return the key bound to the selected command,
+    /// for the caller to replay through the normal key-handling path.
+    pub fn confirm(&mut self) -> Option<KeyCode> {
+        let key = self
+            .list_state
+            .selected()
+            .and_then(|i| self.filtered.get(i))
+            .and_then(|&idx| self.commands.get(idx))
+            .map(|cmd| cmd.key);
+        self.close();
+        key
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        if !self.show {
+            return;
+        }
+
+        let popup = centered_rect(area, 60, 60);
+        f.render_widget(Clear, popup);
+
+        let items: Vec<ListItem> = self
+            .filtered
+            .iter()
+            .map(|&idx| {
+                let cmd = &self.commands[idx];
+                let mut spans = label_spans(&cmd.label, &self.query);
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(format!("[{}]", cmd.key_label), key_style()));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style())
+                    .border_type(BorderType::Rounded)
+                    .title(format!(" Commands  /{}  [↵] Run  [Esc] Close ", self.query)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        let mut list_state = self.list_state.clone();
+        f.render_stateful_widget(list, popup, &mut list_state);
+    }
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split `label` into spans, highlighting the characters `match_positions`
+/// matched against the active query.
+fn label_spans<'a>(label: &'a str, query: &str) -> Vec<Span<'a>> {
+    let Some(matched) = (!query.is_empty()).then(|| match_positions(query, label)).flatten() else {
+        return vec![Span::raw(label)];
+    };
+
+    label
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if matched.contains(&i) {
+                Span::styled(c.to_string(), Style::default().fg(Color::Yellow))
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Commands available from anywhere: switching to any tab, plus quitting.
+pub fn global_commands() -> Vec<PaletteCommand> {
+    let mut commands: Vec<PaletteCommand> = Tab::iter()
+        .map(|tab| {
+            let key = tab.key();
+            let key_label = match tab {
+                Tab::Home => "1",
+                Tab::Config => "2",
+                Tab::Logs => "3",
+                Tab::Browser => "4",
+            };
+            PaletteCommand {
+                label: format!("Go to {}", tab.title()),
+                key_label,
+                key,
+            }
+        })
+        .collect();
+
+    commands.push(PaletteCommand {
+        label: "Quit".to_string(),
+        key_label: "q",
+        key: KeyCode::Char('q'),
+    });
+
+    commands
+}
@@ -2,14 +2,26 @@ use crossterm::event::KeyCode;
 use ratatui::layout::Rect;
 use ratatui::Frame;
 
+use nfd2nfc_core::heartbeat::WatcherHealth;
+
+use crate::daemon_controller::WatcherStats;
 use crate::tui::app::events::MouseState;
 use crate::tui::app::state::PendingWatcherOperation;
+use crate::tui::command_palette::PaletteCommand;
 use crate::tui::tabs::Tab;
 
 /// Read-only shared state passed to tab components (Copy to avoid borrow conflicts)
 #[derive(Debug, Clone, Copy)]
 pub struct SharedState {
-    pub watcher_running: bool,
+    pub watcher_health: WatcherHealth,
+    /// Whether the running watcher is currently suspending NFD->NFC
+    /// conversion in response to a pause command. Meaningless while
+    /// `watcher_health` isn't up.
+    pub paused: bool,
+    /// Live debounce/suppression/throttle numbers read from the watcher's
+    /// `status_out` file, for the Home tab to display. `None` while the
+    /// watcher is down or hasn't written a snapshot yet.
+    pub watcher_stats: Option<WatcherStats>,
     pub async_op_pending: bool,
     pub pending_operation: Option<PendingWatcherOperation>,
     pub current_tab: Tab,
@@ -31,11 +43,57 @@ pub enum Action {
     StartWatcher,
     StopWatcher,
     RestartWatcher,
+    PauseWatcher,
+    ResumeWatcher,
+    CancelWatcherOp,
     ConfigSaved,
     ReloadConfig,
     Consumed,
 }
 
+/// Computes the index after `current` in a 0..len list, wrapping to the
+/// start. Returns `None` if the list is empty.
+pub fn next_index(current: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        Some(i) if i + 1 < len => i + 1,
+        _ => 0,
+    })
+}
+
+/// Computes the index before `current` in a 0..len list, wrapping to the
+/// end. Returns `None` if the list is empty.
+pub fn prev_index(current: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        Some(0) | None => len - 1,
+        Some(i) => i - 1,
+    })
+}
+
+/// Accumulates a digit keypress into a pending vim-style count prefix. A
+/// bare `0` with no count already in progress is left unconsumed (returns
+/// `false`) so it stays free for other bindings instead of starting a
+/// jump-to-top-like motion.
+pub fn push_count_digit(pending: &mut Option<usize>, digit: u32) -> bool {
+    if pending.is_none() && digit == 0 {
+        return false;
+    }
+    *pending = Some(pending.unwrap_or(0).saturating_mul(10).saturating_add(digit as usize));
+    true
+}
+
+/// Takes the pending count (defaulting to 1 if none was entered) and clears
+/// the buffer. Call this once per handled key so an unused count doesn't
+/// leak into a later keypress.
+pub fn take_count(pending: &mut Option<usize>) -> usize {
+    pending.take().unwrap_or(1)
+}
+
 /// Trait that all tab components must implement
 pub trait TabComponent {
     fn render(&mut self, f: &mut Frame, area: Rect, shared: &SharedState, mouse: &mut MouseState);
@@ -56,4 +114,10 @@ pub trait TabComponent {
     fn tick(&mut self, _shared: &SharedState) -> Option<Action> {
         None
     }
+
+    /// Commands this tab offers to the command palette, in addition to the
+    /// globals (tab switching, quit). Defaults to none.
+    fn commands(&self, _shared: &SharedState) -> Vec<PaletteCommand> {
+        Vec::new()
+    }
 }
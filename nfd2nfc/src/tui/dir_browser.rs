@@ -1,20 +1,30 @@
 use std::cmp::Ordering;
 use std::fs;
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
+use log::warn;
+use notify::{Event as NotifyEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use ratatui::style::Color;
 use ratatui::widgets::ListState;
 
+use nfd2nfc_common::config::{read_or_default_config, BookmarkEntry, BrowserSortMode};
+use nfd2nfc_common::constants::CONFIG_PATH;
 use nfd2nfc_core::constants::HOME_DIR;
-use nfd2nfc_core::normalizer::get_actual_file_name;
+use nfd2nfc_core::normalizer::{
+    get_actual_file_name, set_normalizer_threads, CollisionStrategy, NormalizationTarget,
+};
 use nfd2nfc_core::{is_nfc, is_nfd};
 use unicode_normalization::UnicodeNormalization;
 
 use crate::tui::component::{next_index, prev_index};
+use crate::tui::inputs::{AppEvent, Writer};
 use crate::tui::tick_timer::TickTimer;
 
-const AUTO_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+/// Low-frequency fallback refresh for volumes where FSEvents can miss
+/// changes (e.g. some network mounts). The FSEvents watch below is what
+/// makes the browser react instantly in the common case.
+const FALLBACK_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
@@ -54,6 +64,9 @@ pub enum SelectionKind {
     DirUnicode,
     /// Directory with ASCII/Mixed name
     DirAscii,
+    /// Directory with a background recursive convert task running on it
+    /// (see `BrowserState::effective_selection_kind`)
+    DirRecursive,
     /// File with NFD name
     FileNFD,
     /// File with NFC name
@@ -81,15 +94,52 @@ pub struct BrowserEntry {
     pub is_dir: bool,
     pub is_parent: bool,
     pub form: UnicodeForm,
+    /// Collected from the same `fs::metadata` call as `size`, so sorting by
+    /// either needs no extra stat calls.
+    pub modified: Option<SystemTime>,
+    pub size: u64,
+}
+
+/// Incremental "type to filter" query over the current directory's entries,
+/// in the style of yazi/broot's filter input.
+#[derive(Debug, Clone, Default)]
+pub struct FilterState {
+    pub active: bool,
+    pub query: String,
+}
+
+/// Inline rename / manual normalization editor for the selected entry, in
+/// the style of sdn's and dirbuilder's line editors. `input` starts out
+/// pre-filled with the on-disk name and is edited in place over the
+/// entry's row.
+#[derive(Debug, Clone, Default)]
+pub struct RenameState {
+    pub active: bool,
+    pub input: String,
 }
 
 pub struct DirBrowser {
     pub current_dir: PathBuf,
+    /// The unfiltered directory listing; `entries` is always derived from
+    /// this by `filter`.
+    all_entries: Vec<BrowserEntry>,
     pub entries: Vec<BrowserEntry>,
+    pub filter: FilterState,
+    pub rename: RenameState,
     pub list_state: ListState,
     pub show_hidden: bool,
+    pub sort_mode: BrowserSortMode,
+    pub sort_reverse: bool,
+    /// How `BrowserState::convert_selected` handles a converted name that
+    /// already exists as a different file.
+    pub collision_strategy: CollisionStrategy,
+    /// Directories bookmarked under a single-character label, in the order
+    /// they were saved.
+    pub bookmarks: Vec<(char, PathBuf)>,
     pub render_offset: usize,
-    auto_refresh_timer: TickTimer,
+    events_tx: Writer,
+    watcher: Option<RecommendedWatcher>,
+    fallback_refresh_timer: TickTimer,
 }
 
 impl std::fmt::Debug for DirBrowser {
@@ -103,21 +153,72 @@ impl std::fmt::Debug for DirBrowser {
 }
 
 impl DirBrowser {
-    pub fn new() -> Self {
+    pub fn new(events_tx: Writer) -> Self {
+        let raw_config = read_or_default_config(&CONFIG_PATH).unwrap_or_default();
+        set_normalizer_threads(raw_config.normalizer_threads);
         let mut browser = Self {
             current_dir: HOME_DIR.clone(),
+            all_entries: Vec::new(),
             entries: Vec::new(),
+            filter: FilterState::default(),
+            rename: RenameState::default(),
             list_state: ListState::default(),
-            show_hidden: false,
+            show_hidden: raw_config.browser_show_hidden,
+            sort_mode: raw_config.browser_sort_mode,
+            sort_reverse: raw_config.browser_sort_reverse,
+            collision_strategy: raw_config.collision_strategy,
+            bookmarks: raw_config
+                .browser_bookmarks
+                .iter()
+                .map(|b| (b.key, PathBuf::from(&b.path)))
+                .collect(),
             render_offset: 0,
-            auto_refresh_timer: TickTimer::new(AUTO_REFRESH_INTERVAL),
+            events_tx,
+            watcher: None,
+            fallback_refresh_timer: TickTimer::new(FALLBACK_REFRESH_INTERVAL),
         };
         browser.refresh();
+        browser.rewatch();
         browser
     }
 
     pub fn tick(&mut self, active: bool) {
-        if active && self.auto_refresh_timer.ready() {
+        if active && self.fallback_refresh_timer.ready() {
+            self.refresh();
+        }
+    }
+
+    /// (Re)register the FSEvents watch on `current_dir`, tearing down
+    /// whatever the previous watch was on. Silently gives up if the
+    /// watcher can't be created or the path can't be watched; the
+    /// fallback timer still covers us in that case.
+    fn rewatch(&mut self) {
+        let watched_dir = self.current_dir.clone();
+        let tx = self.events_tx.clone();
+
+        let watcher = RecommendedWatcher::new(
+            move |res: Result<NotifyEvent, notify::Error>| {
+                if let Ok(event) = res {
+                    if event.kind.is_create() || event.kind.is_remove() || event.kind.is_modify() {
+                        let _ = tx.send(AppEvent::DirChanged(watched_dir.clone()));
+                    }
+                }
+            },
+            notify::Config::default(),
+        )
+        .and_then(|mut watcher| {
+            watcher.watch(&self.current_dir, RecursiveMode::NonRecursive)?;
+            Ok(watcher)
+        });
+
+        self.watcher = watcher.ok();
+    }
+
+    /// Called when the background watch reports a change in `changed_dir`.
+    /// Refreshes only if that's still the directory being shown, since the
+    /// watch may have already moved on by the time the event arrives.
+    pub fn handle_watch_event(&mut self, changed_dir: PathBuf) {
+        if changed_dir == self.current_dir {
             self.refresh();
         }
     }
@@ -144,6 +245,7 @@ impl DirBrowser {
                 self.current_dir = fallback;
                 self.list_state.select(Some(0));
                 self.render_offset = 0;
+                self.rewatch();
             }
         }
 
@@ -151,7 +253,7 @@ impl DirBrowser {
             .selected_entry()
             .map(|e| e.name.nfc().collect::<String>());
 
-        self.entries.clear();
+        self.all_entries.clear();
 
         let read_result = fs::read_dir(&self.current_dir);
         if let Ok(entries) = read_result {
@@ -160,7 +262,12 @@ impl DirBrowser {
                 .filter_map(|entry| entry.ok())
                 .filter_map(|entry| {
                     let path = entry.path();
-                    let is_dir = path.is_dir();
+                    // Single stat call backs is_dir, modified, and size, so
+                    // sorting by any of them needs no further syscalls.
+                    let metadata = fs::metadata(&path).ok();
+                    let is_dir = metadata.as_ref().map_or_else(|| path.is_dir(), |m| m.is_dir());
+                    let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+                    let size = metadata.as_ref().map_or(0, |m| m.len());
 
                     // Get the actual name from disk
                     let name = if let Ok(actual) = get_actual_file_name(&path) {
@@ -182,23 +289,20 @@ impl DirBrowser {
                         is_dir,
                         is_parent: false,
                         form,
+                        modified,
+                        size,
                     })
                 })
                 .collect();
 
-            // Sort: directories first, then by name
-            items.sort_by(|a, b| match (a.is_dir, b.is_dir) {
-                (true, false) => Ordering::Less,
-                (false, true) => Ordering::Greater,
-                _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-            });
+            sort_entries(&mut items, self.sort_mode, self.sort_reverse);
 
-            self.entries = items;
+            self.all_entries = items;
         }
 
         // Insert parent entry at index 0
         if let Some(parent) = self.current_dir.parent() {
-            self.entries.insert(
+            self.all_entries.insert(
                 0,
                 BrowserEntry {
                     path: parent.to_path_buf(),
@@ -206,11 +310,38 @@ impl DirBrowser {
                     is_dir: true,
                     is_parent: true,
                     form: UnicodeForm::ASCII,
+                    modified: None,
+                    size: 0,
                 },
             );
         }
 
-        // Restore selection by NFC-normalized filename, or clamp index as fallback
+        self.apply_filter(prev_selected_name);
+    }
+
+    /// Recompute `entries` from `all_entries` through the current filter
+    /// query, then restore the selection by NFC-normalized filename (or
+    /// clamp the index as a fallback).
+    fn apply_filter(&mut self, prev_selected_name: Option<String>) {
+        self.entries = if self.filter.query.is_empty() {
+            self.all_entries.clone()
+        } else {
+            // `..` stays pinned at the top regardless of score; everything
+            // else is ranked by descending fuzzy-match quality.
+            let mut scored: Vec<(i32, &BrowserEntry)> = self
+                .all_entries
+                .iter()
+                .filter_map(|e| {
+                    if e.is_parent {
+                        return Some((i32::MAX, e));
+                    }
+                    filter_score(&self.filter.query, &e.name).map(|score| (score, e))
+                })
+                .collect();
+            scored.sort_by(|a, b| b.0.cmp(&a.0));
+            scored.into_iter().map(|(_, e)| e.clone()).collect()
+        };
+
         if self.entries.is_empty() {
             self.list_state.select(None);
         } else if let Some(prev_name) = &prev_selected_name {
@@ -223,18 +354,65 @@ impl DirBrowser {
             } else if let Some(idx) = self.list_state.selected() {
                 self.list_state
                     .select(Some(idx.min(self.entries.len() - 1)));
+            } else {
+                self.list_state.select(Some(0));
             }
         } else if self.list_state.selected().is_none() {
             self.list_state.select(Some(0));
         }
     }
 
+    /// Open the type-to-filter input.
+    pub fn start_filter(&mut self) {
+        self.filter.active = true;
+    }
+
+    /// Clear the filter query and restore the full listing, preserving
+    /// selection by name the same way a normal refresh does.
+    pub fn cancel_filter(&mut self) {
+        let prev_selected_name = self
+            .selected_entry()
+            .map(|e| e.name.nfc().collect::<String>());
+        self.filter = FilterState::default();
+        self.apply_filter(prev_selected_name);
+    }
+
+    /// Stop accepting input but keep the current query narrowing the view.
+    pub fn confirm_filter(&mut self) {
+        self.filter.active = false;
+    }
+
+    pub fn push_filter_char(&mut self, c: char) {
+        let prev_selected_name = self
+            .selected_entry()
+            .map(|e| e.name.nfc().collect::<String>());
+        self.filter.query.push(c);
+        self.apply_filter(prev_selected_name);
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        let prev_selected_name = self
+            .selected_entry()
+            .map(|e| e.name.nfc().collect::<String>());
+        self.filter.query.pop();
+        self.apply_filter(prev_selected_name);
+    }
+
     pub fn selected_entry(&self) -> Option<&BrowserEntry> {
         self.list_state
             .selected()
             .and_then(|idx| self.entries.get(idx))
     }
 
+    /// Alias for `selected_entry` kept at the `DirBrowser` level so callers
+    /// that reach it through `BrowserState::effective_selection_kind`'s
+    /// "effective" naming (which overrides only the *kind* classification
+    /// while a background task owns the directory, not which entry is
+    /// highlighted) read consistently at every call site.
+    pub fn effective_selected_entry(&self) -> Option<&BrowserEntry> {
+        self.selected_entry()
+    }
+
     pub fn selection_kind(&self) -> SelectionKind {
         match self.selected_entry() {
             None => SelectionKind::None,
@@ -263,6 +441,20 @@ impl DirBrowser {
         }
     }
 
+    /// Jumps to the first entry (the `gg` motion).
+    pub fn select_first(&mut self) {
+        if !self.entries.is_empty() {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    /// Jumps to the last entry (the `G` motion).
+    pub fn select_last(&mut self) {
+        if !self.entries.is_empty() {
+            self.list_state.select(Some(self.entries.len() - 1));
+        }
+    }
+
     pub fn dir_indices(&self) -> Vec<usize> {
         self.entries
             .iter()
@@ -294,6 +486,20 @@ impl DirBrowser {
         }
     }
 
+    /// Jumps to the first directory entry (the `gg` motion).
+    pub fn select_first_dir(&mut self) {
+        if let Some(&first) = self.dir_indices().first() {
+            self.list_state.select(Some(first));
+        }
+    }
+
+    /// Jumps to the last directory entry (the `G` motion).
+    pub fn select_last_dir(&mut self) {
+        if let Some(&last) = self.dir_indices().last() {
+            self.list_state.select(Some(last));
+        }
+    }
+
     pub fn try_enter_selected(&mut self) {
         let path = self
             .selected_entry()
@@ -304,12 +510,15 @@ impl DirBrowser {
         }
     }
 
-    pub fn enter_directory(&mut self, path: &std::path::Path) {
+    pub fn enter_directory(&mut self, path: &Path) {
         if path.is_dir() {
             self.current_dir = path.to_path_buf();
+            self.filter = FilterState::default();
+            self.rename = RenameState::default();
             self.list_state.select(Some(0));
             self.render_offset = 0;
             self.refresh();
+            self.rewatch();
         }
     }
 
@@ -317,8 +526,11 @@ impl DirBrowser {
         if let Some(parent) = self.current_dir.parent() {
             let old_dir = self.current_dir.clone();
             self.current_dir = parent.to_path_buf();
+            self.filter = FilterState::default();
+            self.rename = RenameState::default();
             self.render_offset = 0;
             self.refresh();
+            self.rewatch();
 
             // Try to select the directory we came from
             if let Some(idx) = self.entries.iter().position(|e| e.path == old_dir) {
@@ -330,6 +542,20 @@ impl DirBrowser {
     pub fn toggle_hidden(&mut self) {
         let selected_path = self.selected_entry().map(|e| e.path.clone());
         self.show_hidden = !self.show_hidden;
+        self.persist_browser_settings();
+        self.refresh();
+        if let Some(path) = selected_path {
+            if let Some(idx) = self.entries.iter().position(|e| e.path == path) {
+                self.list_state.select(Some(idx));
+            }
+        }
+    }
+
+    /// Advance to the next sort mode and persist it.
+    pub fn cycle_sort_mode(&mut self) {
+        let selected_path = self.selected_entry().map(|e| e.path.clone());
+        self.sort_mode = self.sort_mode.cycle();
+        self.persist_browser_settings();
         self.refresh();
         if let Some(path) = selected_path {
             if let Some(idx) = self.entries.iter().position(|e| e.path == path) {
@@ -337,6 +563,259 @@ impl DirBrowser {
             }
         }
     }
+
+    /// Flip the direction of the current sort mode and persist it.
+    pub fn toggle_sort_reverse(&mut self) {
+        let selected_path = self.selected_entry().map(|e| e.path.clone());
+        self.sort_reverse = !self.sort_reverse;
+        self.persist_browser_settings();
+        self.refresh();
+        if let Some(path) = selected_path {
+            if let Some(idx) = self.entries.iter().position(|e| e.path == path) {
+                self.list_state.select(Some(idx));
+            }
+        }
+    }
+
+    /// Open the inline rename editor on the selected entry, pre-filled with
+    /// its on-disk name. No-op on the parent entry.
+    pub fn start_rename(&mut self) {
+        if let Some(entry) = self.selected_entry() {
+            if !entry.is_parent {
+                self.rename.input = entry.name.clone();
+                self.rename.active = true;
+            }
+        }
+    }
+
+    pub fn cancel_rename(&mut self) {
+        self.rename = RenameState::default();
+    }
+
+    pub fn push_rename_char(&mut self, c: char) {
+        self.rename.input.push(c);
+    }
+
+    pub fn pop_rename_char(&mut self) {
+        self.rename.input.pop();
+    }
+
+    /// Run the editor's current input through `unicode_normalization`,
+    /// updating it in place so the live form badge reflects the result.
+    pub fn normalize_rename_input(&mut self, target: NormalizationTarget) {
+        self.rename.input = target.convert(&self.rename.input);
+    }
+
+    /// Commit the rename editor's input as the new on-disk name, rejecting
+    /// it if another entry in the same directory already normalizes to the
+    /// same NFC form (e.g. a pre-existing NFD-named sibling that looks
+    /// identical once composed).
+    pub fn confirm_rename(&mut self) -> Result<(), String> {
+        let Some(selected) = self.selected_entry().cloned() else {
+            self.rename = RenameState::default();
+            return Ok(());
+        };
+        let new_name = std::mem::take(&mut self.rename.input);
+        self.rename = RenameState::default();
+
+        if new_name.is_empty() || new_name == selected.name {
+            return Ok(());
+        }
+
+        let new_name_nfc: String = new_name.nfc().collect();
+        let collides = self.all_entries.iter().any(|e| {
+            e.path != selected.path && e.name.nfc().collect::<String>() == new_name_nfc
+        });
+        if collides {
+            return Err(format!("'{new_name}' already exists in another form"));
+        }
+
+        let new_path = selected.path.with_file_name(&new_name);
+        fs::rename(&selected.path, &new_path).map_err(|e| e.to_string())?;
+
+        self.refresh();
+        if let Some(idx) = self.entries.iter().position(|e| e.path == new_path) {
+            self.list_state.select(Some(idx));
+        }
+        Ok(())
+    }
+
+    /// Save `current_dir` under `key`, replacing whatever was previously
+    /// bookmarked under that label.
+    pub fn set_bookmark(&mut self, key: char) {
+        let path = self.current_dir.clone();
+        if let Some(existing) = self.bookmarks.iter_mut().find(|(k, _)| *k == key) {
+            existing.1 = path;
+        } else {
+            self.bookmarks.push((key, path));
+        }
+        self.persist_browser_settings();
+    }
+
+    /// Writes the current sort/hidden/bookmark settings back to the config
+    /// file, mirroring `daemon_controller`'s read-modify-write pattern.
+    fn persist_browser_settings(&self) {
+        let mut raw_config = match read_or_default_config(&CONFIG_PATH) {
+            Ok(raw_config) => raw_config,
+            Err(e) => {
+                warn!("Failed to read config before saving browser settings: {e}");
+                return;
+            }
+        };
+        raw_config.browser_sort_mode = self.sort_mode;
+        raw_config.browser_sort_reverse = self.sort_reverse;
+        raw_config.browser_show_hidden = self.show_hidden;
+        raw_config.browser_bookmarks = self
+            .bookmarks
+            .iter()
+            .map(|(key, path)| BookmarkEntry {
+                key: *key,
+                path: path.to_string_lossy().into_owned(),
+            })
+            .collect();
+        if let Err(e) = raw_config.save_to_file(CONFIG_PATH.as_path()) {
+            warn!("Failed to save browser settings: {e}");
+        }
+    }
+}
+
+/// Orders `entries` for display. Directories always sort before files,
+/// regardless of `mode`/`reverse` (broot/yazi convention); `reverse` only
+/// flips the within-group comparison so directories never get pushed below
+/// files by e.g. reversed size.
+fn sort_entries(entries: &mut [BrowserEntry], mode: BrowserSortMode, reverse: bool) {
+    entries.sort_by(|a, b| {
+        let dir_order = b.is_dir.cmp(&a.is_dir);
+        if dir_order != Ordering::Equal {
+            return dir_order;
+        }
+
+        let ordering = match mode {
+            BrowserSortMode::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            BrowserSortMode::ModifiedTime => b.modified.cmp(&a.modified),
+            BrowserSortMode::Size => b.size.cmp(&a.size),
+            BrowserSortMode::UnicodeForm => unicode_form_rank(a.form)
+                .cmp(&unicode_form_rank(b.form))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+        };
+
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+/// Clusters NFD/Mixed names first (they need conversion), then NFC, then
+/// ASCII, so everything needing attention is visible at a glance.
+fn unicode_form_rank(form: UnicodeForm) -> u8 {
+    match form {
+        UnicodeForm::NFD => 0,
+        UnicodeForm::Mixed => 0,
+        UnicodeForm::NFC => 1,
+        UnicodeForm::ASCII => 2,
+    }
+}
+
+/// Best fuzzy-match score of `query` against `name`, checked against both
+/// the NFC and NFD normalizations of `name` so a composed query still
+/// matches an NFD-stored filename (and a decomposed query still matches an
+/// NFC one) — otherwise filtering is unreliable on exactly the mixed-form
+/// directories this tool targets. `None` if neither form matches.
+fn filter_score(query: &str, name: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let nfc_name: String = name.nfc().collect();
+    let nfd_name: String = name.nfd().collect();
+    fuzzy_score(query, &nfc_name).into_iter().chain(fuzzy_score(query, &nfd_name)).max()
+}
+
+/// Subsequence match score of `query` against `name`: walks `name`
+/// left-to-right greedily matching each char of `query` in order, then
+/// scores the alignment found — rewarding consecutive matches (+8) and
+/// matches starting right after a path separator, `_`, `-`, space, or a
+/// case boundary (+10), and penalizing skipped characters (-1 per char,
+/// -3 extra for a leading gap before the first match). `None` if `query`'s
+/// characters don't all appear, in order, in `name` (xplr-style filtering).
+fn fuzzy_score(query: &str, name: &str) -> Option<i32> {
+    let chars: Vec<char> = name.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let q: Vec<char> = query.chars().map(|c| c.to_ascii_lowercase()).collect();
+    if q.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0i32;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (i, c) in lower.iter().enumerate() {
+        if qi >= q.len() {
+            break;
+        }
+        if *c != q[qi] {
+            continue;
+        }
+
+        let gap = match last_match {
+            Some(last) => i - last - 1,
+            None => i,
+        };
+        if gap > 0 {
+            score -= gap as i32;
+            if last_match.is_none() {
+                score -= 3;
+            }
+        } else if last_match.is_some() {
+            score += 8;
+        }
+
+        let at_boundary =
+            i == 0 || matches!(chars[i - 1], '/' | '_' | '-' | ' ') || (chars[i].is_uppercase() && !chars[i - 1].is_uppercase());
+        if at_boundary {
+            score += 10;
+        }
+
+        last_match = Some(i);
+        qi += 1;
+    }
+
+    (qi == q.len()).then_some(score)
+}
+
+/// Matched character positions of `query` within `name`, for highlighting.
+/// Tries a contiguous case-insensitive substring match first, falling back
+/// to a fuzzy (non-contiguous) subsequence match.
+pub fn match_positions(query: &str, name: &str) -> Option<Vec<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let q: Vec<char> = query.chars().map(|c| c.to_ascii_lowercase()).collect();
+
+    if q.len() <= lower.len() {
+        if let Some(start) = lower.windows(q.len()).position(|w| w == q.as_slice()) {
+            return Some((start..start + q.len()).collect());
+        }
+    }
+
+    let mut positions = Vec::with_capacity(q.len());
+    let mut qi = 0;
+    for (i, c) in lower.iter().enumerate() {
+        if qi < q.len() && *c == q[qi] {
+            positions.push(i);
+            qi += 1;
+        }
+    }
+    if qi == q.len() {
+        Some(positions)
+    } else {
+        None
+    }
 }
 
 pub fn detect_unicode_form(name: &str) -> UnicodeForm {
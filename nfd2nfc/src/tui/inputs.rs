@@ -0,0 +1,154 @@
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use nfd2nfc_core::constants::{CONFIG_PATH, NFD2NFC_SERVICE_LABEL};
+use notify::{Event as NotifyEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::watch;
+
+use crate::log_service::{LevelFilter, LogEvent, LogQuery};
+
+/// Cadence of the background clock tick feeding `AppEvent::Tick`.
+const TICK_INTERVAL: Duration = Duration::from_millis(33);
+
+/// How long to wait before re-spawning `log stream` after it exits without
+/// ever delivering an entry (e.g. the `log` binary itself failed to start),
+/// so a persistent failure doesn't spin the respawn loop.
+const STREAM_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Events fed into the main loop by the background input tasks below.
+pub enum AppEvent {
+    Log(LogEvent),
+    Tick,
+    ConfigChanged,
+    /// A create/remove/rename landed in a directory the browser tab is
+    /// watching via FSEvents.
+    DirChanged(PathBuf),
+}
+
+pub type Writer = UnboundedSender<AppEvent>;
+pub type Reader = UnboundedReceiver<AppEvent>;
+
+/// Spawns every background input task (log stream, clock tick, config
+/// watcher) and returns both ends of the unified event channel: the
+/// writer, so callers can register further ad hoc producers (e.g. the
+/// browser tab's per-directory watcher), and the reader, drained by the
+/// main loop. Replaces the scattered per-tab timers and threads those
+/// tasks used to own individually. Also returns a sender the Logs tab uses
+/// to push its level filter down to the log query threads, so a level
+/// hidden in the UI stops being fetched instead of only being hidden
+/// client-side.
+pub fn spawn() -> (Writer, Reader, watch::Sender<LevelFilter>) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (level_tx, level_rx) = watch::channel(LevelFilter::all());
+
+    spawn_log_stream(tx.clone(), level_rx);
+    spawn_tick(tx.clone());
+    spawn_config_watcher(tx.clone());
+
+    (tx, rx, level_tx)
+}
+
+fn spawn_log_stream(tx: Writer, level_rx: watch::Receiver<LevelFilter>) {
+    // Initial load: relayed chunk by chunk as `LogQuery::stream_history`
+    // parses them, rather than blocking until the whole range is read, so
+    // a large `--last` range doesn't leave the tab on "Loading logs..."
+    // any longer than its first batch takes.
+    let load_tx = tx.clone();
+    let mut history_level_rx = level_rx.clone();
+    thread::spawn(move || {
+        let (hist_tx, hist_rx) = std::sync::mpsc::channel();
+        let levels = *history_level_rx.borrow_and_update();
+        thread::spawn(move || {
+            LogQuery::new()
+                .subsystem(NFD2NFC_SERVICE_LABEL)
+                .duration("365d")
+                .levels(levels)
+                .stream_history(hist_tx);
+        });
+        while let Ok(event) = hist_rx.recv() {
+            if load_tx.send(AppEvent::Log(event)).is_err() {
+                break;
+            }
+        }
+    });
+
+    // Streaming: `LogQuery::stream` speaks std mpsc, so relay onto the
+    // unified channel from a forwarding thread. If the underlying `log
+    // stream` process dies (killed, `log` binary missing after an update,
+    // or the Logs tab's level filter changed), re-spawn it resuming from
+    // the last entry's timestamp instead of leaving the UI silently stuck
+    // on stale logs.
+    thread::spawn(move || {
+        let mut since_timestamp: Option<String> = None;
+        let mut level_rx = level_rx;
+        loop {
+            let (stream_tx, stream_rx) = std::sync::mpsc::channel();
+            let levels = *level_rx.borrow_and_update();
+            let mut query = LogQuery::new().subsystem(NFD2NFC_SERVICE_LABEL).levels(levels);
+            if let Some(since) = &since_timestamp {
+                query = query.since_timestamp(since);
+            }
+            let stream_level_rx = level_rx.clone();
+            let stream_thread = thread::spawn(move || query.stream(stream_tx, stream_level_rx));
+
+            let mut saw_entry = false;
+            while let Ok(event) = stream_rx.recv() {
+                if let LogEvent::Live(entry) = &event {
+                    since_timestamp = Some(entry.full_timestamp.clone());
+                    saw_entry = true;
+                }
+                if tx.send(AppEvent::Log(event)).is_err() {
+                    return;
+                }
+            }
+            let _ = stream_thread.join();
+
+            if !saw_entry {
+                thread::sleep(STREAM_RETRY_DELAY);
+            }
+        }
+    });
+}
+
+fn spawn_tick(tx: Writer) {
+    thread::spawn(move || loop {
+        thread::sleep(TICK_INTERVAL);
+        if tx.send(AppEvent::Tick).is_err() {
+            break;
+        }
+    });
+}
+
+/// Watches `CONFIG_PATH` and emits `ConfigChanged` only when the file
+/// actually changes on disk, instead of polling it on a timer.
+fn spawn_config_watcher(tx: Writer) {
+    thread::spawn(move || {
+        let mut watcher = match RecommendedWatcher::new(
+            move |res: Result<NotifyEvent, notify::Error>| {
+                if let Ok(event) = res {
+                    if event.kind.is_modify() || event.kind.is_create() {
+                        let _ = tx.send(AppEvent::ConfigChanged);
+                    }
+                }
+            },
+            notify::Config::default(),
+        ) {
+            Ok(watcher) => watcher,
+            Err(_) => return,
+        };
+
+        if watcher
+            .watch(CONFIG_PATH.as_path(), RecursiveMode::NonRecursive)
+            .is_err()
+        {
+            return;
+        }
+
+        // Keep the watcher (and this thread) alive for the life of the program.
+        loop {
+            thread::park();
+        }
+    });
+}
@@ -0,0 +1,583 @@
+//! Interactive dry-run review for a rename plan.
+//!
+//! Builds a collapsible tree of every entry a normalization pass would
+//! touch, showing the current name and the proposed NFC/NFD result side by
+//! side, and lets the user flag which of them to actually apply before
+//! anything is renamed. Launched by `nfd2nfc -I`, as an alternative to the
+//! blind recursive `-r` pass and the non-interactive `-n` dry-run printout.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyCode};
+use crossterm::execute;
+use futures::StreamExt;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use nfd2nfc_core::utils::abbreviate_home_path;
+
+use crate::normalizer::RenamePlan;
+use crate::scheduler::ConvertScheduler;
+use crate::tui::app::events::MouseState;
+use crate::tui::shortcuts::{gap, nav_arrows, shortcut, shortcut_bracketed, ShortcutBlock};
+use crate::tui::tick_timer::TickTimer;
+use crate::tui::toast::{ToastLevel, ToastState};
+
+/// How often a running commit's progress is drained from its
+/// `ConvertScheduler` and reflected in the progress line.
+const COMMIT_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A node in the rename-review tree. `rename` is `Some` for an entry the
+/// plan actually wants to touch; `None` for a scaffold directory kept around
+/// only to nest renamed descendants under their real parent, which isn't
+/// itself flaggable.
+struct PlanNode {
+    name: String,
+    rename: Option<(PathBuf, PathBuf)>,
+    apply: bool,
+    expanded: bool,
+    children: Vec<PlanNode>,
+}
+
+/// One row of the flattened, fold-aware view of the tree: a path of child
+/// indices from the roots down to the node this row displays.
+struct VisibleRow {
+    path: Vec<usize>,
+    depth: usize,
+}
+
+#[derive(Default)]
+struct TrieNode {
+    rename: Option<(PathBuf, PathBuf)>,
+    children: BTreeMap<String, TrieNode>,
+}
+
+impl TrieNode {
+    fn insert(&mut self, components: &[String], from: &Path, to: &Path) {
+        match components.split_first() {
+            None => self.rename = Some((from.to_path_buf(), to.to_path_buf())),
+            Some((head, rest)) => {
+                self.children
+                    .entry(head.clone())
+                    .or_default()
+                    .insert(rest, from, to);
+            }
+        }
+    }
+
+    fn into_node(self, name: String) -> PlanNode {
+        let children: Vec<PlanNode> = self
+            .children
+            .into_iter()
+            .map(|(child_name, child)| child.into_node(child_name))
+            .collect();
+        let expanded = !children.is_empty();
+        PlanNode {
+            name,
+            rename: self.rename,
+            apply: true,
+            expanded,
+            children,
+        }
+    }
+}
+
+fn relative_components(root: &Path, path: &Path) -> Vec<String> {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Builds the review tree from a flat rename plan, nesting each entry under
+/// its real parent directory (whether or not that parent is itself being
+/// renamed).
+fn build_tree(root: &Path, plan: &[RenamePlan]) -> Vec<PlanNode> {
+    let mut trie = TrieNode::default();
+    for entry in plan {
+        let components = relative_components(root, &entry.from);
+        trie.insert(&components, &entry.from, &entry.to);
+    }
+    trie.children
+        .into_iter()
+        .map(|(name, node)| node.into_node(name))
+        .collect()
+}
+
+fn node_at<'a>(nodes: &'a [PlanNode], path: &[usize]) -> &'a PlanNode {
+    let mut cur = &nodes[path[0]];
+    for &i in &path[1..] {
+        cur = &cur.children[i];
+    }
+    cur
+}
+
+fn node_at_mut<'a>(nodes: &'a mut [PlanNode], path: &[usize]) -> &'a mut PlanNode {
+    let mut cur = &mut nodes[path[0]];
+    for &i in &path[1..] {
+        cur = &mut cur.children[i];
+    }
+    cur
+}
+
+fn flatten(nodes: &[PlanNode], prefix: &mut Vec<usize>, depth: usize, out: &mut Vec<VisibleRow>) {
+    for (i, node) in nodes.iter().enumerate() {
+        prefix.push(i);
+        out.push(VisibleRow {
+            path: prefix.clone(),
+            depth,
+        });
+        if node.expanded {
+            flatten(&node.children, prefix, depth + 1, out);
+        }
+        prefix.pop();
+    }
+}
+
+/// Walks the whole tree (regardless of fold state) in the same top-down,
+/// parent-before-child order the plan was built in, collecting every
+/// flagged rename so applying them can't strand a child under a `from`
+/// path its own parent has already vacated.
+fn collect_flagged<'a>(nodes: &'a [PlanNode], out: &mut Vec<(&'a Path, &'a Path)>) {
+    for node in nodes {
+        if let Some((from, to)) = &node.rename {
+            if node.apply {
+                out.push((from, to));
+            }
+        }
+        collect_flagged(&node.children, out);
+    }
+}
+
+fn set_all(nodes: &mut [PlanNode], apply: bool) {
+    for node in nodes {
+        if node.rename.is_some() {
+            node.apply = apply;
+        }
+        set_all(&mut node.children, apply);
+    }
+}
+
+struct InteractivePlanState {
+    root: PathBuf,
+    to_nfc: bool,
+    jobs: usize,
+    nodes: Vec<PlanNode>,
+    visible: Vec<VisibleRow>,
+    cursor: usize,
+    toasts: ToastState,
+    quit: bool,
+    /// The scheduler for a commit in progress, and whether it's already
+    /// been asked to cancel (so repeated Esc presses don't spam the toast).
+    committing: Option<(ConvertScheduler, bool)>,
+    progress_timer: TickTimer,
+    /// The run id of the most recently finished commit from this screen, so
+    /// `u` can undo it without the user having to copy it from the CLI.
+    last_run_id: Option<String>,
+}
+
+impl InteractivePlanState {
+    fn new(root: PathBuf, plan: Vec<RenamePlan>, to_nfc: bool, jobs: usize) -> Self {
+        let nodes = build_tree(&root, &plan);
+        let mut state = Self {
+            root,
+            to_nfc,
+            jobs,
+            nodes,
+            visible: Vec::new(),
+            cursor: 0,
+            toasts: ToastState::new(),
+            quit: false,
+            committing: None,
+            progress_timer: TickTimer::new(COMMIT_PROGRESS_INTERVAL),
+            last_run_id: None,
+        };
+        state.refresh_visible();
+        state
+    }
+
+    fn refresh_visible(&mut self) {
+        self.visible.clear();
+        flatten(&self.nodes, &mut Vec::new(), 0, &mut self.visible);
+        if self.cursor >= self.visible.len() {
+            self.cursor = self.visible.len().saturating_sub(1);
+        }
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let next = self.cursor as isize + delta;
+        self.cursor = next.clamp(0, self.visible.len() as isize - 1) as usize;
+    }
+
+    fn toggle_apply_at_cursor(&mut self) {
+        let Some(row) = self.visible.get(self.cursor) else {
+            return;
+        };
+        let path = row.path.clone();
+        let node = node_at_mut(&mut self.nodes, &path);
+        if node.rename.is_some() {
+            node.apply = !node.apply;
+        }
+    }
+
+    /// Collapses the node under the cursor if it has children to fold;
+    /// otherwise jumps the cursor up to its parent, mirroring how folding
+    /// editors handle collapse-at-a-leaf.
+    fn collapse_or_to_parent(&mut self) {
+        let Some(row) = self.visible.get(self.cursor) else {
+            return;
+        };
+        let path = row.path.clone();
+        let depth = row.depth;
+        let has_expanded_children = {
+            let node = node_at(&self.nodes, &path);
+            !node.children.is_empty() && node.expanded
+        };
+        if has_expanded_children {
+            node_at_mut(&mut self.nodes, &path).expanded = false;
+            self.refresh_visible();
+            return;
+        }
+        if depth == 0 {
+            return;
+        }
+        if let Some(parent_row) = self
+            .visible
+            .iter()
+            .take(self.cursor)
+            .rposition(|r| r.depth == depth - 1 && path.starts_with(&r.path))
+        {
+            self.cursor = parent_row;
+        }
+    }
+
+    fn expand_at_cursor(&mut self) {
+        let Some(row) = self.visible.get(self.cursor) else {
+            return;
+        };
+        let path = row.path.clone();
+        let node = node_at_mut(&mut self.nodes, &path);
+        if !node.children.is_empty() {
+            node.expanded = true;
+            self.refresh_visible();
+        }
+    }
+
+    fn set_all(&mut self, apply: bool) {
+        set_all(&mut self.nodes, apply);
+    }
+
+    /// Kicks off a background commit of every flagged rename, if one isn't
+    /// already running. Progress is drained by `poll_commit` as the render
+    /// loop ticks, rather than blocking the UI here.
+    fn commit(&mut self) {
+        if self.committing.is_some() {
+            return;
+        }
+
+        let mut flagged = Vec::new();
+        collect_flagged(&self.nodes, &mut flagged);
+
+        if flagged.is_empty() {
+            self.toasts
+                .push("Nothing flagged to apply.".to_string(), ToastLevel::Error);
+            return;
+        }
+
+        let plan: Vec<RenamePlan> = flagged
+            .into_iter()
+            .map(|(from, to)| RenamePlan {
+                from: from.to_path_buf(),
+                to: to.to_path_buf(),
+            })
+            .collect();
+
+        let scheduler = ConvertScheduler::spawn(self.root.clone(), plan, self.jobs, self.to_nfc, false);
+        self.last_run_id = None;
+        self.committing = Some((scheduler, false));
+    }
+
+    /// Asks a running commit to stop enqueuing further renames. In-flight
+    /// ones are left to finish rather than killed mid-rename.
+    fn cancel_commit(&mut self) {
+        let Some((scheduler, cancel_requested)) = &mut self.committing else {
+            return;
+        };
+        if *cancel_requested {
+            return;
+        }
+        scheduler.cancel();
+        *cancel_requested = true;
+        self.toasts.push(
+            "Cancelling: letting in-flight renames finish...".to_string(),
+            ToastLevel::Error,
+        );
+    }
+
+    /// Drains progress from a running commit, pushing a summary toast once
+    /// it finishes.
+    fn poll_commit(&mut self) {
+        let Some((scheduler, _)) = &mut self.committing else {
+            return;
+        };
+        let progress = scheduler.poll();
+        if !progress.done {
+            return;
+        }
+
+        if progress.renamed > 0 {
+            self.last_run_id = Some(scheduler.run_id().to_string());
+        }
+
+        let message = if progress.failed == 0 {
+            format!(
+                "Applied {} of {} flagged renames.{}",
+                progress.renamed,
+                progress.total,
+                if progress.renamed > 0 { " Press u to undo." } else { "" }
+            )
+        } else {
+            format!(
+                "Applied {} of {} flagged renames ({} failed; see logs).{}",
+                progress.renamed,
+                progress.total,
+                progress.failed,
+                if progress.renamed > 0 { " Press u to undo." } else { "" }
+            )
+        };
+        let level = if progress.failed == 0 {
+            ToastLevel::Success
+        } else {
+            ToastLevel::Error
+        };
+        self.toasts.push(message, level);
+        self.committing = None;
+    }
+
+    /// Reverts the most recent commit made from this screen, if any, via the
+    /// same [`nfd2nfc_core::journal::revert_run`] the `nfd2nfc undo` CLI
+    /// subcommand uses.
+    fn undo_last(&mut self) {
+        let Some(run_id) = self.last_run_id.take() else {
+            self.toasts
+                .push("Nothing to undo.".to_string(), ToastLevel::Error);
+            return;
+        };
+        match nfd2nfc_core::journal::revert_run(&run_id) {
+            Ok(()) => self
+                .toasts
+                .push("Reverted the last applied batch.".to_string(), ToastLevel::Success),
+            Err(e) => self.toasts.push(
+                format!("Failed to revert the last applied batch: {}", e),
+                ToastLevel::Error,
+            ),
+        }
+    }
+}
+
+fn handle_key(state: &mut InteractivePlanState, key: KeyCode) {
+    if state.committing.is_some() {
+        // A commit is running in the background; only cancellation and the
+        // tree navigation that doesn't mutate flags make sense mid-run.
+        match key {
+            KeyCode::Up => state.move_cursor(-1),
+            KeyCode::Down => state.move_cursor(1),
+            KeyCode::Esc | KeyCode::Char('q') => state.cancel_commit(),
+            _ => {}
+        }
+        return;
+    }
+
+    match key {
+        KeyCode::Up => state.move_cursor(-1),
+        KeyCode::Down => state.move_cursor(1),
+        KeyCode::Left => state.collapse_or_to_parent(),
+        KeyCode::Right => state.expand_at_cursor(),
+        KeyCode::Char(' ') => state.toggle_apply_at_cursor(),
+        KeyCode::Char('A') => state.set_all(true),
+        KeyCode::Char('C') => state.set_all(false),
+        KeyCode::Enter => state.commit(),
+        KeyCode::Char('u') => state.undo_last(),
+        KeyCode::Esc | KeyCode::Char('q') => state.quit = true,
+        _ => {}
+    }
+}
+
+fn render(state: &mut InteractivePlanState, f: &mut Frame) {
+    let area = f.area();
+    let mode = if state.to_nfc { "NFD -> NFC" } else { "NFC -> NFD" };
+    let title = Line::from(format!(
+        " {} review: {} ",
+        mode,
+        abbreviate_home_path(&state.root)
+    ));
+
+    let mut items = nav_arrows();
+    if state.committing.is_some() {
+        items.push(gap());
+        items.push(shortcut_bracketed("Esc", "Cancel", KeyCode::Esc));
+    } else {
+        items.push(gap());
+        items.push(shortcut("Space", "Toggle", KeyCode::Char(' ')));
+        items.push(gap());
+        items.push(shortcut_bracketed("A", "Flag all", KeyCode::Char('A')));
+        items.push(gap());
+        items.push(shortcut_bracketed("C", "Clear all", KeyCode::Char('C')));
+        items.push(gap());
+        items.push(shortcut_bracketed("Enter", "Apply", KeyCode::Enter));
+        if state.last_run_id.is_some() {
+            items.push(gap());
+            items.push(shortcut_bracketed("u", "Undo", KeyCode::Char('u')));
+        }
+        items.push(gap());
+        items.push(shortcut_bracketed("q", "Quit", KeyCode::Char('q')));
+    }
+
+    let mut mouse = MouseState::default();
+    let content_area = ShortcutBlock::new(title).items(items).render(f, area, &mut mouse);
+
+    let (tree_area, progress_area) = match &state.committing {
+        Some(_) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(content_area);
+            (chunks[0], Some(chunks[1]))
+        }
+        None => (content_area, None),
+    };
+
+    render_tree(state, tree_area, f);
+    if let Some(progress_area) = progress_area {
+        render_commit_progress(state, progress_area, f);
+    }
+    state.toasts.render(f, content_area);
+}
+
+fn render_commit_progress(state: &InteractivePlanState, area: Rect, f: &mut Frame) {
+    let Some((scheduler, cancel_requested)) = &state.committing else {
+        return;
+    };
+    let progress = scheduler.progress();
+    let verb = if *cancel_requested { "Cancelling" } else { "Applying" };
+    let line = Line::from(format!(
+        " {}: {}/{} ({} failed) -- Esc to cancel",
+        verb,
+        progress.completed(),
+        progress.total,
+        progress.failed
+    ));
+    f.render_widget(Paragraph::new(line).style(Style::default().fg(Color::Cyan)), area);
+}
+
+fn render_tree(state: &InteractivePlanState, area: Rect, f: &mut Frame) {
+    let list_items: Vec<ListItem> = state
+        .visible
+        .iter()
+        .map(|row| {
+            let node = node_at(&state.nodes, &row.path);
+            let indent = "  ".repeat(row.depth);
+            let fold_marker = if node.children.is_empty() {
+                "  "
+            } else if node.expanded {
+                "v "
+            } else {
+                "> "
+            };
+
+            let line = if let Some((from, to)) = &node.rename {
+                let checkbox = if node.apply { "[x] " } else { "[ ] " };
+                let from_name = from
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let to_name = to
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::raw(format!("{}{}{}", indent, fold_marker, checkbox)),
+                    Span::styled(from_name, Style::default().fg(Color::Yellow)),
+                    Span::raw("  ->  "),
+                    Span::styled(to_name, Style::default().fg(Color::Green)),
+                ])
+            } else {
+                Line::from(Span::styled(
+                    format!("{}{}\u{1f4c2} {}", indent, fold_marker, node.name),
+                    Style::default().fg(Color::DarkGray),
+                ))
+            };
+            ListItem::new(line)
+        })
+        .collect();
+
+    let list = List::new(list_items).highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.cursor));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// Runs the interactive review screen for `plan`, returning once the user
+/// quits. Does nothing but print a summary if the plan is already empty --
+/// there's nothing to review. Confirmed renames are applied across `jobs`
+/// worker threads via a [`ConvertScheduler`].
+pub fn run(root: &Path, plan: Vec<RenamePlan>, to_nfc: bool, jobs: usize) -> io::Result<()> {
+    if plan.is_empty() {
+        println!(
+            "Nothing to convert under {}; every name is already {}.",
+            root.display(),
+            if to_nfc { "NFC" } else { "NFD" }
+        );
+        return Ok(());
+    }
+
+    let mut terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut state = InteractivePlanState::new(root.to_path_buf(), plan, to_nfc, jobs);
+    let result = tokio::runtime::Runtime::new()
+        .expect("failed to start the async runtime backing the interactive review screen")
+        .block_on(run_loop(&mut terminal, &mut state));
+
+    execute!(io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+async fn run_loop(terminal: &mut DefaultTerminal, state: &mut InteractivePlanState) -> io::Result<()> {
+    let mut event_stream = EventStream::new();
+    let mut ticker = tokio::time::interval(Duration::from_millis(100));
+
+    loop {
+        terminal.draw(|f| render(state, f))?;
+
+        tokio::select! {
+            maybe_event = event_stream.next() => {
+                if let Some(Ok(Event::Key(key))) = maybe_event {
+                    handle_key(state, key.code);
+                }
+            }
+            _ = ticker.tick() => {}
+        }
+        state.toasts.tick();
+        if state.progress_timer.ready() {
+            state.poll_commit();
+        }
+
+        if state.quit {
+            break;
+        }
+    }
+
+    Ok(())
+}
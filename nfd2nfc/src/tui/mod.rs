@@ -1,11 +1,17 @@
 pub mod app;
+pub mod bookmarks_picker;
+pub mod command_palette;
 pub mod component;
 pub mod dir_browser;
+pub mod inputs;
+pub mod interactive_plan;
 pub mod shortcuts;
 pub mod styles;
 pub mod tabs;
 pub mod tick_timer;
 pub mod toast;
+pub mod volumes_picker;
+pub mod watch_picker;
 
 use std::io;
 use std::time::Duration;
@@ -45,7 +51,6 @@ async fn run_app(
     app: &mut App,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut event_stream = EventStream::new();
-    let mut tick_interval = tokio::time::interval(Duration::from_millis(33));
 
     loop {
         // Force full redraw when tab switched (clears rendering artifacts)
@@ -64,9 +69,11 @@ async fn run_app(
             maybe_event = event_stream.next() => {
                 app::events::handle_event(app, maybe_event)?;
             }
-            // Periodic tick for updates
-            _ = tick_interval.tick() => {
-                app.tick();
+            // Background input tasks: log stream, clock tick, config watcher
+            maybe_event = app.events.recv() => {
+                if let Some(event) = maybe_event {
+                    app.handle_app_event(event);
+                }
             }
         }
 
@@ -76,6 +83,11 @@ async fn run_app(
             app::events::handle_event(app, Some(Ok(event)))?;
         }
 
+        // Drain any further input-task events already queued (e.g. a burst of log lines)
+        while let Ok(event) = app.events.try_recv() {
+            app.handle_app_event(event);
+        }
+
         if !app.running {
             break;
         }
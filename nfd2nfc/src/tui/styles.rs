@@ -1,3 +1,4 @@
+use nfd2nfc_core::heartbeat::WatcherHealth;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Span;
 
@@ -11,6 +12,10 @@ pub fn label_style() -> Style {
     Style::default().fg(Color::Cyan)
 }
 
+pub fn bold_fg(color: Color) -> Style {
+    Style::default().fg(color).add_modifier(Modifier::BOLD)
+}
+
 pub fn dimmed_style() -> Style {
     Style::default().fg(Color::DarkGray)
 }
@@ -57,18 +62,30 @@ pub fn border_style() -> Style {
     Style::default().fg(Color::Gray)
 }
 
+/// Underline applied to the row or tab header currently under the mouse,
+/// distinct from the background fill used for the selected row and the bold
+/// color used for the active tab.
+pub fn hover_style() -> Style {
+    Style::default().add_modifier(Modifier::UNDERLINED)
+}
+
 /// Labels for watcher status display.
 pub struct StatusLabels {
     pub pending_prefix: &'static str,
     pub pending_suffix: &'static str,
     pub running: &'static str,
+    /// Shown for [`WatcherHealth::Idle`]: the watcher is up but hasn't
+    /// converted anything recently.
+    pub idle: &'static str,
+    pub paused: &'static str,
     pub stopped: &'static str,
 }
 
 /// Build a styled Span for the current watcher status.
 pub fn watcher_status_span(
     pending_op: Option<PendingWatcherOperation>,
-    watcher_running: bool,
+    health: WatcherHealth,
+    paused: bool,
     labels: &StatusLabels,
 ) -> Span<'static> {
     if let Some(op) = pending_op {
@@ -91,10 +108,26 @@ pub fn watcher_status_span(
                     labels.pending_prefix, labels.pending_suffix
                 )
             }
+            PendingWatcherOperation::Pausing => {
+                format!(
+                    "{}Pausing...{}",
+                    labels.pending_prefix, labels.pending_suffix
+                )
+            }
+            PendingWatcherOperation::Resuming => {
+                format!(
+                    "{}Resuming...{}",
+                    labels.pending_prefix, labels.pending_suffix
+                )
+            }
         };
         Span::styled(label, status_pending_style())
-    } else if watcher_running {
+    } else if health.is_up() && paused {
+        Span::styled(labels.paused, status_pending_style())
+    } else if health == WatcherHealth::Active {
         Span::styled(labels.running, status_running_style())
+    } else if health == WatcherHealth::Idle {
+        Span::styled(labels.idle, status_pending_style())
     } else {
         Span::styled(labels.stopped, status_stopped_style())
     }
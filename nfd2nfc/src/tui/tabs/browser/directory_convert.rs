@@ -0,0 +1,111 @@
+//! Background, cancellable conversion of a directory via
+//! `nfd2nfc_core::normalizer::normalize_directory`.
+//!
+//! Unlike `recursive_convert`'s always-NFC subtree scan, this task runs the
+//! exact mode/action/exclusions the user configured in the browser
+//! (`Children` or `Recursive`, NFC or NFD), so `convert_selected` can hand a
+//! large directory off to a worker thread instead of blocking the render
+//! loop until the whole BFS finishes.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use log::{error, info};
+
+use nfd2nfc_core::exclude::ExcludeSet;
+use nfd2nfc_core::normalizer::{
+    normalize_directory, CollisionStrategy, DirectoryProgress, NormalizationTarget,
+};
+use nfd2nfc_core::utils::abbreviate_home_path;
+
+/// A directory conversion running on a background thread.
+pub struct DirectoryConvertTask {
+    pub root: PathBuf,
+    pub progress: DirectoryProgress,
+    progress_rx: Receiver<DirectoryProgress>,
+    cancel: Arc<AtomicBool>,
+    finished: Arc<AtomicBool>,
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl DirectoryConvertTask {
+    /// Spawn a worker that converts `root`'s entries (and, if `recursive`,
+    /// everything beneath it) to `target`, skipping anything `exclude`
+    /// matches. `collision` decides what happens when a converted name
+    /// already exists as a different file.
+    pub fn spawn(
+        root: PathBuf,
+        recursive: bool,
+        target: NormalizationTarget,
+        exclude: ExcludeSet,
+        collision: CollisionStrategy,
+    ) -> Self {
+        let (tx, progress_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let finished = Arc::new(AtomicBool::new(false));
+        let error = Arc::new(Mutex::new(None));
+
+        let worker_root = root.clone();
+        let worker_cancel = cancel.clone();
+        let worker_finished = finished.clone();
+        let worker_error = error.clone();
+        thread::spawn(move || {
+            info!(
+                "Starting directory conversion to {} below: {}",
+                target.as_str(),
+                abbreviate_home_path(&worker_root)
+            );
+
+            if let Err(e) = normalize_directory(
+                &worker_root,
+                recursive,
+                target,
+                &exclude,
+                Some(&tx),
+                Some(&worker_cancel),
+                None,
+                collision,
+            ) {
+                error!(
+                    "Directory conversion failed for {}: {}",
+                    abbreviate_home_path(&worker_root),
+                    e
+                );
+                *worker_error.lock().unwrap_or_else(|p| p.into_inner()) = Some(e.to_string());
+            }
+            worker_finished.store(true, Ordering::Relaxed);
+        });
+
+        Self {
+            root,
+            progress: DirectoryProgress::default(),
+            progress_rx,
+            cancel,
+            finished,
+            error,
+        }
+    }
+
+    /// Drain all progress updates sent so far. Returns `true` once the task
+    /// has finished (completed or been cancelled).
+    pub fn poll(&mut self) -> bool {
+        while let Ok(progress) = self.progress_rx.try_recv() {
+            self.progress = progress;
+        }
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Takes the combined error summary left by the worker, if the walk
+    /// stopped early on a failure (e.g. a `CollisionStrategy::Fail` hit).
+    /// Returns `None` on a clean finish.
+    pub fn take_error(&self) -> Option<String> {
+        self.error.lock().unwrap_or_else(|p| p.into_inner()).take()
+    }
+}
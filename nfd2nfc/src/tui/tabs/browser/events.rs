@@ -1,18 +1,67 @@
 use crossterm::event::KeyCode;
 
-use crate::tui::component::{Action, ScrollDirection, SharedState};
+use crate::tui::component::{push_count_digit, take_count, Action, ScrollDirection, SharedState};
 use crate::tui::dir_browser::{SelectionKind, UnicodeForm};
 use crate::tui::tabs::browser::render::browser_list_y_range;
-use crate::tui::tabs::browser::state::{BrowserAction, BrowserMode, BrowserState};
+use crate::tui::tabs::browser::state::{BrowserAction, BrowserMode, BrowserState, ConvertOutcome};
+use nfd2nfc_core::normalizer::NormalizationTarget;
 
 pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState) -> Option<Action> {
+    if state.volumes_picker.show {
+        return handle_volumes_picker_key(state, key);
+    }
+    if state.bookmarks_picker.show {
+        return handle_bookmarks_picker_key(state, key);
+    }
+    if state.marking {
+        return handle_mark_key(state, key);
+    }
+    if state.dir_browser.rename.active {
+        return handle_rename_key(state, key);
+    }
+    if state.dir_browser.filter.active {
+        return handle_filter_key(state, key);
+    }
+
+    if let KeyCode::Char(c) = key {
+        if c.is_ascii_digit() {
+            if push_count_digit(&mut state.pending_count, c.to_digit(10).unwrap()) {
+                state.pending_g = false;
+            }
+            return None;
+        }
+        if c == 'g' {
+            if state.pending_g {
+                state.pending_g = false;
+                state.pending_count = None;
+                state.dir_browser.select_first();
+            } else {
+                state.pending_g = true;
+            }
+            return None;
+        }
+    }
+
+    // Any other key cancels a pending count or `g`; `take_count` both
+    // resolves and clears it so it can't leak into a later keypress.
+    let count = take_count(&mut state.pending_count);
+    state.pending_g = false;
+
     match key {
         KeyCode::Up | KeyCode::Char('k') => {
-            state.dir_browser.select_previous();
+            for _ in 0..count {
+                state.dir_browser.select_previous();
+            }
             None
         }
         KeyCode::Down | KeyCode::Char('j') => {
-            state.dir_browser.select_next();
+            for _ in 0..count {
+                state.dir_browser.select_next();
+            }
+            None
+        }
+        KeyCode::Char('G') => {
+            state.dir_browser.select_last();
             None
         }
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Backspace => {
@@ -27,12 +76,52 @@ pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState)
             }
             None
         }
+        KeyCode::Char(' ') => {
+            state.toggle_mark_selected();
+            state.dir_browser.select_next();
+            None
+        }
+        KeyCode::Char('A') => {
+            state.select_all_visible();
+            None
+        }
+        KeyCode::Char('C') => {
+            state.clear_selection();
+            None
+        }
+        KeyCode::Char('i') => {
+            state.toggle_inspector();
+            None
+        }
+        KeyCode::Enter if !state.marked.is_empty() => {
+            let (converted, errors) = state.convert_marked();
+            Some(match errors.first() {
+                Some(first_error) if converted == 0 => Action::ShowToast {
+                    message: format!("Conversion failed: {}", first_error),
+                    is_error: true,
+                },
+                Some(first_error) => Action::ShowToast {
+                    message: format!(
+                        "Converted {} item(s), {} failed ({})",
+                        converted,
+                        errors.len(),
+                        first_error
+                    ),
+                    is_error: true,
+                },
+                None => Action::ShowToast {
+                    message: format!("Converted {} item(s)", converted),
+                    is_error: false,
+                },
+            })
+        }
         KeyCode::Enter => {
             let kind = state.dir_browser.selection_kind();
             if matches!(
                 kind,
                 SelectionKind::Parent | SelectionKind::FileAscii | SelectionKind::None
-            ) {
+            ) || state.effective_selection_kind() == SelectionKind::DirRecursive
+            {
                 return None;
             }
 
@@ -55,10 +144,13 @@ pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState)
                 }
             }
             match state.convert_selected() {
-                Ok(_) => Some(Action::ShowToast {
+                Ok(ConvertOutcome::Completed) => Some(Action::ShowToast {
                     message: "Conversion completed".to_string(),
                     is_error: false,
                 }),
+                // Running in the background; progress is shown inline in
+                // the options bar instead of a toast.
+                Ok(ConvertOutcome::Started) => None,
                 Err(e) => Some(Action::ShowToast {
                     message: format!("Conversion failed: {}", e),
                     is_error: true,
@@ -67,7 +159,7 @@ pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState)
         }
         KeyCode::Char('t') => {
             let kind = state.dir_browser.selection_kind();
-            if kind.is_dir() {
+            if kind.is_dir() && state.effective_selection_kind() != SelectionKind::DirRecursive {
                 let is_name_only_with_unicode_name =
                     kind == SelectionKind::DirUnicode && state.mode == BrowserMode::NameOnly;
                 if !is_name_only_with_unicode_name {
@@ -78,7 +170,7 @@ pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState)
         }
         KeyCode::Char('m') => {
             let kind = state.dir_browser.selection_kind();
-            if kind.is_dir() {
+            if kind.is_dir() && state.effective_selection_kind() != SelectionKind::DirRecursive {
                 if kind == SelectionKind::DirAscii {
                     state.mode = state.mode.cycle_skip_name_only();
                 } else {
@@ -91,11 +183,167 @@ pub fn handle_key(state: &mut BrowserState, key: KeyCode, _shared: &SharedState)
             state.dir_browser.toggle_hidden();
             None
         }
+        KeyCode::Char('s') => {
+            state.dir_browser.cycle_sort_mode();
+            None
+        }
+        KeyCode::Char('S') => {
+            state.dir_browser.toggle_sort_reverse();
+            None
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            let kind = state.dir_browser.selection_kind();
+            if matches!(kind, SelectionKind::DirUnicode | SelectionKind::DirAscii)
+                && state.recursive_task.is_none()
+                && state.dir_convert_task.is_none()
+            {
+                if let Some(path) = state
+                    .dir_browser
+                    .effective_selected_entry()
+                    .map(|e| e.path.clone())
+                {
+                    state.start_recursive_convert(path);
+                }
+            }
+            None
+        }
+        KeyCode::Char('v') => {
+            state.volumes_picker.open();
+            None
+        }
+        KeyCode::Char('M') => {
+            state.marking = true;
+            None
+        }
+        KeyCode::Char('B') => {
+            state.bookmarks_picker.open(&state.dir_browser.bookmarks);
+            None
+        }
+        KeyCode::Char('/') => {
+            state.dir_browser.start_filter();
+            None
+        }
+        KeyCode::Char('n') => {
+            state.dir_browser.start_rename();
+            None
+        }
+        KeyCode::Esc => {
+            if state.recursive_task.is_some() {
+                state.cancel_recursive_task();
+            } else if state.dir_convert_task.is_some() {
+                state.cancel_dir_convert_task();
+            } else if !state.dir_browser.filter.query.is_empty() {
+                state.dir_browser.cancel_filter();
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Rename editor input: typing edits the name, Tab/BackTab apply an
+/// explicit NFC/NFD normalization pass to the current text, Enter commits.
+fn handle_rename_key(state: &mut BrowserState, key: KeyCode) -> Option<Action> {
+    match key {
+        KeyCode::Char(c) => {
+            state.dir_browser.push_rename_char(c);
+            None
+        }
+        KeyCode::Backspace => {
+            state.dir_browser.pop_rename_char();
+            None
+        }
+        KeyCode::Tab => {
+            state
+                .dir_browser
+                .normalize_rename_input(NormalizationTarget::NFC);
+            None
+        }
+        KeyCode::BackTab => {
+            state
+                .dir_browser
+                .normalize_rename_input(NormalizationTarget::NFD);
+            None
+        }
+        KeyCode::Enter => match state.dir_browser.confirm_rename() {
+            Ok(()) => None,
+            Err(e) => Some(Action::ShowToast {
+                message: format!("Rename failed: {e}"),
+                is_error: true,
+            }),
+        },
+        KeyCode::Esc => {
+            state.dir_browser.cancel_rename();
+            None
+        }
+        _ => None,
+    }
+}
+
+fn handle_filter_key(state: &mut BrowserState, key: KeyCode) -> Option<Action> {
+    match key {
+        KeyCode::Char(c) => state.dir_browser.push_filter_char(c),
+        KeyCode::Backspace => state.dir_browser.pop_filter_char(),
+        KeyCode::Enter => state.dir_browser.confirm_filter(),
+        KeyCode::Esc => state.dir_browser.cancel_filter(),
+        _ => {}
+    }
+    None
+}
+
+fn handle_volumes_picker_key(state: &mut BrowserState, key: KeyCode) -> Option<Action> {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => state.volumes_picker.select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => state.volumes_picker.select_next(),
+        KeyCode::Enter => state.jump_to_selected_volume(),
+        KeyCode::Esc | KeyCode::Char('v') | KeyCode::Char('q') => state.volumes_picker.close(),
+        _ => {}
+    }
+    None
+}
+
+fn handle_bookmarks_picker_key(state: &mut BrowserState, key: KeyCode) -> Option<Action> {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => state.bookmarks_picker.select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => state.bookmarks_picker.select_next(),
+        KeyCode::Enter => state.jump_to_selected_bookmark(),
+        KeyCode::Esc | KeyCode::Char('B') | KeyCode::Char('q') => state.bookmarks_picker.close(),
+        _ => {}
+    }
+    None
+}
+
+/// Awaiting the label keypress started by 'M': any letter or digit saves
+/// `current_dir` under that label, anything else cancels.
+fn handle_mark_key(state: &mut BrowserState, key: KeyCode) -> Option<Action> {
+    state.marking = false;
+    match key {
+        KeyCode::Char(c) if c.is_alphanumeric() => {
+            state.dir_browser.set_bookmark(c);
+            Some(Action::ShowToast {
+                message: format!("Bookmarked as '{c}'"),
+                is_error: false,
+            })
+        }
         _ => None,
     }
 }
 
 pub fn handle_scroll(state: &mut BrowserState, direction: ScrollDirection) -> Option<Action> {
+    if state.volumes_picker.show {
+        match direction {
+            ScrollDirection::Up => state.volumes_picker.select_previous(),
+            ScrollDirection::Down => state.volumes_picker.select_next(),
+        }
+        return None;
+    }
+    if state.bookmarks_picker.show {
+        match direction {
+            ScrollDirection::Up => state.bookmarks_picker.select_previous(),
+            ScrollDirection::Down => state.bookmarks_picker.select_next(),
+        }
+        return None;
+    }
     match direction {
         ScrollDirection::Up => state.dir_browser.select_previous(),
         ScrollDirection::Down => state.dir_browser.select_next(),
@@ -104,7 +352,11 @@ pub fn handle_scroll(state: &mut BrowserState, direction: ScrollDirection) -> Op
 }
 
 pub fn handle_mouse_click(state: &mut BrowserState, _x: u16, y: u16) -> Option<Action> {
-    let (list_start_y, list_end_y) = browser_list_y_range(state.path_height);
+    if state.volumes_picker.show || state.bookmarks_picker.show {
+        return None;
+    }
+    let (list_start_y, list_end_y) =
+        browser_list_y_range(state.path_height, state.inspector_height);
 
     if y >= list_start_y && y < list_end_y {
         let visible_index = (y - list_start_y) as usize;
@@ -119,7 +371,11 @@ pub fn handle_mouse_click(state: &mut BrowserState, _x: u16, y: u16) -> Option<A
 }
 
 pub fn handle_double_click(state: &mut BrowserState, _x: u16, y: u16) -> Option<Action> {
-    let (list_start_y, list_end_y) = browser_list_y_range(state.path_height);
+    if state.volumes_picker.show || state.bookmarks_picker.show {
+        return None;
+    }
+    let (list_start_y, list_end_y) =
+        browser_list_y_range(state.path_height, state.inspector_height);
 
     if y >= list_start_y && y < list_end_y {
         let visible_index = (y - list_start_y) as usize;
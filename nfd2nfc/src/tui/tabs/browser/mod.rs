@@ -1,4 +1,6 @@
+pub mod directory_convert;
 pub mod events;
+pub mod recursive_convert;
 pub mod render;
 pub mod state;
 
@@ -9,6 +11,7 @@ use ratatui::layout::Rect;
 use ratatui::Frame;
 
 use crate::tui::app::events::MouseState;
+use crate::tui::command_palette::PaletteCommand;
 use crate::tui::component::{Action, ScrollDirection, SharedState, TabComponent};
 use crate::tui::tabs::Tab;
 
@@ -33,8 +36,101 @@ impl TabComponent for BrowserState {
         events::handle_double_click(self, x, y)
     }
 
-    fn tick(&mut self, shared: &SharedState) {
+    fn tick(&mut self, shared: &SharedState) -> Option<Action> {
         self.dir_browser.tick(shared.current_tab == Tab::Browser);
         self.auto_adjust_mode();
+        self.tick_recursive_task();
+        let action = self.tick_dir_convert_task();
+        self.volumes_picker.tick();
+        action
+    }
+
+    fn commands(&self, _shared: &SharedState) -> Vec<PaletteCommand> {
+        if self.volumes_picker.show
+            || self.bookmarks_picker.show
+            || self.marking
+            || self.dir_browser.rename.active
+            || self.dir_browser.filter.active
+        {
+            return Vec::new();
+        }
+
+        vec![
+            PaletteCommand {
+                label: "Toggle action (convert/reverse)".to_string(),
+                key_label: "t",
+                key: KeyCode::Char('t'),
+            },
+            PaletteCommand {
+                label: "Cycle mode".to_string(),
+                key_label: "m",
+                key: KeyCode::Char('m'),
+            },
+            PaletteCommand {
+                label: "Toggle hidden files".to_string(),
+                key_label: ".",
+                key: KeyCode::Char('.'),
+            },
+            PaletteCommand {
+                label: "Cycle sort mode".to_string(),
+                key_label: "s",
+                key: KeyCode::Char('s'),
+            },
+            PaletteCommand {
+                label: "Toggle sort direction".to_string(),
+                key_label: "S",
+                key: KeyCode::Char('S'),
+            },
+            PaletteCommand {
+                label: "Recursive convert selected directory".to_string(),
+                key_label: "r",
+                key: KeyCode::Char('r'),
+            },
+            PaletteCommand {
+                label: "Jump to volume".to_string(),
+                key_label: "v",
+                key: KeyCode::Char('v'),
+            },
+            PaletteCommand {
+                label: "Mark current directory".to_string(),
+                key_label: "M",
+                key: KeyCode::Char('M'),
+            },
+            PaletteCommand {
+                label: "Open bookmarks".to_string(),
+                key_label: "B",
+                key: KeyCode::Char('B'),
+            },
+            PaletteCommand {
+                label: "Filter entries".to_string(),
+                key_label: "/",
+                key: KeyCode::Char('/'),
+            },
+            PaletteCommand {
+                label: "Rename selected entry".to_string(),
+                key_label: "n",
+                key: KeyCode::Char('n'),
+            },
+            PaletteCommand {
+                label: "Toggle mark on selected entry".to_string(),
+                key_label: "Space",
+                key: KeyCode::Char(' '),
+            },
+            PaletteCommand {
+                label: "Select all visible entries".to_string(),
+                key_label: "A",
+                key: KeyCode::Char('A'),
+            },
+            PaletteCommand {
+                label: "Clear selection".to_string(),
+                key_label: "C",
+                key: KeyCode::Char('C'),
+            },
+            PaletteCommand {
+                label: "Toggle codepoint inspector".to_string(),
+                key_label: "i",
+                key: KeyCode::Char('i'),
+            },
+        ]
     }
 }
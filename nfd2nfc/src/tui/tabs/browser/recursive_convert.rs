@@ -0,0 +1,152 @@
+//! Background recursive NFD→NFC conversion for a directory subtree.
+//!
+//! Modeled after a small cooperative task scheduler (yazi's task queue is
+//! the inspiration): a single worker thread walks the subtree rooted at a
+//! selected directory, converting names as it goes and streaming progress
+//! back over a channel so the render loop never blocks on a deep tree.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+
+use log::{error, info};
+
+use nfd2nfc_core::normalizer::{
+    get_actual_file_name, normalize_single_file, CollisionStrategy, NormalizationTarget,
+};
+use nfd2nfc_core::utils::abbreviate_home_path;
+
+/// Running tally reported back to the TUI as the scan/convert proceeds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecursiveConvertProgress {
+    pub scanned: usize,
+    pub needs_conversion: usize,
+    pub converted: usize,
+    pub failed: usize,
+    pub done: bool,
+}
+
+/// A recursive convert-to-NFC task running on a background thread.
+pub struct RecursiveConvertTask {
+    pub root: PathBuf,
+    pub progress: RecursiveConvertProgress,
+    progress_rx: Receiver<RecursiveConvertProgress>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl RecursiveConvertTask {
+    /// Spawn a worker that walks `root` and converts every NFD/Mixed name
+    /// beneath it to NFC.
+    pub fn spawn(root: PathBuf) -> Self {
+        let (tx, progress_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let worker_root = root.clone();
+        let worker_cancel = cancel.clone();
+        thread::spawn(move || {
+            info!(
+                "Starting recursive convert to NFC below: {}",
+                abbreviate_home_path(&worker_root)
+            );
+
+            let mut progress = RecursiveConvertProgress::default();
+            walk_and_convert(&worker_root, &worker_cancel, &mut progress, &tx);
+            progress.done = true;
+            let _ = tx.send(progress);
+
+            info!(
+                "Finished recursive convert below {}: {} scanned, {} converted, {} failed",
+                abbreviate_home_path(&worker_root),
+                progress.scanned,
+                progress.converted,
+                progress.failed
+            );
+        });
+
+        Self {
+            root,
+            progress: RecursiveConvertProgress::default(),
+            progress_rx,
+            cancel,
+        }
+    }
+
+    /// Drain all progress updates sent so far. Returns `true` once the task
+    /// has finished (completed or been cancelled).
+    pub fn poll(&mut self) -> bool {
+        while let Ok(progress) = self.progress_rx.try_recv() {
+            self.progress = progress;
+        }
+        self.progress.done
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Recursively walk `dir`, converting any NFD/Mixed name to NFC and sending
+/// an updated `progress` snapshot after every entry. Bails out early if
+/// `cancel` is set.
+fn walk_and_convert(
+    dir: &Path,
+    cancel: &AtomicBool,
+    progress: &mut RecursiveConvertProgress,
+    tx: &Sender<RecursiveConvertProgress>,
+) {
+    let entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok()).collect(),
+        Err(e) => {
+            error!(
+                "Recursive convert: failed to read {}: {}",
+                abbreviate_home_path(dir),
+                e
+            );
+            return;
+        }
+    };
+
+    for entry in entries {
+        if cancel.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let path = entry.path();
+        progress.scanned += 1;
+
+        let mut converted_path = path.clone();
+        if let Ok(name) = get_actual_file_name(&path) {
+            if NormalizationTarget::NFC.needs_conversion(&name) {
+                progress.needs_conversion += 1;
+                match normalize_single_file(
+                    &path,
+                    NormalizationTarget::NFC,
+                    None,
+                    CollisionStrategy::Skip,
+                ) {
+                    Ok(()) => {
+                        progress.converted += 1;
+                        converted_path = path.with_file_name(NormalizationTarget::NFC.convert(&name));
+                    }
+                    Err(e) => {
+                        progress.failed += 1;
+                        error!(
+                            "Recursive convert: failed to convert {}: {}",
+                            abbreviate_home_path(&path),
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        let _ = tx.send(*progress);
+
+        if converted_path.is_dir() {
+            walk_and_convert(&converted_path, cancel, progress, tx);
+        }
+    }
+}
@@ -6,19 +6,25 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
+use unicode_normalization::UnicodeNormalization;
 use unicode_width::UnicodeWidthStr;
 
-use crate::tui::app::events::MouseState;
+use crate::tui::app::events::{list_row_rect, MouseState};
 use crate::tui::app::render::content_area;
 use crate::tui::component::SharedState;
 use crate::tui::dir_browser::{SelectionKind, UnicodeForm};
 use crate::tui::shortcuts::{gap, shortcut, shortcut_bracketed, space, ShortcutBlock};
 use crate::tui::styles::{
-    active_value_style, inactive_italic_style, inactive_style, key_style, label_style,
+    active_value_style, hover_style, inactive_italic_style, inactive_style, key_style, label_style,
     reverse_value_style,
 };
 use crate::tui::tabs::browser::state::{BrowserAction, BrowserMode, BrowserState};
-use nfd2nfc_core::utils::abbreviate_home;
+use nfd2nfc_core::normalizer::NormalizationTarget;
+use nfd2nfc_core::utils::{abbreviate_home, abbreviate_home_path};
+
+/// Rows shown at once in the codepoint-inspector pane before the rest of a
+/// long name is cut off, so one giant filename can't swallow the tab.
+const INSPECTOR_MAX_ROWS: usize = 8;
 
 pub fn render(
     state: &mut BrowserState,
@@ -27,13 +33,24 @@ pub fn render(
     _shared: &SharedState,
     mouse: &mut MouseState,
 ) {
-    // Hide Convert button for Parent and ASCII file selections
-    let kind = state.dir_browser.selection_kind();
-    let hide_convert = matches!(kind, SelectionKind::Parent | SelectionKind::FileAscii);
+    // Hide Convert button for Parent, ASCII file, and busy-recursive
+    // selections -- unless entries are marked, in which case Enter acts on
+    // the marked set instead of the highlighted entry.
+    let kind = state.effective_selection_kind();
+    let hide_convert = state.marked.is_empty()
+        && matches!(
+            kind,
+            SelectionKind::Parent | SelectionKind::FileAscii | SelectionKind::DirRecursive
+        );
 
     let mut items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![space()];
     if !hide_convert {
-        items.push(shortcut_bracketed("↵", "Convert", KeyCode::Enter));
+        let convert_label = if state.marked.is_empty() {
+            "Convert"
+        } else {
+            "Convert marked"
+        };
+        items.push(shortcut_bracketed("↵", convert_label, KeyCode::Enter));
         items.push(gap());
     }
     items.extend(vec![
@@ -52,11 +69,36 @@ pub fn render(
         gap(),
         shortcut_bracketed(".", "Hidden", KeyCode::Char('.')),
         gap(),
+        shortcut_bracketed("/", "Filter", KeyCode::Char('/')),
+        gap(),
+        shortcut_bracketed("n", "Rename", KeyCode::Char('n')),
+        gap(),
+        shortcut_bracketed("Space", "Mark", KeyCode::Char(' ')),
+        gap(),
+        shortcut("A", "ll", KeyCode::Char('A')),
+        gap(),
+        shortcut("C", "lear", KeyCode::Char('C')),
+        gap(),
+        shortcut_bracketed("i", "Inspect", KeyCode::Char('i')),
+        gap(),
+        shortcut("S", "ort", KeyCode::Char('s')),
+        gap(),
+        shortcut("V", "olumes", KeyCode::Char('v')),
+        gap(),
+        shortcut("M", "ark", KeyCode::Char('M')),
+        gap(),
+        shortcut("B", "ookmarks", KeyCode::Char('B')),
+        gap(),
         shortcut("Q", "uit", KeyCode::Char('q')),
         space(),
     ]);
 
-    let inner = ShortcutBlock::new(Line::from(" Browser "))
+    let title = if state.marked.is_empty() {
+        " Browser ".to_string()
+    } else {
+        format!(" Browser ({} selected) ", state.marked.len())
+    };
+    let inner = ShortcutBlock::new(Line::from(title))
         .items(items)
         .render(f, area, mouse);
 
@@ -78,12 +120,35 @@ pub fn render(
     let path_height = path_lines + 2; // +2 for top/bottom borders
     state.path_height = path_height;
 
-    let chunks = Layout::vertical([
+    // Codepoint-inspector rows for the highlighted entry, computed ahead of
+    // layout since the pane's height depends on how many codepoints there
+    // are to show.
+    let inspector_rows = if state.show_inspector {
+        state
+            .dir_browser
+            .effective_selected_entry()
+            .filter(|e| !e.is_parent)
+            .map(|e| inspector_rows_for(&e.name))
+    } else {
+        None
+    };
+    let inspector_height = inspector_rows
+        .as_ref()
+        .map(|rows| rows.len().min(INSPECTOR_MAX_ROWS) as u16 + 2)
+        .unwrap_or(0);
+    state.inspector_height = inspector_height;
+
+    let mut constraints = vec![
         Constraint::Length(path_height), // Current path (dynamic)
         Constraint::Min(5),              // File list
-        Constraint::Length(2),           // Options
-    ])
-    .split(inner);
+    ];
+    if inspector_height > 0 {
+        constraints.push(Constraint::Length(inspector_height)); // Codepoint inspector
+    }
+    constraints.push(Constraint::Length(2)); // Options
+
+    let chunks = Layout::vertical(constraints).split(inner);
+    let options_area_idx = chunks.len() - 1;
 
     // Current path
     let path_block = Block::default()
@@ -99,6 +164,10 @@ pub fn render(
 
     // File list
     let selected_idx = state.dir_browser.list_state.selected();
+    // Last frame's scroll offset, since ratatui only settles this frame's
+    // offset once `render_stateful_widget` below runs.
+    let list_offset = state.dir_browser.render_offset;
+    let list_area = chunks[1];
 
     let items: Vec<ListItem> = state
         .dir_browser
@@ -106,11 +175,36 @@ pub fn render(
         .iter()
         .enumerate()
         .map(|(i, entry)| {
+            let hovered = selected_idx != Some(i)
+                && list_row_rect(list_area, 1, list_offset, i).is_some_and(|rect| mouse.is_hovered(rect));
+            let row_style = if hovered { hover_style() } else { Style::default() };
+
             if entry.is_parent {
                 ListItem::new(Line::from(vec![
                     Span::styled("📂", Style::default().fg(Color::Yellow)),
                     Span::styled("..", Style::default().fg(Color::Yellow)),
                 ]))
+                .style(row_style)
+            } else if state.dir_browser.rename.active && selected_idx == Some(i) {
+                let icon = if entry.is_dir { "📁" } else { "📄" };
+                let input = &state.dir_browser.rename.input;
+                let form = crate::tui::dir_browser::detect_unicode_form(input);
+
+                let mut spans = vec![
+                    Span::styled(icon, Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        input.clone(),
+                        Style::default().fg(Color::Black).bg(Color::Yellow),
+                    ),
+                ];
+                if form != UnicodeForm::ASCII {
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(
+                        format!("[{}]", form.as_str()),
+                        Style::default().fg(Color::Black).bg(form.color()),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
             } else {
                 let icon = if entry.is_dir { "📁" } else { "📄" };
                 let style = if entry.is_dir {
@@ -119,10 +213,12 @@ pub fn render(
                     Style::default()
                 };
 
-                let mut spans = vec![
-                    Span::styled(icon, style),
-                    Span::styled(entry.name.clone(), style),
-                ];
+                let mut spans = Vec::new();
+                if state.marked.contains(&entry.path) {
+                    spans.push(Span::styled("✓ ", Style::default().fg(Color::Green)));
+                }
+                spans.push(Span::styled(icon, style));
+                spans.extend(name_spans(&entry.name, style, &state.dir_browser.filter.query));
 
                 if entry.form != UnicodeForm::ASCII {
                     let is_selected = selected_idx == Some(i);
@@ -138,17 +234,38 @@ pub fn render(
                     ));
                 }
 
-                ListItem::new(Line::from(spans))
+                ListItem::new(Line::from(spans)).style(row_style)
             }
         })
         .collect();
 
+    let mut files_title = vec![Span::raw(" Files ")];
+    if state.dir_browser.filter.active || !state.dir_browser.filter.query.is_empty() {
+        files_title.push(Span::styled(
+            format!("/{}", state.dir_browser.filter.query),
+            Style::default().fg(Color::Yellow),
+        ));
+        files_title.push(Span::raw(" "));
+    }
+    if state.marking {
+        files_title.push(Span::styled(
+            "Mark: press a key ",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if state.dir_browser.rename.active {
+        files_title.push(Span::styled(
+            "Rename: [Tab]NFC [⇧Tab]NFD [↵]Save [Esc]Cancel ",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .title(" Files "),
+                .title(Line::from(files_title)),
         )
         .highlight_style(Style::default().bg(Color::DarkGray));
 
@@ -160,6 +277,10 @@ pub fn render(
     // Store rendered offset for mouse click calculations
     state.dir_browser.render_offset = adjusted_state.offset();
 
+    if inspector_height > 0 {
+        render_inspector(inspector_rows.as_deref().unwrap_or(&[]), chunks[2], f);
+    }
+
     // Bottom menu logic based on SelectionKind
     let selected_form = state.dir_browser.effective_selected_entry().map(|e| e.form);
 
@@ -183,6 +304,8 @@ pub fn render(
             Some(UnicodeForm::NFD) => (gray, gray, "Convert (NFD→NFC)", gray_italic),
             _ => (gray, gray, "Reverse (NFC→NFD)", gray_italic),
         },
+        // Busy: a background recursive convert task owns this directory
+        SelectionKind::DirRecursive => (gray, gray, "Busy", gray_italic),
         // Directory (Recursive/Children): user-selectable (active)
         _ => match state.action {
             BrowserAction::Convert => (active_key, active_label, "Convert (NFD→NFC)", active_text),
@@ -195,6 +318,7 @@ pub fn render(
             (gray, gray, "N/A", gray_italic)
         }
         SelectionKind::FileNFD | SelectionKind::FileNFC => (gray, gray, "Name only", gray_italic),
+        SelectionKind::DirRecursive => (gray, gray, "Converting…", gray_italic),
         _ => (
             active_key,
             active_label,
@@ -209,7 +333,7 @@ pub fn render(
     let mode_clickable = kind.is_dir();
 
     // Register click areas for Action and Mode options (centered)
-    let options_area = chunks[2];
+    let options_area = chunks[options_area_idx];
     let action_spans = vec![
         Span::styled("Ac", action_label_style),
         Span::styled("t", action_key_style),
@@ -227,7 +351,7 @@ pub fn render(
         Span::styled(mode_text, mode_text_style),
     ];
 
-    let option_items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![
+    let mut option_items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![
         (
             action_spans,
             if action_clickable {
@@ -245,8 +369,65 @@ pub fn render(
                 None
             },
         ),
+        (vec![Span::styled("  |  ", Style::default())], None),
+        (
+            vec![
+                Span::styled("S", active_key),
+                Span::styled("ort: ", active_label),
+                Span::styled(
+                    format!(
+                        "{} {}",
+                        state.dir_browser.sort_mode.as_str(),
+                        if state.dir_browser.sort_reverse { "↑" } else { "↓" }
+                    ),
+                    active_text,
+                ),
+            ],
+            Some(KeyCode::Char('s')),
+        ),
     ];
 
+    // Recursive-convert segment: an "R" hint for any directory selection, or
+    // live progress + an Esc-to-cancel hint while a task is running on it.
+    match kind {
+        SelectionKind::DirUnicode | SelectionKind::DirAscii => {
+            option_items.push((vec![Span::styled("  |  ", Style::default())], None));
+            option_items.push((
+                vec![
+                    Span::styled("R", active_key),
+                    Span::styled(": convert tree", active_label),
+                ],
+                Some(KeyCode::Char('r')),
+            ));
+        }
+        SelectionKind::DirRecursive => {
+            let status_text = if let Some(task) = &state.recursive_task {
+                format!(
+                    "Converting tree: {} scanned, {} converted, {} failed",
+                    task.progress.scanned, task.progress.converted, task.progress.failed
+                )
+            } else if let Some(task) = &state.dir_convert_task {
+                format!(
+                    "Converting: {} dirs scanned, {} converted",
+                    task.progress.dirs_scanned, task.progress.files_converted
+                )
+            } else {
+                String::new()
+            };
+            option_items.push((vec![Span::styled("  |  ", Style::default())], None));
+            option_items.push((vec![Span::styled(status_text, active_text)], None));
+            option_items.push((vec![Span::styled("  ", Style::default())], None));
+            option_items.push((
+                vec![
+                    Span::styled("Esc", active_key),
+                    Span::styled(": cancel", active_label),
+                ],
+                Some(KeyCode::Esc),
+            ));
+        }
+        _ => {}
+    }
+
     let total_width: u16 = option_items
         .iter()
         .flat_map(|(spans, _)| spans.iter())
@@ -258,16 +439,202 @@ pub fn render(
     let options_para = Paragraph::new(Line::from(option_spans));
     let render_area = Rect::new(x_start, options_area.y, total_width, 1);
     f.render_widget(options_para, render_area);
+
+    if state.volumes_picker.show {
+        render_volumes_picker(state, f, area);
+    }
+    if state.bookmarks_picker.show {
+        render_bookmarks_picker(state, f, area);
+    }
+}
+
+/// hunter-style bookmark jump popup, listing every saved label/path pair.
+fn render_bookmarks_picker(state: &BrowserState, f: &mut Frame, area: Rect) {
+    let popup = centered_rect(area, 70, 60);
+    f.render_widget(ratatui::widgets::Clear, popup);
+
+    let items: Vec<ListItem> = state
+        .bookmarks_picker
+        .entries
+        .iter()
+        .map(|entry| {
+            let spans = vec![
+                Span::styled(format!("[{}]", entry.key), Style::default().fg(Color::Yellow)),
+                Span::raw(" "),
+                Span::styled(
+                    abbreviate_home_path(&entry.path),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ];
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title(" Bookmarks  [↵] Jump  [Esc] Close "),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = state.bookmarks_picker.list_state;
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
+
+/// broot-style `:filesystems` popup: every mounted volume with its type,
+/// free space, and an `UnicodeForm`-style badge reporting how it actually
+/// stores NFD filenames once probed.
+fn render_volumes_picker(state: &BrowserState, f: &mut Frame, area: Rect) {
+    use crate::tui::volumes_picker::behavior_badge;
+
+    let popup = centered_rect(area, 70, 60);
+    f.render_widget(ratatui::widgets::Clear, popup);
+
+    let items: Vec<ListItem> = state
+        .volumes_picker
+        .entries
+        .iter()
+        .map(|entry| {
+            let (badge_text, badge_color) = behavior_badge(entry.behavior);
+            let free_gb = entry.info.free_bytes as f64 / 1_073_741_824.0;
+            let total_gb = entry.info.total_bytes as f64 / 1_073_741_824.0;
+
+            let spans = vec![
+                Span::styled(format!("{:<20}", entry.info.name), Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                Span::styled(format!("{:<8}", entry.info.fs_type), Style::default().fg(Color::White)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{:>6.1}/{:<6.1} GB free", free_gb, total_gb),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw("  "),
+                Span::styled(format!("[{}]", badge_text), Style::default().fg(badge_color)),
+            ];
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title(" Volumes  [↵] Jump  [Esc] Close "),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = state.volumes_picker.list_state;
+    f.render_stateful_widget(list, popup, &mut list_state);
+}
+
+/// A rect centered in `area`, `percent_x`/`percent_y` of its size.
+pub(crate) fn centered_rect(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .split(area);
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(vertical[1])[1]
+}
+
+/// Split `name` into spans, highlighting characters matched by the active
+/// filter `query` (if any) on top of the entry's base `style`.
+pub(crate) fn name_spans<'a>(name: &'a str, style: Style, query: &str) -> Vec<Span<'a>> {
+    let Some(matched) = (!query.is_empty())
+        .then(|| crate::tui::dir_browser::match_positions(query, name))
+        .flatten()
+    else {
+        return vec![Span::styled(name, style)];
+    };
+
+    let highlight = style.fg(Color::Yellow).add_modifier(ratatui::style::Modifier::BOLD);
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if matched.contains(&i) {
+                Span::styled(c.to_string(), highlight)
+            } else {
+                Span::styled(c.to_string(), style)
+            }
+        })
+        .collect()
 }
 
 // ─────────────────────────────────────────────────────────────
 // Layout helper for mouse click calculations
 // ─────────────────────────────────────────────────────────────
 
-pub fn browser_list_y_range(path_height: u16) -> (u16, u16) {
+pub fn browser_list_y_range(path_height: u16, inspector_height: u16) -> (u16, u16) {
     let ca = content_area();
     let inner_y = ca.y + 1;
     let list_start_y = inner_y + path_height + 1;
-    let list_end_y = ca.y + ca.height - 1 - 2 - 1;
+    let list_end_y = ca.y + ca.height - 1 - 2 - 1 - inspector_height;
     (list_start_y, list_end_y)
 }
+
+/// One row per character of `name`'s NFC form: its own `U+XXXX` codepoint
+/// next to the `U+XXXX` sequence it decomposes into under NFD, plus whether
+/// that sequence actually differs from the composed character (more than
+/// one codepoint, or a different one).
+fn inspector_rows_for(name: &str) -> Vec<(String, String, bool)> {
+    let nfc_form = NormalizationTarget::NFC.convert(name);
+    nfc_form
+        .chars()
+        .map(|c| {
+            let decomposed: String = c.to_string().nfd().collect();
+            let composed = format!("U+{:04X} '{}'", c as u32, c);
+            let parts: Vec<String> = decomposed
+                .chars()
+                .map(|d| format!("U+{:04X} '{}'", d as u32, d))
+                .collect();
+            let differs = parts.len() > 1 || decomposed.chars().next() != Some(c);
+            (composed, parts.join(" "), differs)
+        })
+        .collect()
+}
+
+/// Renders the codepoint-inspector pane: each row pairs one composed (NFC)
+/// character with the codepoint sequence it decomposes into under NFD, so a
+/// user can see exactly which grapheme "Convert"/"Reverse" will touch before
+/// pressing Enter. Rows where the two sides differ are highlighted.
+fn render_inspector(rows: &[(String, String, bool)], area: Rect, f: &mut Frame) {
+    let nfc_width = rows.iter().map(|(nfc, _, _)| nfc.width()).max().unwrap_or(0);
+    let lines: Vec<Line> = rows
+        .iter()
+        .take(INSPECTOR_MAX_ROWS)
+        .map(|(nfc, nfd, differs)| {
+            let style = if *differs {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Line::from(Span::styled(
+                format!("{:<width$}  ->  {}", nfc, nfd, width = nfc_width),
+                style,
+            ))
+        })
+        .collect();
+
+    let hidden = rows.len().saturating_sub(INSPECTOR_MAX_ROWS);
+    let title = if hidden > 0 {
+        format!(" Codepoints: NFC -> NFD (+{} more) ", hidden)
+    } else {
+        " Codepoints: NFC -> NFD ".to_string()
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title(title);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -1,7 +1,26 @@
-use nfd2nfc_core::normalizer::{normalize_directory, normalize_single_file, NormalizationTarget};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use nfd2nfc_core::exclude::ExcludeSet;
+use nfd2nfc_core::normalizer::{normalize_single_file, NormalizationTarget};
 use unicode_normalization::UnicodeNormalization;
 
-use crate::tui::dir_browser::{DirBrowser, UnicodeForm};
+use crate::tui::bookmarks_picker::BookmarksPicker;
+use crate::tui::component::Action;
+use crate::tui::dir_browser::{DirBrowser, SelectionKind, UnicodeForm};
+use crate::tui::inputs::Writer;
+use crate::tui::tabs::browser::directory_convert::DirectoryConvertTask;
+use crate::tui::tabs::browser::recursive_convert::RecursiveConvertTask;
+use crate::tui::volumes_picker::VolumesPicker;
+
+/// What `convert_selected` actually did: finish synchronously, or hand the
+/// work off to a background `DirectoryConvertTask` because it targeted a
+/// whole directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertOutcome {
+    Completed,
+    Started,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BrowserAction {
@@ -64,6 +83,29 @@ pub struct BrowserState {
     pub action: BrowserAction,
     pub mode: BrowserMode,
     pub path_height: u16,
+    /// Height of the codepoint-inspector pane last frame, 0 when hidden;
+    /// `browser_list_y_range` needs this to keep mouse clicks aligned with
+    /// the file list once the pane pushes it up.
+    pub inspector_height: u16,
+    /// Whether the codepoint-inspector pane (NFC/NFD breakdown of the
+    /// highlighted entry's name) is shown below the file list.
+    pub show_inspector: bool,
+    pub recursive_task: Option<RecursiveConvertTask>,
+    pub dir_convert_task: Option<DirectoryConvertTask>,
+    pub volumes_picker: VolumesPicker,
+    pub bookmarks_picker: BookmarksPicker,
+    /// Awaiting a label keypress for a pending bookmark, started by the
+    /// "mark" key.
+    pub marking: bool,
+    /// Vim-style repeat count accumulated from digit keypresses, applied to
+    /// the next `j`/`k` motion.
+    pub pending_count: Option<usize>,
+    /// Whether the last key handled was `g`, awaiting a second `g` to jump
+    /// to the first entry.
+    pub pending_g: bool,
+    /// Entries marked with the space bar for a batch `Enter` conversion,
+    /// instead of acting on only the highlighted entry.
+    pub marked: HashSet<PathBuf>,
 }
 
 impl std::fmt::Debug for BrowserState {
@@ -77,13 +119,213 @@ impl std::fmt::Debug for BrowserState {
 }
 
 impl BrowserState {
-    pub fn new() -> Self {
+    pub fn new(events_tx: Writer) -> Self {
         Self {
-            dir_browser: DirBrowser::new(),
+            dir_browser: DirBrowser::new(events_tx),
             action: BrowserAction::Convert,
             mode: BrowserMode::NameOnly,
             path_height: 3,
+            inspector_height: 0,
+            show_inspector: false,
+            recursive_task: None,
+            dir_convert_task: None,
+            volumes_picker: VolumesPicker::new(),
+            bookmarks_picker: BookmarksPicker::new(),
+            marking: false,
+            pending_count: None,
+            pending_g: false,
+            marked: HashSet::new(),
+        }
+    }
+
+    /// Classification of the selected entry, overridden to `DirRecursive`
+    /// while a background recursive convert task (or a background
+    /// `convert_selected` directory conversion) is running on it.
+    pub fn effective_selection_kind(&self) -> SelectionKind {
+        let kind = self.dir_browser.selection_kind();
+        let selected_path = self.dir_browser.effective_selected_entry().map(|e| &e.path);
+        let recursive_task_running_here = self
+            .recursive_task
+            .as_ref()
+            .is_some_and(|task| selected_path.is_some_and(|p| *p == task.root));
+        let dir_convert_task_running_here = self
+            .dir_convert_task
+            .as_ref()
+            .is_some_and(|task| selected_path.is_some_and(|p| *p == task.root));
+        if recursive_task_running_here || dir_convert_task_running_here {
+            SelectionKind::DirRecursive
+        } else {
+            kind
+        }
+    }
+
+    /// Start a background recursive scan that converts every NFD/Mixed name
+    /// beneath `root` to NFC without blocking the render loop.
+    pub fn start_recursive_convert(&mut self, root: PathBuf) {
+        self.recursive_task = Some(RecursiveConvertTask::spawn(root));
+    }
+
+    /// Cancel the active recursive convert task, if any.
+    pub fn cancel_recursive_task(&mut self) {
+        if let Some(task) = &self.recursive_task {
+            task.cancel();
+        }
+    }
+
+    /// Poll the active recursive convert task, if any, refreshing the
+    /// browser as entries are converted and clearing the task once it
+    /// finishes or is cancelled.
+    pub fn tick_recursive_task(&mut self) {
+        let Some(task) = &mut self.recursive_task else {
+            return;
+        };
+        let finished = task.poll();
+        self.dir_browser.refresh();
+        if finished {
+            self.recursive_task = None;
+        }
+    }
+
+    /// Start a background conversion of `root` in the given mode/action
+    /// without blocking the render loop, so a live counter can be shown and
+    /// the run can be cancelled mid-flight.
+    fn start_directory_convert(
+        &mut self,
+        root: PathBuf,
+        recursive: bool,
+        target: NormalizationTarget,
+    ) {
+        self.dir_convert_task = Some(DirectoryConvertTask::spawn(
+            root,
+            recursive,
+            target,
+            ExcludeSet::default(),
+            self.dir_browser.collision_strategy,
+        ));
+    }
+
+    /// Cancel the active directory convert task, if any.
+    pub fn cancel_dir_convert_task(&mut self) {
+        if let Some(task) = &self.dir_convert_task {
+            task.cancel();
+        }
+    }
+
+    /// Poll the active directory convert task, if any, refreshing the
+    /// browser as entries are converted and clearing the task once it
+    /// finishes or is cancelled. Returns a toast summarizing the run if it
+    /// just finished: an error summary if the walk stopped early on a
+    /// failure, or a converted-count toast otherwise.
+    pub fn tick_dir_convert_task(&mut self) -> Option<Action> {
+        let task = self.dir_convert_task.as_mut()?;
+        let finished = task.poll();
+        self.dir_browser.refresh();
+        if !finished {
+            return None;
+        }
+
+        let error = task.take_error();
+        let files_converted = task.progress.files_converted;
+        self.dir_convert_task = None;
+
+        Some(match error {
+            Some(message) => Action::ShowToast {
+                message: format!("Directory conversion stopped: {}", message),
+                is_error: true,
+            },
+            None => Action::ShowToast {
+                message: format!("Converted {} item(s)", files_converted),
+                is_error: false,
+            },
+        })
+    }
+
+    /// React to a create/remove/rename reported by the FSEvents watch on
+    /// `dir_browser`'s current directory, refreshing only if it's still
+    /// the directory being shown.
+    pub fn on_dir_changed(&mut self, changed_dir: PathBuf) {
+        self.dir_browser.handle_watch_event(changed_dir);
+    }
+
+    /// Jump the browser to the volume currently selected in the picker and
+    /// close it.
+    pub fn jump_to_selected_volume(&mut self) {
+        if let Some(path) = self.volumes_picker.selected_path() {
+            self.dir_browser.enter_directory(&path);
+        }
+        self.volumes_picker.close();
+    }
+
+    /// Jump the browser to the bookmark currently selected in the popup and
+    /// close it.
+    pub fn jump_to_selected_bookmark(&mut self) {
+        if let Some(path) = self.bookmarks_picker.selected_path() {
+            self.dir_browser.enter_directory(&path);
+        }
+        self.bookmarks_picker.close();
+    }
+
+    /// Toggles the mark on the currently highlighted entry (the synthetic
+    /// `..` entry can't be marked).
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(entry) = self.dir_browser.effective_selected_entry() else {
+            return;
+        };
+        if entry.is_parent {
+            return;
         }
+        let path = entry.path.clone();
+        if !self.marked.remove(&path) {
+            self.marked.insert(path);
+        }
+    }
+
+    /// Marks every entry currently listed (post-filter), skipping `..`.
+    pub fn select_all_visible(&mut self) {
+        self.marked.extend(
+            self.dir_browser
+                .entries
+                .iter()
+                .filter(|e| !e.is_parent)
+                .map(|e| e.path.clone()),
+        );
+    }
+
+    /// Clears the marked set without touching the current selection.
+    pub fn clear_selection(&mut self) {
+        self.marked.clear();
+    }
+
+    /// Toggles the codepoint-inspector pane that breaks the highlighted
+    /// entry's NFC and NFD forms down into individual `U+XXXX` codepoints.
+    pub fn toggle_inspector(&mut self) {
+        self.show_inspector = !self.show_inspector;
+    }
+
+    /// Runs `self.action` over every marked entry, each as its own
+    /// single-level rename exactly like `convert_selected`'s `NameOnly`
+    /// path (a marked directory's own name is converted, but batch marking
+    /// doesn't recurse into its contents; use `r` on one directory at a
+    /// time for that). Successfully converted entries are unmarked; any
+    /// that fail stay marked so the run can be retried. Returns the count
+    /// converted and the error message for each failure.
+    pub fn convert_marked(&mut self) -> (usize, Vec<String>) {
+        let target = self.action.to_target();
+        let mut converted = 0;
+        let mut errors = Vec::new();
+
+        for path in self.marked.clone() {
+            match normalize_single_file(&path, target, None, self.dir_browser.collision_strategy) {
+                Ok(()) => {
+                    self.marked.remove(&path);
+                    converted += 1;
+                }
+                Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+
+        self.dir_browser.refresh();
+        (converted, errors)
     }
 
     pub fn toggle_action(&mut self) {
@@ -106,59 +348,57 @@ impl BrowserState {
         }
     }
 
-    pub fn convert_selected(&mut self) -> Result<(), String> {
+    /// Converts the selected entry according to `self.action`/`self.mode`.
+    /// A directory in `Children` or `Recursive` mode is handed off to a
+    /// background `DirectoryConvertTask` (`ConvertOutcome::Started`);
+    /// everything else is a single, immediate rename
+    /// (`ConvertOutcome::Completed`).
+    pub fn convert_selected(&mut self) -> Result<ConvertOutcome, String> {
         let entry = match self.dir_browser.effective_selected_entry() {
             Some(e) => e,
             None => return Err("No item selected".to_string()),
         };
 
         let target = self.action.to_target();
-        let path = &entry.path;
+        let path = entry.path.clone();
 
-        // Calculate the expected new path after conversion
-        let new_path = if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-            let new_name: String = match target {
-                NormalizationTarget::NFC => name.nfc().collect(),
-                NormalizationTarget::NFD => name.nfd().collect(),
-            };
-            path.with_file_name(new_name)
-        } else {
-            path.clone()
-        };
-
-        let result = match self.mode {
-            BrowserMode::NameOnly => normalize_single_file(path, target),
-            BrowserMode::Children => {
-                if path.is_dir() {
-                    normalize_directory(path, false, target)
-                } else {
-                    normalize_single_file(path, target)
-                }
-            }
-            BrowserMode::Recursive => {
-                if path.is_dir() {
-                    normalize_directory(path, true, target)
-                } else {
-                    normalize_single_file(path, target)
-                }
+        let recursive = match self.mode {
+            BrowserMode::Recursive => true,
+            BrowserMode::Children => false,
+            BrowserMode::NameOnly => {
+                normalize_single_file(&path, target, None, self.dir_browser.collision_strategy)
+                    .map_err(|e| e.to_string())?;
+                self.dir_browser.refresh();
+                return Ok(ConvertOutcome::Completed);
             }
         };
 
-        result.map_err(|e| e.to_string())?;
+        if path.is_dir() {
+            self.start_directory_convert(path, recursive, target);
+            return Ok(ConvertOutcome::Started);
+        }
 
-        // Refresh after conversion
-        self.dir_browser.refresh();
+        normalize_single_file(&path, target, None, self.dir_browser.collision_strategy)
+            .map_err(|e| e.to_string())?;
 
-        // Try to select the converted path
-        if let Some(idx) = self
-            .dir_browser
-            .entries
-            .iter()
-            .position(|e| e.path == new_path)
-        {
-            self.dir_browser.list_state.select(Some(idx));
+        // Refresh and try to select the converted path
+        self.dir_browser.refresh();
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            let new_name: String = match target {
+                NormalizationTarget::NFC => name.nfc().collect(),
+                NormalizationTarget::NFD => name.nfd().collect(),
+            };
+            let new_path = path.with_file_name(new_name);
+            if let Some(idx) = self
+                .dir_browser
+                .entries
+                .iter()
+                .position(|e| e.path == new_path)
+            {
+                self.dir_browser.list_state.select(Some(idx));
+            }
         }
 
-        Ok(())
+        Ok(ConvertOutcome::Completed)
     }
 }
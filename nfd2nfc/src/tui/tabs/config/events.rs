@@ -2,12 +2,25 @@ use crossterm::event::KeyCode;
 
 use crate::tui::app::render::content_area;
 use crate::tui::component::{Action, ScrollDirection, SharedState};
+use crate::tui::tabs::config::manage_modal::ManageModalState;
 use crate::tui::tabs::config::modal::events as modal_events;
 use crate::tui::tabs::config::modal::events::{
     handle_modal_double_click, handle_modal_mouse_click, handle_modal_scroll,
 };
 use crate::tui::tabs::config::state::ConfigState;
 
+/// Mutation requested by the manage-paths modal for the entry at `index`,
+/// applied by `ConfigState` — analogous to `ModalAddResult` for the
+/// add-path flow, but for editing an entry already in the list.
+pub enum ModalEditResult {
+    ToggleAction { index: usize },
+    ToggleMode { index: usize },
+}
+
+pub struct ModalRemoveResult {
+    pub index: usize,
+}
+
 pub fn handle_key(state: &mut ConfigState, key: KeyCode, _shared: &SharedState) -> Option<Action> {
     if state.modal.show {
         let (action, add_result) = modal_events::handle_modal_key(&mut state.modal, key);
@@ -17,6 +30,19 @@ pub fn handle_key(state: &mut ConfigState, key: KeyCode, _shared: &SharedState)
         return action.or(Some(Action::Consumed));
     }
 
+    if state.manage_modal.show {
+        let path_count = state.config.paths.len();
+        let (action, edit, remove) =
+            handle_manage_modal_key(&mut state.manage_modal, path_count, key);
+        if let Some(edit) = edit {
+            state.apply_manage_edit(edit);
+        }
+        if let Some(remove) = remove {
+            state.apply_manage_remove(remove);
+        }
+        return action.or(Some(Action::Consumed));
+    }
+
     match key {
         KeyCode::Up | KeyCode::Char('k') => {
             state.select_previous();
@@ -31,6 +57,12 @@ pub fn handle_key(state: &mut ConfigState, key: KeyCode, _shared: &SharedState)
             state.modal.browser.refresh();
             None
         }
+        KeyCode::Char('e') => {
+            if !state.config.paths.is_empty() {
+                state.manage_modal.open(state.table_state.selected());
+            }
+            None
+        }
         KeyCode::Char('d') | KeyCode::Delete => {
             state.delete_selected();
             None
@@ -79,6 +111,51 @@ pub fn handle_key(state: &mut ConfigState, key: KeyCode, _shared: &SharedState)
     }
 }
 
+/// Returns (action, edit mutation, remove mutation); the caller applies
+/// whichever mutation is present against `ConfigState`.
+fn handle_manage_modal_key(
+    modal: &mut ManageModalState,
+    path_count: usize,
+    key: KeyCode,
+) -> (Option<Action>, Option<ModalEditResult>, Option<ModalRemoveResult>) {
+    match key {
+        KeyCode::Esc => {
+            modal.close();
+            (None, None, None)
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            modal.select_previous(path_count);
+            (None, None, None)
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            modal.select_next(path_count);
+            (None, None, None)
+        }
+        KeyCode::Char('t') => {
+            let edit = modal
+                .list_state
+                .selected()
+                .map(|index| ModalEditResult::ToggleAction { index });
+            (None, edit, None)
+        }
+        KeyCode::Char('m') => {
+            let edit = modal
+                .list_state
+                .selected()
+                .map(|index| ModalEditResult::ToggleMode { index });
+            (None, edit, None)
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            let remove = modal
+                .list_state
+                .selected()
+                .map(|index| ModalRemoveResult { index });
+            (None, None, remove)
+        }
+        _ => (None, None, None),
+    }
+}
+
 pub fn handle_scroll(state: &mut ConfigState, direction: ScrollDirection) -> Option<Action> {
     if state.modal.show {
         return handle_modal_scroll(&mut state.modal, direction);
@@ -0,0 +1,139 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::tui::app::events::{list_row_rect, MouseState};
+use crate::tui::component::{next_index, prev_index};
+use crate::tui::shortcuts::{gap, nav_arrows, shortcut_bracketed, space, ShortcutBlock};
+use crate::tui::styles::hover_style;
+use crate::tui::tabs::config::modal;
+use nfd2nfc_core::config::{PathAction, PathEntry, PathMode};
+use nfd2nfc_core::utils::abbreviate_home;
+
+/// Overlay for editing or removing an already-configured path in place —
+/// toggle its action, cycle its mode, or delete it — without leaving the
+/// Config tab, modeled on kmon's module-management options menu.
+pub struct ManageModalState {
+    pub show: bool,
+    pub list_state: ListState,
+}
+
+impl ManageModalState {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            list_state: ListState::default(),
+        }
+    }
+
+    pub fn open(&mut self, selected: Option<usize>) {
+        self.list_state.select(selected);
+        self.show = true;
+    }
+
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+
+    pub fn select_next(&mut self, len: usize) {
+        if let Some(i) = next_index(self.list_state.selected(), len) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn select_previous(&mut self, len: usize) {
+        if let Some(i) = prev_index(self.list_state.selected(), len) {
+            self.list_state.select(Some(i));
+        }
+    }
+}
+
+impl Default for ManageModalState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn render_manage_modal(
+    modal: &mut ManageModalState,
+    paths: &[PathEntry],
+    f: &mut Frame,
+    mouse: &mut MouseState,
+) {
+    let full_area = f.area();
+
+    // Dim the entire background including header, same as the add-path modal.
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::DarkGray)),
+        full_area,
+    );
+
+    let modal_area = modal::modal_area(full_area);
+
+    f.render_widget(Clear, modal_area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::Black).fg(Color::White)),
+        modal_area,
+    );
+
+    let mut items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![space()];
+    if !paths.is_empty() {
+        items.push(shortcut_bracketed("t", "Action", KeyCode::Char('t')));
+        items.push(gap());
+        items.push(shortcut_bracketed("m", "Mode", KeyCode::Char('m')));
+        items.push(gap());
+        items.push(shortcut_bracketed("d", "Delete", KeyCode::Char('d')));
+        items.push(gap());
+    }
+    items.extend(nav_arrows());
+    items.push(gap());
+    items.push(shortcut_bracketed("⎋", "Close", KeyCode::Esc));
+    items.push(space());
+
+    let inner = ShortcutBlock::new(Line::from(Span::styled(
+        " Manage Paths ",
+        Style::default().fg(Color::White),
+    )))
+    .items(items)
+    .render(f, modal_area, mouse);
+
+    // Last frame's scroll offset, since ratatui only settles this frame's
+    // offset once `render_stateful_widget` below runs.
+    let offset = modal.list_state.offset();
+
+    let list_items: Vec<ListItem> = paths
+        .iter()
+        .enumerate()
+        .map(|(pos, entry)| {
+            let (mode_text, mode_style) = if entry.action == PathAction::Ignore {
+                (
+                    "Recursive",
+                    Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC),
+                )
+            } else {
+                (entry.mode.as_str(), Style::default())
+            };
+            let hovered = modal.list_state.selected() != Some(pos)
+                && list_row_rect(inner, 1, offset, pos).is_some_and(|rect| mouse.is_hovered(rect));
+            let row_style = if hovered { hover_style() } else { Style::default() };
+            ListItem::new(Line::from(vec![
+                Span::raw(abbreviate_home(&entry.raw)),
+                Span::raw("  "),
+                Span::styled(entry.action.as_str(), Style::default().fg(Color::Cyan)),
+                Span::raw(" / "),
+                Span::styled(mode_text, mode_style),
+            ]))
+            .style(row_style)
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(Block::default().borders(Borders::ALL).title(" Configured Paths "))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    f.render_stateful_widget(list, inner, &mut modal.list_state);
+}
@@ -0,0 +1,106 @@
+pub mod events;
+pub mod manage_modal;
+pub mod modal;
+pub mod render;
+pub mod state;
+
+pub use state::ConfigState;
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::Frame;
+
+use crate::tui::app::events::MouseState;
+use crate::tui::command_palette::PaletteCommand;
+use crate::tui::component::{Action, ScrollDirection, SharedState, TabComponent};
+
+impl TabComponent for ConfigState {
+    fn render(&mut self, f: &mut Frame, area: Rect, shared: &SharedState, mouse: &mut MouseState) {
+        render::render(self, f, area, shared, mouse);
+    }
+
+    fn handle_key(&mut self, key: KeyCode, shared: &SharedState) -> Option<Action> {
+        events::handle_key(self, key, shared)
+    }
+
+    fn handle_scroll(&mut self, direction: ScrollDirection) -> Option<Action> {
+        events::handle_scroll(self, direction)
+    }
+
+    fn handle_mouse_click(&mut self, x: u16, y: u16) -> Option<Action> {
+        events::handle_mouse_click(self, x, y)
+    }
+
+    fn handle_double_click(&mut self, x: u16, y: u16) -> Option<Action> {
+        events::handle_double_click(self, x, y)
+    }
+
+    fn tick(&mut self, shared: &SharedState) -> Option<Action> {
+        self.poll(shared);
+        None
+    }
+
+    fn commands(&self, _shared: &SharedState) -> Vec<PaletteCommand> {
+        if self.modal.show || self.manage_modal.show {
+            return Vec::new();
+        }
+
+        let mut commands = vec![
+            PaletteCommand {
+                label: "Add path".to_string(),
+                key_label: "a",
+                key: KeyCode::Char('a'),
+            },
+            PaletteCommand {
+                label: "Manage paths".to_string(),
+                key_label: "e",
+                key: KeyCode::Char('e'),
+            },
+            PaletteCommand {
+                label: "Delete selected path".to_string(),
+                key_label: "d",
+                key: KeyCode::Char('d'),
+            },
+            PaletteCommand {
+                label: "Toggle action (watch/ignore)".to_string(),
+                key_label: "t",
+                key: KeyCode::Char('t'),
+            },
+            PaletteCommand {
+                label: "Toggle mode (recursive/children)".to_string(),
+                key_label: "m",
+                key: KeyCode::Char('m'),
+            },
+            PaletteCommand {
+                label: "Sort paths".to_string(),
+                key_label: "o",
+                key: KeyCode::Char('o'),
+            },
+            PaletteCommand {
+                label: "Move path up".to_string(),
+                key_label: "+",
+                key: KeyCode::Char('+'),
+            },
+            PaletteCommand {
+                label: "Move path down".to_string(),
+                key_label: "-",
+                key: KeyCode::Char('-'),
+            },
+        ];
+
+        if self.has_changes {
+            commands.push(PaletteCommand {
+                label: "Save config".to_string(),
+                key_label: "s",
+                key: KeyCode::Char('s'),
+            });
+            commands.push(PaletteCommand {
+                label: "Discard changes".to_string(),
+                key_label: "Esc",
+                key: KeyCode::Esc,
+            });
+        }
+
+        commands
+    }
+}
@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use crossterm::event::KeyCode;
 
-use crate::tui::component::{Action, ScrollDirection};
+use crate::tui::component::{push_count_digit, take_count, Action, ScrollDirection};
 use crate::tui::dir_browser::SelectionKind;
 use crate::tui::tabs::config::modal::state::AddModalState;
 use nfd2nfc_core::config::{PathAction, PathMode};
@@ -20,17 +20,53 @@ pub fn handle_modal_key(
     modal: &mut AddModalState,
     key: KeyCode,
 ) -> (Option<Action>, Option<ModalAddResult>) {
+    if modal.browser.filter.active {
+        return handle_modal_filter_key(modal, key);
+    }
+
+    if let KeyCode::Char(c) = key {
+        if c.is_ascii_digit() {
+            if push_count_digit(&mut modal.pending_count, c.to_digit(10).unwrap()) {
+                modal.pending_g = false;
+            }
+            return (None, None);
+        }
+        if c == 'g' {
+            if modal.pending_g {
+                modal.pending_g = false;
+                modal.pending_count = None;
+                modal.browser.select_first_dir();
+            } else {
+                modal.pending_g = true;
+            }
+            return (None, None);
+        }
+    }
+
+    // Any other key cancels a pending count or `g`; `take_count` both
+    // resolves and clears it so it can't leak into a later keypress.
+    let count = take_count(&mut modal.pending_count);
+    modal.pending_g = false;
+
     match key {
         KeyCode::Esc => {
             modal.show = false;
             (None, None)
         }
         KeyCode::Up | KeyCode::Char('k') => {
-            modal.browser.select_previous_dir();
+            for _ in 0..count {
+                modal.browser.select_previous_dir();
+            }
             (None, None)
         }
         KeyCode::Down | KeyCode::Char('j') => {
-            modal.browser.select_next_dir();
+            for _ in 0..count {
+                modal.browser.select_next_dir();
+            }
+            (None, None)
+        }
+        KeyCode::Char('G') => {
+            modal.browser.select_last_dir();
             (None, None)
         }
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Backspace => {
@@ -84,10 +120,31 @@ pub fn handle_modal_key(
             modal.browser.toggle_hidden();
             (None, None)
         }
+        KeyCode::Char('/') => {
+            modal.browser.start_filter();
+            (None, None)
+        }
         _ => (None, None),
     }
 }
 
+/// Incremental filter input, mirroring the browser tab's filter editor:
+/// typing narrows the directory listing, `Enter` confirms and returns to
+/// normal navigation, `Esc` clears the filter and restores the full list.
+fn handle_modal_filter_key(
+    modal: &mut AddModalState,
+    key: KeyCode,
+) -> (Option<Action>, Option<ModalAddResult>) {
+    match key {
+        KeyCode::Char(c) => modal.browser.push_filter_char(c),
+        KeyCode::Backspace => modal.browser.pop_filter_char(),
+        KeyCode::Enter => modal.browser.confirm_filter(),
+        KeyCode::Esc => modal.browser.cancel_filter(),
+        _ => {}
+    }
+    (None, None)
+}
+
 pub fn handle_modal_scroll(
     modal: &mut AddModalState,
     direction: ScrollDirection,
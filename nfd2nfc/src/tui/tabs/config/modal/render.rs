@@ -1,12 +1,13 @@
-use crate::tui::app::events::MouseState;
+use crate::tui::app::events::{list_row_rect, MouseState};
 use crate::tui::dir_browser::SelectionKind;
 use crate::tui::shortcuts::{
     gap, nav_arrows, render_centered_options, shortcut_bracketed, space, ShortcutBlock,
 };
 use crate::tui::styles::{
-    active_value_style, inactive_italic_style, inactive_style, key_style, label_style,
-    reverse_value_style,
+    active_value_style, hover_style, inactive_italic_style, inactive_style, key_style,
+    label_style, reverse_value_style,
 };
+use crate::tui::tabs::browser::render::name_spans;
 use crate::tui::tabs::config::modal::state::AddModalState;
 use crossterm::event::KeyCode;
 use nfd2nfc_core::config::PathAction;
@@ -55,6 +56,8 @@ pub fn render_add_modal(
         gap(),
         shortcut_bracketed(".", "Hidden", KeyCode::Char('.')),
         gap(),
+        shortcut_bracketed("/", "Filter", KeyCode::Char('/')),
+        gap(),
         shortcut_bracketed("⎋", "Cancel", KeyCode::Esc),
         space(),
     ]);
@@ -102,47 +105,66 @@ pub fn render_add_modal(
 
     f.render_widget(path_para, chunks[0]);
 
+    // Adjust list state: map from entries index to filtered dir-only index
+    let dir_indices = modal.browser.dir_indices();
+    let selected_pos = modal
+        .browser
+        .list_state
+        .selected()
+        .and_then(|selected_entry_idx| dir_indices.iter().position(|&i| i == selected_entry_idx));
+
+    // Last frame's scroll offset, since ratatui only settles this frame's
+    // offset once `render_stateful_widget` below runs.
+    let offset = modal.browser.render_offset;
+
     // File browser list (directories only)
     let items: Vec<ListItem> = modal
         .browser
         .entries
         .iter()
         .filter(|e| e.is_dir)
-        .map(|entry| {
+        .enumerate()
+        .map(|(pos, entry)| {
+            let hovered = selected_pos != Some(pos)
+                && list_row_rect(chunks[1], 1, offset, pos).is_some_and(|rect| mouse.is_hovered(rect));
+            let row_style = if hovered { hover_style() } else { Style::default() };
+
             if entry.is_parent {
                 ListItem::new(Line::from(vec![
                     Span::styled(" 📂", Style::default().fg(Color::Yellow)),
                     Span::styled("..", Style::default().fg(Color::Yellow)),
                 ]))
+                .style(row_style)
             } else {
                 let style = Style::default().fg(Color::White);
-                ListItem::new(Line::from(vec![
-                    Span::styled(" 📁", style),
-                    Span::styled(&entry.name, style),
-                ]))
+                let mut spans = vec![Span::styled(" 📁", style)];
+                spans.extend(name_spans(&entry.name, style, &modal.browser.filter.query));
+                ListItem::new(Line::from(spans)).style(row_style)
             }
         })
         .collect();
 
+    let mut dir_title = vec![Span::raw(" Directories ")];
+    if modal.browser.filter.active || !modal.browser.filter.query.is_empty() {
+        dir_title.push(Span::styled(
+            format!("/{}", modal.browser.filter.query),
+            Style::default().fg(Color::Yellow),
+        ));
+        dir_title.push(Span::raw(" "));
+    }
+
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .title(" Directories "),
+                .title(Line::from(dir_title)),
         )
         .highlight_style(Style::default().bg(Color::DarkGray));
 
-    // Adjust list state: map from entries index to filtered dir-only index
-    let dir_indices = modal.browser.dir_indices();
-
     let mut adjusted_state = ratatui::widgets::ListState::default();
-    *adjusted_state.offset_mut() = modal.browser.render_offset;
-    if let Some(selected_entry_idx) = modal.browser.list_state.selected() {
-        if let Some(pos) = dir_indices.iter().position(|&i| i == selected_entry_idx) {
-            adjusted_state.select(Some(pos));
-        }
-    }
+    *adjusted_state.offset_mut() = offset;
+    adjusted_state.select(selected_pos);
 
     f.render_stateful_widget(list, chunks[1], &mut adjusted_state);
 
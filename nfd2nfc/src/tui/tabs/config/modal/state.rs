@@ -1,4 +1,5 @@
 use crate::tui::dir_browser::DirBrowser;
+use crate::tui::inputs::Writer;
 use nfd2nfc_core::config::{PathAction, PathMode};
 
 #[derive(Debug)]
@@ -8,16 +9,24 @@ pub struct AddModalState {
     pub action: PathAction,
     pub mode: PathMode,
     pub path_box_height: u16,
+    /// Vim-style repeat count accumulated from digit keypresses, applied to
+    /// the next `j`/`k` motion.
+    pub pending_count: Option<usize>,
+    /// Whether the last key handled was `g`, awaiting a second `g` to jump
+    /// to the first entry.
+    pub pending_g: bool,
 }
 
 impl AddModalState {
-    pub fn new() -> Self {
+    pub fn new(events_tx: Writer) -> Self {
         Self {
             show: false,
-            browser: DirBrowser::new(),
+            browser: DirBrowser::new(events_tx),
             action: PathAction::Watch,
             mode: PathMode::Recursive,
             path_box_height: 3,
+            pending_count: None,
+            pending_g: false,
         }
     }
 
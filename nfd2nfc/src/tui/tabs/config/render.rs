@@ -16,10 +16,11 @@ const CONFIG_TABLE_WIDTHS: [ratatui::layout::Constraint; 5] = [
     ratatui::layout::Constraint::Length(12),
 ];
 
-use crate::tui::app::events::MouseState;
+use crate::tui::app::events::{list_row_rect, MouseState};
 use crate::tui::component::SharedState;
 use crate::tui::shortcuts::{gap, space, ShortcutBlock};
-use crate::tui::styles::{key_style, label_style};
+use crate::tui::styles::{hover_style, key_style, label_style};
+use crate::tui::tabs::config::manage_modal::render_manage_modal;
 use crate::tui::tabs::config::modal::render::render_add_modal;
 use crate::tui::tabs::config::state::ConfigState;
 use nfd2nfc_core::config::{PathAction, PathEntry, PathMode, PathStatus};
@@ -77,8 +78,8 @@ pub fn render(
         Line::from(" Config ")
     };
 
-    // Build shortcuts (register click areas only when modal is not shown)
-    let register = !state.modal.show;
+    // Build shortcuts (register click areas only when no modal is shown)
+    let register = !state.modal.show && !state.manage_modal.show;
     let reg = |code: KeyCode| if register { Some(code) } else { None };
 
     let mut items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![
@@ -91,6 +92,14 @@ pub fn render(
             reg(KeyCode::Char('a')),
         ),
         gap(),
+        (
+            vec![
+                Span::styled("E", key_style()),
+                Span::styled("dit", label_style()),
+            ],
+            reg(KeyCode::Char('e')),
+        ),
+        gap(),
         (
             vec![
                 Span::styled("D", key_style()),
@@ -235,6 +244,12 @@ pub fn render(
                 Cell::from(Line::from(Span::styled(abbreviated, path_style)))
             };
 
+            // Table rows aren't scrolled (the table is assumed to fit the
+            // viewport, same assumption `handle_table_mouse_click` makes),
+            // so the row offset is always 0.
+            let hovered = Some(idx) != selected_idx
+                && list_row_rect(table_area, 2, 0, idx).is_some_and(|rect| mouse.is_hovered(rect));
+
             Row::new(vec![
                 Cell::from(format!("{}", idx + 1)),
                 path_cell,
@@ -246,6 +261,7 @@ pub fn render(
                     entry.status.as_str()
                 )),
             ])
+            .style(if hovered { hover_style() } else { Style::default() })
         })
         .collect();
 
@@ -308,4 +324,9 @@ pub fn render(
     if state.modal.show {
         render_add_modal(&mut state.modal, f, area, mouse);
     }
+
+    // Render manage-paths modal if active
+    if state.manage_modal.show {
+        render_manage_modal(&mut state.manage_modal, &state.config.paths, f, mouse);
+    }
 }
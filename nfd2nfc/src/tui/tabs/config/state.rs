@@ -7,32 +7,37 @@ use nfd2nfc_core::config::{load_config, Config, PathAction, PathEntry, PathMode}
 use nfd2nfc_core::constants::CONFIG_PATH;
 
 use crate::tui::component::SharedState;
+use crate::tui::inputs::Writer;
+use crate::tui::tabs::config::events::ModalEditResult;
+use crate::tui::tabs::config::events::ModalRemoveResult;
+use crate::tui::tabs::config::manage_modal::ManageModalState;
 use crate::tui::tabs::config::modal::state::AddModalState;
 use crate::tui::tabs::Tab;
 use crate::tui::tick_timer::TickTimer;
 
 const STATUS_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
-const CONFIG_RELOAD_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug)]
 pub struct ConfigState {
     pub config: Config,
     pub table_state: TableState,
     pub modal: AddModalState,
+    pub manage_modal: ManageModalState,
     pub has_changes: bool,
+    events_tx: Writer,
     status_refresh_timer: TickTimer,
-    config_reload_timer: TickTimer,
 }
 
 impl ConfigState {
-    pub fn from_config(config: Config) -> Self {
+    pub fn from_config(config: Config, events_tx: Writer) -> Self {
         let mut state = Self {
             config,
             table_state: TableState::default(),
-            modal: AddModalState::new(),
+            modal: AddModalState::new(events_tx.clone()),
+            manage_modal: ManageModalState::new(),
             has_changes: false,
+            events_tx,
             status_refresh_timer: TickTimer::new(STATUS_REFRESH_INTERVAL),
-            config_reload_timer: TickTimer::new(CONFIG_RELOAD_INTERVAL),
         };
 
         if !state.config.paths.is_empty() {
@@ -108,6 +113,57 @@ impl ConfigState {
         }
     }
 
+    /// Apply a mutation requested by the manage-paths modal for the entry
+    /// at the given index.
+    pub fn apply_manage_edit(&mut self, edit: ModalEditResult) {
+        match edit {
+            ModalEditResult::ToggleAction { index } => {
+                self.config.paths[index].action = self.config.paths[index].action.toggle();
+                if self.config.paths[index].action == PathAction::Ignore {
+                    self.config.paths[index].mode = PathMode::Recursive;
+                }
+            }
+            ModalEditResult::ToggleMode { index } => {
+                if self.config.paths[index].action == PathAction::Ignore {
+                    return;
+                }
+                self.config.paths[index].mode = self.config.paths[index].mode.toggle();
+            }
+        }
+        self.has_changes = true;
+        self.config.refresh_statuses();
+    }
+
+    /// Remove the entry at the index requested by the manage-paths modal,
+    /// keeping both the table and the modal's own selection in bounds.
+    pub fn apply_manage_remove(&mut self, remove: ModalRemoveResult) {
+        self.config.paths.remove(remove.index);
+        self.has_changes = true;
+        if self.config.paths.is_empty() {
+            self.table_state.select(None);
+            self.manage_modal.list_state.select(None);
+        } else {
+            if self
+                .table_state
+                .selected()
+                .is_some_and(|i| i >= self.config.paths.len())
+            {
+                self.table_state.select(Some(self.config.paths.len() - 1));
+            }
+            if self
+                .manage_modal
+                .list_state
+                .selected()
+                .is_some_and(|i| i >= self.config.paths.len())
+            {
+                self.manage_modal
+                    .list_state
+                    .select(Some(self.config.paths.len() - 1));
+            }
+        }
+        self.config.refresh_statuses();
+    }
+
     pub fn delete_selected(&mut self) {
         if let Some(i) = self.table_state.selected() {
             self.config.paths.remove(i);
@@ -131,7 +187,7 @@ impl ConfigState {
     pub fn reload(&mut self) {
         let selected = self.table_state.selected();
         let (config, _) = load_config();
-        *self = Self::from_config(config);
+        *self = Self::from_config(config, self.events_tx.clone());
         if let Some(i) = selected {
             if i < self.config.paths.len() {
                 self.table_state.select(Some(i));
@@ -160,17 +216,20 @@ impl ConfigState {
     pub fn poll(&mut self, shared: &SharedState) {
         if shared.current_tab == Tab::Config {
             self.modal.tick();
-            if !self.modal.show {
-                if self.status_refresh_timer.ready() {
-                    self.config.refresh_statuses();
-                }
-                if !self.has_changes && self.config_reload_timer.ready() {
-                    self.reload();
-                }
+            if !self.modal.show && self.status_refresh_timer.ready() {
+                self.config.refresh_statuses();
             }
         }
     }
 
+    /// Reload from disk in response to an external change to `CONFIG_PATH`,
+    /// unless local edits are pending or a modal is open.
+    pub fn on_config_changed(&mut self) {
+        if !self.has_changes && !self.modal.show && !self.manage_modal.show {
+            self.reload();
+        }
+    }
+
     pub fn save(&mut self) -> Result<(), String> {
         self.config
             .save_to_file(&CONFIG_PATH)
@@ -4,26 +4,39 @@ use crate::tui::component::{Action, SharedState};
 use crate::tui::tabs::home::state::HomeState;
 
 pub fn handle_key(_state: &mut HomeState, key: KeyCode, shared: &SharedState) -> Option<Action> {
-    // Ignore keys while operation is in progress
+    // While an operation is in progress, only Esc (cancel) is handled --
+    // everything else is ignored rather than queued.
     if shared.async_op_pending {
-        return None;
+        return match key {
+            KeyCode::Esc => Some(Action::CancelWatcherOp),
+            _ => None,
+        };
     }
 
     match key {
         KeyCode::Char('s') => {
-            if shared.watcher_running {
+            if shared.watcher_health.is_up() {
                 Some(Action::StopWatcher)
             } else {
                 Some(Action::StartWatcher)
             }
         }
         KeyCode::Char('r') => {
-            if shared.watcher_running {
+            if shared.watcher_health.is_up() {
                 Some(Action::RestartWatcher)
             } else {
                 None
             }
         }
+        KeyCode::Char('p') => {
+            if !shared.watcher_health.is_up() {
+                None
+            } else if shared.paused {
+                Some(Action::ResumeWatcher)
+            } else {
+                Some(Action::PauseWatcher)
+            }
+        }
         _ => None,
     }
 }
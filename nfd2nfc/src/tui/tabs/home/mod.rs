@@ -9,6 +9,7 @@ use ratatui::layout::Rect;
 use ratatui::Frame;
 
 use crate::tui::app::events::MouseState;
+use crate::tui::command_palette::PaletteCommand;
 use crate::tui::component::{Action, SharedState, TabComponent};
 
 impl TabComponent for HomeState {
@@ -23,4 +24,37 @@ impl TabComponent for HomeState {
     fn tick(&mut self, _shared: &SharedState) {
         self.tick_version_check();
     }
+
+    fn commands(&self, shared: &SharedState) -> Vec<PaletteCommand> {
+        if shared.async_op_pending {
+            return Vec::new();
+        }
+
+        let mut commands = vec![PaletteCommand {
+            label: if shared.watcher_health.is_up() {
+                "Stop watcher".to_string()
+            } else {
+                "Start watcher".to_string()
+            },
+            key_label: "s",
+            key: KeyCode::Char('s'),
+        }];
+        if shared.watcher_health.is_up() {
+            commands.push(PaletteCommand {
+                label: "Restart watcher".to_string(),
+                key_label: "r",
+                key: KeyCode::Char('r'),
+            });
+            commands.push(PaletteCommand {
+                label: if shared.paused {
+                    "Resume watcher".to_string()
+                } else {
+                    "Pause watcher".to_string()
+                },
+                key_label: "p",
+                key: KeyCode::Char('p'),
+            });
+        }
+        commands
+    }
 }
@@ -37,16 +37,22 @@ pub fn render(
             gap(),
             shortcut_dimmed("R", "estart"),
             gap(),
-            shortcut("Q", "uit", KeyCode::Char('q')),
+            shortcut("Esc", "Cancel", KeyCode::Esc),
             space(),
         ]
-    } else if shared.watcher_running {
+    } else if shared.watcher_health.is_up() {
         vec![
             space(),
             shortcut("S", "top", KeyCode::Char('s')),
             gap(),
             shortcut("R", "estart", KeyCode::Char('r')),
             gap(),
+            if shared.paused {
+                shortcut("P", "esume", KeyCode::Char('p'))
+            } else {
+                shortcut("P", "ause", KeyCode::Char('p'))
+            },
+            gap(),
             shortcut("Q", "uit", KeyCode::Char('q')),
             space(),
         ]
@@ -93,16 +99,28 @@ pub fn render(
     // Watcher status (shows pending operation if in progress)
     let status_text = watcher_status_span(
         shared.pending_operation,
-        shared.watcher_running,
+        shared.watcher_health,
+        shared.paused,
         &StatusLabels {
             pending_prefix: "  ",
             pending_suffix: "  ",
             running: "  Running  ",
+            idle: "  Idle  ",
+            paused: "  Paused  ",
             stopped: "  Stopped  ",
         },
     );
 
-    let status_line = Line::from(vec![Span::raw("Watcher Status: "), status_text]);
+    let mut status_spans = vec![Span::raw("Watcher Status: "), status_text];
+    if shared.watcher_health.is_up() {
+        if let Some(stats) = shared.watcher_stats {
+            status_spans.push(Span::styled(
+                format!("  ({:.1}/s)", stats.renames_per_sec),
+                dimmed_style(),
+            ));
+        }
+    }
+    let status_line = Line::from(status_spans);
 
     let status = Paragraph::new(status_line).alignment(Alignment::Center);
     f.render_widget(status, chunks[1]);
@@ -1,19 +1,51 @@
 use crossterm::event::KeyCode;
+use ratatui::layout::Position;
 
-use crate::tui::app::render::content_area;
-use crate::tui::component::{Action, ScrollDirection, SharedState};
-use crate::tui::tabs::logs::state::LogsState;
+use crate::tui::component::{push_count_digit, take_count, Action, ScrollDirection, SharedState};
+use crate::tui::tabs::logs::state::{LevelClass, LogsState};
 
 pub fn handle_key(state: &mut LogsState, key: KeyCode, _shared: &SharedState) -> Option<Action> {
+    if state.search.active {
+        return handle_search_key(state, key);
+    }
+
     let visible_height = state.visible_height;
 
+    if let KeyCode::Char(c) = key {
+        if c.is_ascii_digit() {
+            if push_count_digit(&mut state.pending_count, c.to_digit(10).unwrap()) {
+                state.pending_g = false;
+            }
+            return None;
+        }
+        if c == 'g' {
+            if state.pending_g {
+                state.pending_g = false;
+                state.pending_count = None;
+                state.go_to_top();
+            } else {
+                state.pending_g = true;
+            }
+            return None;
+        }
+    }
+
+    // Any other key cancels a pending count or `g`; `take_count` both
+    // resolves and clears it so it can't leak into a later keypress.
+    let count = take_count(&mut state.pending_count);
+    state.pending_g = false;
+
     match key {
         KeyCode::Up | KeyCode::Char('k') => {
-            state.scroll_up(1);
+            state.scroll_up(count);
             None
         }
         KeyCode::Down | KeyCode::Char('j') => {
-            state.scroll_down(1);
+            state.scroll_down(count);
+            None
+        }
+        KeyCode::Char('G') => {
+            state.go_to_bottom();
             None
         }
         KeyCode::PageUp | KeyCode::Char('u') => {
@@ -32,10 +64,64 @@ pub fn handle_key(state: &mut LogsState, key: KeyCode, _shared: &SharedState) ->
             state.go_to_bottom();
             None
         }
+        KeyCode::Char('/') => {
+            state.start_search();
+            None
+        }
+        KeyCode::Char('n') if !state.search.matches.is_empty() => {
+            state.jump_next_match();
+            None
+        }
+        KeyCode::Char('N') if !state.search.matches.is_empty() => {
+            state.jump_prev_match();
+            None
+        }
+        KeyCode::Esc if !state.search.matches.is_empty() => {
+            state.cancel_search();
+            None
+        }
+        KeyCode::Char('x') if !state.notifications.is_empty() => {
+            state.dismiss_notification();
+            None
+        }
+        KeyCode::Char('F') => {
+            state.toggle_level_filter(LevelClass::Fault);
+            None
+        }
+        KeyCode::Char('E') => {
+            state.toggle_level_filter(LevelClass::Error);
+            None
+        }
+        KeyCode::Char('I') => {
+            state.toggle_level_filter(LevelClass::Info);
+            None
+        }
+        KeyCode::Char('D') => {
+            state.toggle_level_filter(LevelClass::Debug);
+            None
+        }
+        KeyCode::Char('O') => {
+            state.toggle_level_filter(LevelClass::Other);
+            None
+        }
         _ => None,
     }
 }
 
+fn handle_search_key(state: &mut LogsState, key: KeyCode) -> Option<Action> {
+    match key {
+        KeyCode::Char(c) => state.push_search_char(c),
+        KeyCode::Backspace => state.pop_search_char(),
+        KeyCode::Enter => {
+            state.confirm_search();
+            state.scroll_to_current_match();
+        }
+        KeyCode::Esc => state.cancel_search(),
+        _ => {}
+    }
+    None
+}
+
 pub fn handle_scroll(state: &mut LogsState, direction: ScrollDirection) -> Option<Action> {
     match direction {
         ScrollDirection::Up => state.scroll_up(3),
@@ -44,23 +130,30 @@ pub fn handle_scroll(state: &mut LogsState, direction: ScrollDirection) -> Optio
     None
 }
 
-pub fn handle_mouse_click(state: &mut LogsState, _x: u16, y: u16) -> Option<Action> {
-    let ca = content_area();
-    let inner_y = ca.y + 1;
-    let inner_height = ca.height.saturating_sub(2);
+pub fn handle_mouse_click(state: &mut LogsState, x: u16, y: u16) -> Option<Action> {
+    let pos = Position { x, y };
 
-    if y >= inner_y && y < inner_y + inner_height {
-        let clicked_line = (y - inner_y) as usize;
-        let target_offset = state
-            .scroll_offset
-            .saturating_add(clicked_line)
-            .saturating_sub(state.visible_height / 2);
-        let max_offset = state
-            .line_cache
-            .total_lines()
-            .saturating_sub(state.visible_height);
-        state.scroll_offset = target_offset.min(max_offset);
-        state.auto_scroll = false;
+    if let Some(area) = state.histogram_area {
+        if area.contains(pos) {
+            state.scroll_to_histogram_bucket(x, area);
+            return None;
+        }
+    }
+
+    if let Some(area) = state.table_area {
+        if area.contains(pos) {
+            let clicked_line = (y - area.y) as usize;
+            let target_offset = state
+                .scroll_offset
+                .saturating_add(clicked_line)
+                .saturating_sub(state.visible_height / 2);
+            let max_offset = state
+                .line_cache
+                .total_lines()
+                .saturating_sub(state.visible_height);
+            state.scroll_offset = target_offset.min(max_offset);
+            state.auto_scroll = false;
+        }
     }
     None
 }
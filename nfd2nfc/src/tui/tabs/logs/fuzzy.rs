@@ -0,0 +1,65 @@
+//! Fuzzy subsequence fallback for Logs search: when a query doesn't occur as
+//! a literal substring, `"cfgerr"` should still find `"config error"`. A
+//! cheap `CharBag` pre-filter rules out most lines before the subsequence
+//! check itself ever runs.
+
+/// Bitmask of which ASCII letters appear in a string (lowercase and
+/// uppercase on separate bits), with digits folded into the same 52 bits. Used as an O(1) pre-filter: if a
+/// candidate's bag doesn't contain every bit set in the query's bag, the
+/// candidate cannot be a fuzzy subsequence match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CharBag(u64);
+
+impl CharBag {
+    pub fn of(s: &str) -> Self {
+        let mut bits: u64 = 0;
+        for c in s.chars() {
+            if let Some(bit) = char_bit(c) {
+                bits |= 1 << bit;
+            }
+        }
+        CharBag(bits)
+    }
+
+    /// True if every bit set in `query` is also set in `self`.
+    pub fn contains_all(&self, query: CharBag) -> bool {
+        self.0 & query.0 == query.0
+    }
+}
+
+fn char_bit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(26 + (c as u32 - 'A' as u32)),
+        // Digits don't get their own bits; fold them into the lowercase
+        // range so the bag stays 52 bits wide while still narrowing
+        // candidates that contain no digits at all.
+        '0'..='9' => Some((c as u32 - '0' as u32) % 26),
+        _ => None,
+    }
+}
+
+/// Finds `query` as a case-insensitive subsequence of `candidate`, greedily
+/// matching each query character at the earliest possible later position.
+/// Returns the byte range of each matched character, in order, for
+/// highlighting -- or `None` if `query` isn't a subsequence of `candidate`
+/// at all. Assumes `query` is already lowercased (callers compute it once
+/// per search, not once per candidate line).
+pub fn fuzzy_subsequence_ranges(query_lower: &str, candidate: &str) -> Option<Vec<(usize, usize)>> {
+    let mut query_chars = query_lower.chars();
+    let mut want = query_chars.next()?;
+    let mut ranges = Vec::new();
+
+    for (byte_start, c) in candidate.char_indices() {
+        if c.to_ascii_lowercase() != want {
+            continue;
+        }
+        ranges.push((byte_start, byte_start + c.len_utf8()));
+        match query_chars.next() {
+            Some(next) => want = next,
+            None => return Some(ranges),
+        }
+    }
+
+    None
+}
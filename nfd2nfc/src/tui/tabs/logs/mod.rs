@@ -1,4 +1,5 @@
 pub mod events;
+mod fuzzy;
 pub mod render;
 pub mod state;
 
@@ -9,6 +10,7 @@ use ratatui::layout::Rect;
 use ratatui::Frame;
 
 use crate::tui::app::events::MouseState;
+use crate::tui::command_palette::PaletteCommand;
 use crate::tui::component::{Action, ScrollDirection, SharedState, TabComponent};
 
 impl TabComponent for LogsState {
@@ -28,7 +30,74 @@ impl TabComponent for LogsState {
         events::handle_mouse_click(self, x, y)
     }
 
-    fn tick(&mut self, _shared: &SharedState) {
-        self.process_events();
+    fn commands(&self, _shared: &SharedState) -> Vec<PaletteCommand> {
+        let mut commands = vec![
+            PaletteCommand {
+                label: "Go to top".to_string(),
+                key_label: "t",
+                key: KeyCode::Char('t'),
+            },
+            PaletteCommand {
+                label: "Go to bottom".to_string(),
+                key_label: "b",
+                key: KeyCode::Char('b'),
+            },
+            PaletteCommand {
+                label: "Search logs".to_string(),
+                key_label: "/",
+                key: KeyCode::Char('/'),
+            },
+        ];
+
+        if !self.search.matches.is_empty() {
+            commands.push(PaletteCommand {
+                label: "Jump to next match".to_string(),
+                key_label: "n",
+                key: KeyCode::Char('n'),
+            });
+            commands.push(PaletteCommand {
+                label: "Jump to previous match".to_string(),
+                key_label: "N",
+                key: KeyCode::Char('N'),
+            });
+        }
+
+        if !self.notifications.is_empty() {
+            commands.push(PaletteCommand {
+                label: "Dismiss notification".to_string(),
+                key_label: "x",
+                key: KeyCode::Char('x'),
+            });
+        }
+
+        commands.extend([
+            PaletteCommand {
+                label: "Toggle Fault level filter".to_string(),
+                key_label: "F",
+                key: KeyCode::Char('F'),
+            },
+            PaletteCommand {
+                label: "Toggle Error level filter".to_string(),
+                key_label: "E",
+                key: KeyCode::Char('E'),
+            },
+            PaletteCommand {
+                label: "Toggle Info level filter".to_string(),
+                key_label: "I",
+                key: KeyCode::Char('I'),
+            },
+            PaletteCommand {
+                label: "Toggle Debug level filter".to_string(),
+                key_label: "D",
+                key: KeyCode::Char('D'),
+            },
+            PaletteCommand {
+                label: "Toggle Other level filter".to_string(),
+                key_label: "O",
+                key: KeyCode::Char('O'),
+            },
+        ]);
+
+        commands
     }
 }
@@ -2,9 +2,9 @@ use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline},
 };
 
 /// Timestamp column width: "01-21 11:23:45" (14) + "  " (2)
@@ -12,9 +12,10 @@ const TIMESTAMP_COL_WIDTH: usize = 16;
 
 use crate::tui::app::events::MouseState;
 use crate::tui::component::SharedState;
-use crate::tui::shortcuts::{ShortcutBlock, gap, shortcut, space};
-use crate::tui::styles::{key_style, label_style};
-use crate::tui::tabs::logs::state::{LogsState, MAX_LOG_ENTRIES};
+use crate::tui::shortcuts::{ShortcutBlock, gap, shortcut, shortcut_bracketed, space};
+use crate::tui::styles::{hover_style, key_style, label_style};
+use crate::tui::tabs::logs::fuzzy::fuzzy_subsequence_ranges;
+use crate::tui::tabs::logs::state::{HISTOGRAM_HEIGHT, LevelClass, LogsState, MAX_LOG_ENTRIES, WrapMode, wrap_text};
 
 fn format_count(n: usize) -> String {
     if n == 0 {
@@ -51,6 +52,85 @@ fn format_compact(n: usize) -> String {
     }
 }
 
+/// Split `text` at each case-insensitive occurrence of `query_lower`,
+/// wrapping matched substrings in a reversed style so they stand out from
+/// `base_style`. Falls back to highlighting each individually-matched
+/// character of the leftmost fuzzy subsequence (see
+/// `fuzzy::fuzzy_subsequence_ranges`) when `query_lower` has no literal
+/// occurrence -- the same fallback `LogsState::recompute_matches` used to
+/// decide this line matches in the first place. Assumes lower-casing
+/// doesn't change byte offsets, true for the ASCII-dominated paths and
+/// messages this tab displays.
+fn highlight_matches(text: &str, query_lower: &str, base_style: Style) -> Vec<Span<'static>> {
+    let lower = text.to_lowercase();
+    let match_style = base_style.add_modifier(Modifier::REVERSED).fg(Color::Yellow);
+
+    if lower.contains(query_lower) {
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        while let Some(found) = lower.get(pos..).and_then(|rest| rest.find(query_lower)) {
+            let start = pos + found;
+            let end = start + query_lower.len();
+            if start > pos {
+                spans.push(Span::styled(text[pos..start].to_string(), base_style));
+            }
+            spans.push(Span::styled(text[start..end].to_string(), match_style));
+            pos = end;
+        }
+        if pos < text.len() || spans.is_empty() {
+            spans.push(Span::styled(text[pos..].to_string(), base_style));
+        }
+        return spans;
+    }
+
+    let Some(ranges) = fuzzy_subsequence_ranges(query_lower, &lower) else {
+        return vec![Span::styled(text.to_string(), base_style)];
+    };
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for (start, end) in ranges {
+        if start > pos {
+            spans.push(Span::styled(text[pos..start].to_string(), base_style));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), match_style));
+        pos = end;
+    }
+    if pos < text.len() {
+        spans.push(Span::styled(text[pos..].to_string(), base_style));
+    }
+    spans
+}
+
+/// Wrap each queued notification to `width`, growing to as many lines as needed
+/// rather than truncating. Oldest (next to be dismissed) is shown first.
+fn notification_lines(state: &LogsState, width: usize) -> Vec<Line<'static>> {
+    state
+        .notifications
+        .iter()
+        .flat_map(|(text, level)| {
+            wrap_text(text, width, WrapMode::Strict).into_iter().map(move |line| {
+                Line::from(Span::styled(line, level.style()))
+            })
+        })
+        .collect()
+}
+
+/// Draws the activity histogram built by `LogsState::rebuild_histogram`,
+/// scaling each bucket's weight to `area`'s height. Hovering brightens the
+/// whole widget; `handle_mouse_click` resolves exactly which bucket a click
+/// landed on itself from the click position, so there's no per-bar state
+/// to track here.
+fn render_histogram(state: &LogsState, f: &mut Frame, area: Rect, mouse: &MouseState) {
+    let data: Vec<u64> = state.histogram.iter().map(|b| b.weight).collect();
+    let style = if mouse.is_hovered(area) {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    f.render_widget(Sparkline::default().data(&data).style(style), area);
+}
+
 pub fn render(
     state: &mut LogsState,
     f: &mut Frame,
@@ -58,7 +138,7 @@ pub fn render(
     _shared: &SharedState,
     mouse: &mut MouseState,
 ) {
-    let items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![
+    let mut items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![
         space(),
         (vec![Span::styled("[", label_style())], None),
         (vec![Span::styled("↑", key_style())], Some(KeyCode::Up)),
@@ -92,30 +172,96 @@ pub fn render(
         (vec![Span::styled("/", label_style())], None),
         shortcut("B", "ottom", KeyCode::Char('b')),
         gap(),
+        (vec![Span::styled("[", label_style())], None),
+        (vec![Span::styled("F", key_style())], Some(KeyCode::Char('F'))),
+        (vec![Span::styled("E", key_style())], Some(KeyCode::Char('E'))),
+        (vec![Span::styled("I", key_style())], Some(KeyCode::Char('I'))),
+        (vec![Span::styled("D", key_style())], Some(KeyCode::Char('D'))),
+        (vec![Span::styled("O", key_style())], Some(KeyCode::Char('O'))),
+        (
+            vec![
+                Span::styled("]", label_style()),
+                Span::styled("Filter", label_style()),
+            ],
+            None,
+        ),
+        gap(),
         shortcut("Q", "uit", KeyCode::Char('q')),
         space(),
     ];
 
+    if !state.notifications.is_empty() {
+        items.push(gap());
+        items.push(shortcut_bracketed("X", "Dismiss", KeyCode::Char('x')));
+        items.push(space());
+    }
+
     let count_label = format!(
         "{}/{} ",
         format_count(state.entries.len()),
         format_compact(MAX_LOG_ENTRIES)
     );
-    let title = Line::from(vec![
+    let mut title_spans = vec![
         Span::raw(" Logs "),
         Span::styled(count_label, Style::default().fg(Color::DarkGray)),
-    ]);
+    ];
+    if state.search.active || !state.search.query.is_empty() {
+        title_spans.push(Span::styled(
+            format!("/{}", state.search.query),
+            Style::default().fg(Color::Cyan),
+        ));
+        if !state.search.active {
+            title_spans.push(Span::styled(
+                format!(
+                    " match {}/{} ",
+                    if state.search.matches.is_empty() {
+                        0
+                    } else {
+                        state.search.cursor + 1
+                    },
+                    state.search.matches.len()
+                ),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+    }
+    if !state.level_filter.is_all() {
+        let hidden: Vec<&str> = LevelClass::ALL
+            .into_iter()
+            .filter(|class| !state.level_filter.is_active(*class))
+            .map(LevelClass::label)
+            .collect();
+        title_spans.push(Span::styled(
+            format!(" Hiding: {} ", hidden.join(", ")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    let title = Line::from(title_spans);
 
     let inner = ShortcutBlock::new(title)
         .items(items)
         .render(f, area, mouse);
 
-    let logs_area = inner;
+    let notif_lines = notification_lines(state, inner.width as usize);
+    let notif_height = notif_lines.len() as u16;
+    if notif_height > 0 {
+        let bar_area = Rect {
+            height: notif_height,
+            ..inner
+        };
+        f.render_widget(Paragraph::new(notif_lines), bar_area);
+    }
 
-    state.visible_height = logs_area.height as usize;
+    let logs_area = Rect {
+        y: inner.y + notif_height,
+        height: inner.height.saturating_sub(notif_height),
+        ..inner
+    };
 
     // Show loading state during initial load
     if state.is_loading() {
+        state.histogram_area = None;
+        state.table_area = None;
         let centered_area = Rect {
             y: logs_area.y + logs_area.height / 2,
             height: 1,
@@ -129,6 +275,8 @@ pub fn render(
     }
 
     if state.entries.is_empty() {
+        state.histogram_area = None;
+        state.table_area = None;
         let empty = Paragraph::new("No logs available")
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Center);
@@ -136,7 +284,26 @@ pub fn render(
         return;
     }
 
-    let available_width = (logs_area.width as usize).saturating_sub(TIMESTAMP_COL_WIDTH + 1);
+    // Activity histogram above the table, when there's room for both.
+    let histogram_height = if logs_area.height > HISTOGRAM_HEIGHT { HISTOGRAM_HEIGHT } else { 0 };
+    let histogram_area = (histogram_height > 0).then(|| Rect { height: histogram_height, ..logs_area });
+    let table_area = Rect {
+        y: logs_area.y + histogram_height,
+        height: logs_area.height.saturating_sub(histogram_height),
+        ..logs_area
+    };
+    state.histogram_area = histogram_area;
+    state.table_area = Some(table_area);
+    state.visible_height = table_area.height as usize;
+
+    if let Some(histogram_area) = histogram_area {
+        if state.histogram_needs_rebuild(histogram_area.width as usize) {
+            state.rebuild_histogram(histogram_area.width as usize);
+        }
+        render_histogram(state, f, histogram_area, mouse);
+    }
+
+    let available_width = (table_area.width as usize).saturating_sub(TIMESTAMP_COL_WIDTH + 1);
 
     // Rebuild cache if needed, preserving scroll position anchor
     if state.line_cache.needs_rebuild(available_width) {
@@ -150,14 +317,21 @@ pub fn render(
             None
         };
 
-        state
-            .line_cache
-            .rebuild(&state.entries, available_width, state.base_index());
+        state.line_cache.rebuild(
+            &state.entries,
+            available_width,
+            state.base_index(),
+            state.level_filter,
+        );
 
         // Restore scroll position from anchor
         if let Some(entry_idx) = anchor_entry {
             state.scroll_offset = state.line_cache.first_line_of_entry(entry_idx);
         }
+
+        // Line indices shifted under the rebuild; recompute without moving
+        // the viewport (already anchored above).
+        state.recompute_matches();
     }
 
     // Clamp scroll_offset
@@ -165,31 +339,62 @@ pub fn render(
     let max_offset = total.saturating_sub(state.visible_height);
     state.scroll_offset = state.scroll_offset.min(max_offset);
 
+    let current_match = state.search.current_match();
+    let query = (!state.search.query.is_empty()).then(|| state.search.query.to_lowercase());
+
     // Render only the visible slice
     let end = (state.scroll_offset + state.visible_height).min(total);
     let visible_lines: Vec<Line> = state.line_cache.lines[state.scroll_offset..end]
         .iter()
-        .map(|cached| {
+        .enumerate()
+        .map(|(i, cached)| {
+            let abs_line = state.scroll_offset + i;
+            let style = if Some(abs_line) == current_match {
+                cached.style.bg(Color::DarkGray)
+            } else {
+                cached.style
+            };
+            let row_rect = Rect::new(table_area.x, table_area.y + i as u16, table_area.width, 1);
+            let style = if mouse.is_hovered(row_rect) {
+                style.patch(hover_style())
+            } else {
+                style
+            };
+            let is_match = query.is_some() && state.search.matches.binary_search(&abs_line).is_ok();
+            let text_spans = if is_match {
+                // Search highlighting takes priority on a matched line; the
+                // rare combination of a match inside an ANSI-colored
+                // message renders in the plain match style instead of
+                // trying to merge the two.
+                highlight_matches(&cached.text, query.as_deref().unwrap(), style)
+            } else if let Some(runs) = &cached.ansi_spans {
+                runs.iter()
+                    .map(|run| Span::styled(run.text.clone(), style.patch(run.style)))
+                    .collect()
+            } else {
+                vec![Span::styled(cached.text.clone(), style)]
+            };
+
             if cached.is_first {
-                Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         cached.display_time.clone(),
                         Style::default().fg(Color::DarkGray),
                     ),
                     Span::raw("  "),
-                    Span::styled(cached.text.clone(), cached.style),
-                ])
+                ];
+                spans.extend(text_spans);
+                Line::from(spans)
             } else {
-                Line::from(vec![
-                    Span::raw(" ".repeat(TIMESTAMP_COL_WIDTH)),
-                    Span::styled(cached.text.clone(), cached.style),
-                ])
+                let mut spans = vec![Span::raw(" ".repeat(TIMESTAMP_COL_WIDTH))];
+                spans.extend(text_spans);
+                Line::from(spans)
             }
         })
         .collect();
 
     let paragraph = Paragraph::new(visible_lines);
-    f.render_widget(paragraph, logs_area);
+    f.render_widget(paragraph, table_area);
 
     // Scrollbar overlay on the right border
     if max_offset > 0 {
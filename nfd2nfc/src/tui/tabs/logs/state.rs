@@ -1,10 +1,12 @@
 use std::collections::VecDeque;
-use std::sync::mpsc::{self, Receiver, TryRecvError};
 
+use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
+use tokio::sync::watch;
 use unicode_width::UnicodeWidthChar;
 
-use crate::log_service::{self, LogEntry, LogEvent};
+use super::fuzzy::{fuzzy_subsequence_ranges, CharBag};
+use crate::log_service::{slice_ansi_spans, AnsiSpan, LevelFilter, LogEntry, LogEvent};
 
 // ─────────────────────────────────────────────────────────────
 // Constants
@@ -21,6 +23,10 @@ pub(super) const MAX_LOG_ENTRIES: usize = 100_000;
 pub struct CachedLine {
     pub text: String,
     pub style: Style,
+    /// `text`'s ANSI-colored rendition, sliced from the source entry's
+    /// `ansi_spans` to the byte range this wrapped line covers. `None` for
+    /// the common case of a message with no embedded ANSI codes.
+    pub ansi_spans: Option<Vec<AnsiSpan>>,
     pub is_first: bool,
     pub display_time: String,
     pub entry_index: usize, // absolute index (monotonically increasing)
@@ -49,24 +55,40 @@ impl LineCache {
         self.dirty || self.width != width
     }
 
-    /// Full rebuild from all entries. Used on width change or initial load.
+    /// Full rebuild from all entries passing `filter`. Used on width/filter
+    /// change or initial load. `entry_index` on each cached line stays the
+    /// true absolute index of its source entry, even though hidden entries
+    /// leave no lines behind, so scroll math and eviction stay correct.
     pub fn rebuild(
         &mut self,
         entries: &VecDeque<LogEntry>,
         available_width: usize,
         base_index: usize,
+        filter: LevelMask,
     ) {
         self.lines.clear();
 
         for (i, entry) in entries.iter().enumerate() {
+            if !filter.contains(&entry.level) {
+                continue;
+            }
+
             let abs_index = base_index + i;
             let msg_style = entry_style(entry);
 
-            let wrapped = wrap_text(&entry.message, available_width);
+            let text = display_message(entry);
+            let runs = combined_ansi_runs(entry, &text);
+            let wrapped = wrap_text(&text, available_width, WrapMode::WordBoundary);
+            let mut offset = 0;
             for (j, text) in wrapped.into_iter().enumerate() {
+                let line_len = text.len();
+                let ansi_spans = runs.as_ref().map(|runs| slice_ansi_spans(runs, offset, offset + line_len));
+                offset += line_len;
+
                 self.lines.push(CachedLine {
                     text,
                     style: msg_style,
+                    ansi_spans,
                     is_first: j == 0,
                     display_time: if j == 0 {
                         entry.display_time.clone()
@@ -84,34 +106,50 @@ impl LineCache {
         self.dirty = false;
     }
 
-    /// Append lines for a single new entry (incremental update).
-    /// Returns the number of lines added (excluding padding replacement).
+    /// Append lines for a single new entry (incremental update), skipping it
+    /// entirely if its level is filtered out. Returns the number of lines
+    /// added (excluding padding replacement).
     pub fn append_entry(
         &mut self,
         entry: &LogEntry,
         abs_index: usize,
         available_width: usize,
+        filter: LevelMask,
     ) -> usize {
         // Remove old bottom padding
         self.remove_padding();
 
-        let msg_style = entry_style(entry);
-        let wrapped = wrap_text(&entry.message, available_width);
-        let line_count = wrapped.len();
+        let line_count = if filter.contains(&entry.level) {
+            let msg_style = entry_style(entry);
+            let text = display_message(entry);
+            let runs = combined_ansi_runs(entry, &text);
+            let wrapped = wrap_text(&text, available_width, WrapMode::WordBoundary);
+            let line_count = wrapped.len();
 
-        for (j, text) in wrapped.into_iter().enumerate() {
-            self.lines.push(CachedLine {
-                text,
-                style: msg_style,
-                is_first: j == 0,
-                display_time: if j == 0 {
-                    entry.display_time.clone()
-                } else {
-                    String::new()
-                },
-                entry_index: abs_index,
-            });
-        }
+            let mut offset = 0;
+            for (j, text) in wrapped.into_iter().enumerate() {
+                let line_len = text.len();
+                let ansi_spans = runs.as_ref().map(|runs| slice_ansi_spans(runs, offset, offset + line_len));
+                offset += line_len;
+
+                self.lines.push(CachedLine {
+                    text,
+                    style: msg_style,
+                    ansi_spans,
+                    is_first: j == 0,
+                    display_time: if j == 0 {
+                        entry.display_time.clone()
+                    } else {
+                        String::new()
+                    },
+                    entry_index: abs_index,
+                });
+            }
+
+            line_count
+        } else {
+            0
+        };
 
         // Re-add bottom padding
         self.push_padding(abs_index + 1);
@@ -165,6 +203,7 @@ impl LineCache {
             self.lines.push(CachedLine {
                 text: String::new(),
                 style: Style::default(),
+                ansi_spans: None,
                 is_first: false,
                 display_time: String::new(),
                 entry_index: padding_index,
@@ -173,6 +212,37 @@ impl LineCache {
     }
 }
 
+/// Prefixes `entry.message` with its `category`/`process_id`, when present,
+/// e.g. `"[Watcher:412] Converted foo.txt"`.
+fn display_message(entry: &LogEntry) -> String {
+    match (&entry.category, entry.process_id) {
+        (Some(category), Some(pid)) => format!("[{}:{}] {}", category, pid, entry.message),
+        (Some(category), None) => format!("[{}] {}", category, entry.message),
+        (None, Some(pid)) => format!("[{}] {}", pid, entry.message),
+        (None, None) => entry.message.clone(),
+    }
+}
+
+/// Builds the styled runs covering all of `text` (the `display_message`
+/// output for `entry`), for slicing into each of its wrapped lines: the
+/// category/PID prefix (if any) as one plain run, followed by `entry`'s
+/// `ansi_spans` verbatim, since `display_message` appends `entry.message`
+/// unchanged at the end. `None` when `entry` has no ANSI codes at all.
+fn combined_ansi_runs(entry: &LogEntry, text: &str) -> Option<Vec<AnsiSpan>> {
+    let ansi_spans = entry.ansi_spans.as_ref()?;
+    let prefix_len = text.len() - entry.message.len();
+
+    let mut runs = Vec::with_capacity(ansi_spans.len() + 1);
+    if prefix_len > 0 {
+        runs.push(AnsiSpan {
+            text: text[..prefix_len].to_string(),
+            style: Style::default(),
+        });
+    }
+    runs.extend(ansi_spans.iter().cloned());
+    Some(runs)
+}
+
 fn entry_style(entry: &LogEntry) -> Style {
     match entry.level.as_str() {
         "Fault" => Style::default().fg(Color::Red),
@@ -182,16 +252,159 @@ fn entry_style(entry: &LogEntry) -> Style {
     }
 }
 
+// ─────────────────────────────────────────────────────────────
+// Level filter
+// ─────────────────────────────────────────────────────────────
+
+/// The level classes users can toggle visibility of, independently of the
+/// raw `messageType` string on each entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LevelClass {
+    Fault,
+    Error,
+    Info,
+    Debug,
+    /// "Default" and any other unrecognized level.
+    Other,
+}
+
+impl LevelClass {
+    pub const ALL: [LevelClass; 5] = [
+        LevelClass::Fault,
+        LevelClass::Error,
+        LevelClass::Info,
+        LevelClass::Debug,
+        LevelClass::Other,
+    ];
+
+    fn from_log_level(level: &str) -> Self {
+        match level {
+            "Fault" => LevelClass::Fault,
+            "Error" => LevelClass::Error,
+            "Info" => LevelClass::Info,
+            "Debug" => LevelClass::Debug,
+            _ => LevelClass::Other,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LevelClass::Fault => "Fault",
+            LevelClass::Error => "Error",
+            LevelClass::Info => "Info",
+            LevelClass::Debug => "Debug",
+            LevelClass::Other => "Other",
+        }
+    }
+
+    pub fn key(self) -> char {
+        match self {
+            LevelClass::Fault => 'F',
+            LevelClass::Error => 'E',
+            LevelClass::Info => 'I',
+            LevelClass::Debug => 'D',
+            LevelClass::Other => 'O',
+        }
+    }
+}
+
+/// Per-level visibility toggles applied when rebuilding the `LineCache`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LevelMask {
+    pub fault: bool,
+    pub error: bool,
+    pub info: bool,
+    pub debug: bool,
+    pub other: bool,
+}
+
+impl Default for LevelMask {
+    fn default() -> Self {
+        Self {
+            fault: true,
+            error: true,
+            info: true,
+            debug: true,
+            other: true,
+        }
+    }
+}
+
+impl LevelMask {
+    fn contains(&self, level: &str) -> bool {
+        self.is_active(LevelClass::from_log_level(level))
+    }
+
+    pub fn is_active(&self, class: LevelClass) -> bool {
+        match class {
+            LevelClass::Fault => self.fault,
+            LevelClass::Error => self.error,
+            LevelClass::Info => self.info,
+            LevelClass::Debug => self.debug,
+            LevelClass::Other => self.other,
+        }
+    }
+
+    pub fn toggle(&mut self, class: LevelClass) {
+        let flag = match class {
+            LevelClass::Fault => &mut self.fault,
+            LevelClass::Error => &mut self.error,
+            LevelClass::Info => &mut self.info,
+            LevelClass::Debug => &mut self.debug,
+            LevelClass::Other => &mut self.other,
+        };
+        *flag = !*flag;
+    }
+
+    pub fn is_all(&self) -> bool {
+        LevelClass::ALL.iter().all(|c| self.is_active(*c))
+    }
+
+    /// Converts to the `LogQuery`/`log_service` level representation, so the
+    /// active filter can be pushed down to the background query threads.
+    /// `Other` (unrecognized `messageType`s, grouped with "Default" in the
+    /// UI) maps onto `LevelFilter::default`, mirroring
+    /// `LevelClass::from_log_level`'s own fallback.
+    fn to_query_filter(self) -> LevelFilter {
+        LevelFilter {
+            debug: self.debug,
+            info: self.info,
+            default: self.other,
+            error: self.error,
+            fault: self.fault,
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────
 // wrap_text
 // ─────────────────────────────────────────────────────────────
 
+/// How `wrap_text` chooses break points within an overlong line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Break at the nearest character that would overflow `max_width`,
+    /// regardless of what it splits.
+    Strict,
+    /// Prefer breaking at whitespace or a path separator (`/`, `\`) when one
+    /// exists on the current line, falling back to a character-boundary
+    /// break only when a single token itself exceeds `max_width`.
+    WordBoundary,
+}
+
 /// Wrap text to fit within max_width, respecting Unicode character boundaries
-pub fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
+pub fn wrap_text(text: &str, max_width: usize, mode: WrapMode) -> Vec<String> {
     if max_width == 0 {
         return vec![text.to_string()];
     }
 
+    match mode {
+        WrapMode::Strict => wrap_strict(text, max_width),
+        WrapMode::WordBoundary => wrap_word_boundary(text, max_width),
+    }
+}
+
+fn wrap_strict(text: &str, max_width: usize) -> Vec<String> {
     let mut lines = Vec::new();
     let mut current_line = String::new();
     let mut current_width = 0;
@@ -220,6 +433,217 @@ pub fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
     lines
 }
 
+fn wrap_word_boundary(text: &str, max_width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+    let mut current_width = 0;
+    // Byte offset into `current_line` and the width up to (and including)
+    // the most recent whitespace/separator char seen on this line.
+    let mut last_break: Option<(usize, usize)> = None;
+
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+
+        if current_width + char_width > max_width && !current_line.is_empty() {
+            match last_break {
+                Some((break_at, break_width)) if break_at > 0 => {
+                    let rest = current_line.split_off(break_at);
+                    lines.push(current_line);
+                    current_line = rest;
+                    current_width -= break_width;
+                }
+                _ => {
+                    // No break point on this line: the current token itself
+                    // exceeds max_width, so fall back to a hard break.
+                    lines.push(std::mem::take(&mut current_line));
+                    current_width = 0;
+                }
+            }
+            last_break = None;
+        }
+
+        current_line.push(c);
+        current_width += char_width;
+
+        if c.is_whitespace() || c == '/' || c == '\\' {
+            last_break = Some((current_line.len(), current_width));
+        }
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+// ─────────────────────────────────────────────────────────────
+// Search
+// ─────────────────────────────────────────────────────────────
+
+/// Incremental search over the cached, wrapped log lines: a
+/// case-insensitive substring match, falling back to a fuzzy subsequence
+/// match (see `fuzzy::fuzzy_subsequence_ranges`) for lines with no literal
+/// occurrence.
+#[derive(Default)]
+pub struct SearchState {
+    pub active: bool,
+    pub query: String,
+    /// Indices into `LineCache::lines` that match the query (see
+    /// `LogsState::recompute_matches`), in ascending order.
+    pub matches: Vec<usize>,
+    pub cursor: usize,
+}
+
+impl SearchState {
+    pub fn current_match(&self) -> Option<usize> {
+        self.matches.get(self.cursor).copied()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Notification bar
+// ─────────────────────────────────────────────────────────────
+
+/// Maximum number of Fault/Error messages held in the notification bar at once.
+const MAX_NOTIFICATIONS: usize = 5;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Fault,
+    Error,
+}
+
+impl NotificationLevel {
+    pub fn style(self) -> Style {
+        match self {
+            NotificationLevel::Fault => Style::default().fg(Color::Red),
+            NotificationLevel::Error => Style::default().fg(Color::Yellow),
+        }
+    }
+
+    fn from_log_level(level: &str) -> Option<Self> {
+        match level {
+            "Fault" => Some(NotificationLevel::Fault),
+            "Error" => Some(NotificationLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+struct Notification {
+    text: String,
+    level: NotificationLevel,
+}
+
+/// Bounded, deduplicated queue of active Fault/Error messages surfaced above the log view.
+#[derive(Default)]
+pub struct NotificationBar {
+    messages: VecDeque<Notification>,
+}
+
+impl NotificationBar {
+    fn push(&mut self, text: String, level: NotificationLevel) {
+        if self.messages.iter().any(|m| m.text == text) {
+            return;
+        }
+        self.messages.push_back(Notification { text, level });
+        if self.messages.len() > MAX_NOTIFICATIONS {
+            self.messages.pop_front();
+        }
+    }
+
+    /// Dismiss the front message, along with any other queued copies of the same text.
+    fn dismiss_front(&mut self) {
+        if let Some(front) = self.messages.pop_front() {
+            self.messages.retain(|m| m.text != front.text);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, NotificationLevel)> {
+        self.messages.iter().map(|m| (m.text.as_str(), m.level))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Activity histogram
+// ─────────────────────────────────────────────────────────────
+
+/// How tall the activity histogram drawn above the table is; 0 if there
+/// isn't enough room to show it alongside the table itself.
+pub(super) const HISTOGRAM_HEIGHT: u16 = 3;
+
+/// One column of the logs tab's activity histogram: the time span it
+/// covers and the absolute index of the first entry that falls within it,
+/// so clicking the bar can jump the table straight there.
+#[derive(Clone, Copy)]
+pub struct HistogramBucket {
+    pub start_ts: f64,
+    pub end_ts: f64,
+    /// Entry count, with `Error`/`Fault` weighted higher so a burst of
+    /// failures stands out from routine activity of the same volume.
+    pub weight: u64,
+    pub first_entry_index: Option<usize>,
+}
+
+/// Parses a `LogEntry::full_timestamp` ("2026-01-21 11:23:45.123456+0900")
+/// into Unix epoch seconds, normalizing out the trailing `+ZZZZ`/`-ZZZZ`
+/// offset so timestamps from different zones bucket on the same timeline.
+fn parse_epoch_seconds(full_timestamp: &str) -> Option<f64> {
+    let mut parts = full_timestamp.split_whitespace();
+    let date = parts.next()?;
+    let time_and_offset = parts.next()?;
+
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    // The offset has no separator from the fractional seconds before it
+    // ("...123456+0900"), so find the sign that starts it by scanning from
+    // the end.
+    let offset_pos = time_and_offset.rfind(['+', '-'])?;
+    let time = &time_and_offset[..offset_pos];
+    let offset = &time_and_offset[offset_pos..];
+    if offset.len() < 5 {
+        return None;
+    }
+    let offset_sign: i64 = if offset.starts_with('-') { -1 } else { 1 };
+    let offset_secs = offset_sign * (offset[1..3].parse::<i64>().ok()? * 3600 + offset[3..5].parse::<i64>().ok()? * 60);
+
+    let mut time_parts = time.split(':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    let second: f64 = time_parts.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    let local_secs = days * 86_400 + hour * 3600 + minute * 60;
+    Some(local_secs as f64 + second - offset_secs as f64)
+}
+
+/// Days between the Unix epoch (1970-01-01) and the given proleptic
+/// Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    const DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let leaps_before = |y: i64| y / 4 - y / 100 + y / 400;
+
+    let mut days = (year - 1970) * 365 + leaps_before(year - 1) - leaps_before(1969);
+    days += DAYS_BEFORE_MONTH[(month - 1).clamp(0, 11) as usize];
+    if month > 2 && is_leap {
+        days += 1;
+    }
+    days + (day - 1)
+}
+
 // ─────────────────────────────────────────────────────────────
 // LogsState
 // ─────────────────────────────────────────────────────────────
@@ -233,11 +657,43 @@ pub struct LogsState {
     pub is_initial_loading: bool,
     pub visible_height: usize,
 
+    pub search: SearchState,
+    pub notifications: NotificationBar,
+    pub level_filter: LevelMask,
+
     /// Monotonically increasing counter for absolute entry indexing.
     /// The entry at entries[0] has absolute index = next_entry_index - entries.len().
     next_entry_index: usize,
 
-    event_receiver: Receiver<LogEvent>,
+    /// Live entries that arrive while the initial historical load is still
+    /// streaming in chunks. Historical entries are always older, so these
+    /// are held back and spliced in after the final chunk instead of being
+    /// pushed straight to the back (which would put them out of order).
+    pending_live: Vec<LogEntry>,
+
+    /// Vim-style repeat count accumulated from digit keypresses, applied to
+    /// the next `j`/`k` scroll.
+    pub pending_count: Option<usize>,
+    /// Whether the last key handled was `g`, awaiting a second `g` to jump
+    /// to the top.
+    pub pending_g: bool,
+
+    /// The activity histogram's current buckets, rebuilt by `render` via
+    /// `rebuild_histogram` whenever `histogram_dirty` or the column count
+    /// changes.
+    pub histogram: Vec<HistogramBucket>,
+    histogram_dirty: bool,
+    histogram_columns: usize,
+    /// Where the histogram and the table were last drawn, so
+    /// `handle_mouse_click` can tell which one a click landed in without
+    /// recomputing the tab's layout itself.
+    pub histogram_area: Option<Rect>,
+    pub table_area: Option<Rect>,
+
+    /// Pushes `level_filter`'s current value down to the background log
+    /// query threads whenever it changes, so a hidden level stops being
+    /// fetched rather than only being hidden client-side.
+    level_filter_tx: watch::Sender<LevelFilter>,
 }
 
 impl std::fmt::Debug for LogsState {
@@ -252,22 +708,7 @@ impl std::fmt::Debug for LogsState {
 }
 
 impl LogsState {
-    pub fn new() -> Self {
-        let (event_tx, event_rx) = mpsc::channel();
-
-        // Initial load thread
-        let load_tx = event_tx.clone();
-        std::thread::spawn(move || {
-            let entries = load_all_logs();
-            let _ = load_tx.send(LogEvent::HistoryChunk { entries });
-        });
-
-        // Streaming thread
-        let stream_tx = event_tx;
-        std::thread::spawn(move || {
-            log_service::stream_logs(stream_tx);
-        });
-
+    pub fn new(level_filter_tx: watch::Sender<LevelFilter>) -> Self {
         Self {
             entries: VecDeque::new(),
             scroll_offset: 0,
@@ -275,8 +716,19 @@ impl LogsState {
             line_cache: LineCache::new(),
             is_initial_loading: true,
             visible_height: 0,
+            search: SearchState::default(),
+            notifications: NotificationBar::default(),
+            level_filter: LevelMask::default(),
             next_entry_index: 0,
-            event_receiver: event_rx,
+            pending_live: Vec::new(),
+            pending_count: None,
+            pending_g: false,
+            histogram: Vec::new(),
+            histogram_dirty: true,
+            histogram_columns: 0,
+            histogram_area: None,
+            table_area: None,
+            level_filter_tx,
         }
     }
 
@@ -285,52 +737,70 @@ impl LogsState {
         self.next_entry_index - self.entries.len()
     }
 
-    pub fn process_events(&mut self) {
-        loop {
-            match self.event_receiver.try_recv() {
-                Ok(LogEvent::Live(entry)) => {
-                    let abs_index = self.next_entry_index;
-                    self.entries.push_back(entry);
-                    self.next_entry_index += 1;
-
-                    // Incremental cache update (only if cache is clean and width is known)
-                    if !self.line_cache.dirty && self.line_cache.width > 0 {
-                        self.line_cache.append_entry(
-                            self.entries.back().unwrap(),
-                            abs_index,
-                            self.line_cache.width,
-                        );
-                    } else {
-                        self.line_cache.mark_dirty();
-                    }
+    /// Handle one `LogEvent` pushed from the app-level input channel.
+    pub fn handle_log_event(&mut self, event: LogEvent) {
+        match event {
+            LogEvent::Live(entry) => {
+                if let Some(level) = NotificationLevel::from_log_level(&entry.level) {
+                    self.notifications.push(entry.message.clone(), level);
+                }
 
-                    // Evict oldest entries if over capacity
-                    self.evict_overflow();
+                // The historical load streams in chronological order and
+                // hasn't reached "now" yet, so a live entry arriving mid-load
+                // would land out of order if pushed straight to the back.
+                if self.is_initial_loading {
+                    self.pending_live.push(entry);
+                    return;
+                }
 
-                    if self.auto_scroll {
-                        self.scroll_offset = usize::MAX;
-                    }
+                let abs_index = self.next_entry_index;
+                self.entries.push_back(entry);
+                self.next_entry_index += 1;
+
+                // Incremental cache update (only if cache is clean and width is known)
+                if !self.line_cache.dirty && self.line_cache.width > 0 {
+                    self.line_cache.append_entry(
+                        self.entries.back().unwrap(),
+                        abs_index,
+                        self.line_cache.width,
+                        self.level_filter,
+                    );
+                } else {
+                    self.line_cache.mark_dirty();
                 }
-                Ok(LogEvent::HistoryChunk { entries }) => {
-                    self.merge_initial(entries);
-                    self.is_initial_loading = false;
+
+                // Evict oldest entries if over capacity
+                self.evict_overflow();
+                self.histogram_dirty = true;
+
+                if self.auto_scroll {
+                    self.scroll_offset = usize::MAX;
                 }
-                Err(TryRecvError::Empty) => break,
-                Err(TryRecvError::Disconnected) => break,
+            }
+            LogEvent::HistoryChunk { entries, done } => {
+                self.append_history_chunk(entries, done);
             }
         }
     }
 
-    fn merge_initial(&mut self, historical: Vec<LogEntry>) {
-        let cutoff = historical.last().map(|e| &e.full_timestamp);
-        let live_entries: Vec<LogEntry> = self
-            .entries
-            .drain(..)
-            .filter(|e| cutoff.is_none_or(|ts| e.full_timestamp > *ts))
-            .collect();
+    /// Append one progressively-streamed batch of historical entries.
+    /// `is_loading()` drops to false (and the table starts rendering) as
+    /// soon as the first chunk lands; on the final chunk, any live entries
+    /// that raced ahead of the load during `is_initial_loading` are spliced
+    /// in after it, in the order they were received.
+    fn append_history_chunk(&mut self, chunk: Vec<LogEntry>, done: bool) {
+        for entry in chunk {
+            self.entries.push_back(entry);
+            self.next_entry_index += 1;
+        }
+        self.is_initial_loading = false;
 
-        self.entries = VecDeque::from(historical);
-        self.entries.extend(live_entries);
+        if done {
+            for entry in self.pending_live.drain(..) {
+                self.entries.push_back(entry);
+                self.next_entry_index += 1;
+            }
+        }
 
         // Truncate to MAX_LOG_ENTRIES from the front if needed
         if self.entries.len() > MAX_LOG_ENTRIES {
@@ -338,9 +808,11 @@ impl LogsState {
             self.entries.drain(..excess);
         }
 
-        self.next_entry_index = self.entries.len();
         self.line_cache.mark_dirty();
-        self.scroll_offset = usize::MAX;
+        self.histogram_dirty = true;
+        if self.auto_scroll {
+            self.scroll_offset = usize::MAX;
+        }
     }
 
     /// Evict oldest entries when over MAX_LOG_ENTRIES.
@@ -361,6 +833,18 @@ impl LogsState {
                         self.scroll_offset -= removed_lines;
                     }
                 }
+
+                // Shift search match indices down, dropping any that fell
+                // off the front of the cache.
+                if !self.search.matches.is_empty() {
+                    self.search.matches = self
+                        .search
+                        .matches
+                        .iter()
+                        .filter_map(|line| line.checked_sub(removed_lines))
+                        .collect();
+                    self.search.cursor = self.search.cursor.min(self.search.matches.len().saturating_sub(1));
+                }
             }
         }
     }
@@ -394,12 +878,217 @@ impl LogsState {
     pub fn is_loading(&self) -> bool {
         self.is_initial_loading
     }
-}
 
-// ─────────────────────────────────────────────────────────────
-// Log loading
-// ─────────────────────────────────────────────────────────────
+    /// Dismiss the oldest queued notification (and any duplicates of its text).
+    pub fn dismiss_notification(&mut self) {
+        self.notifications.dismiss_front();
+    }
+
+    /// Toggle visibility of a level class, marking the cache dirty so it's
+    /// rebuilt from the new filtered view on next render, and pushing the
+    /// new filter to the background query threads so the newly-hidden level
+    /// (if any) stops being fetched too.
+    pub fn toggle_level_filter(&mut self, class: LevelClass) {
+        self.level_filter.toggle(class);
+        self.line_cache.mark_dirty();
+        let _ = self.level_filter_tx.send(self.level_filter.to_query_filter());
+    }
+
+    pub fn start_search(&mut self) {
+        self.search.active = true;
+    }
+
+    pub fn cancel_search(&mut self) {
+        self.search = SearchState::default();
+    }
+
+    /// Confirms the current query, leaving matches active for n/N navigation
+    /// while no longer consuming typed characters.
+    pub fn confirm_search(&mut self) {
+        self.search.active = false;
+    }
+
+    pub fn push_search_char(&mut self, c: char) {
+        self.search.query.push(c);
+        self.run_search();
+    }
+
+    pub fn pop_search_char(&mut self) {
+        self.search.query.pop();
+        self.run_search();
+    }
+
+    /// Recompute `search.matches` against the current query and jump the
+    /// viewport to the first match at or after the current scroll position.
+    /// Disables `auto_scroll` so the viewport stays anchored on the match
+    /// instead of snapping back to the bottom as new entries arrive.
+    fn run_search(&mut self) {
+        self.recompute_matches();
+        self.auto_scroll = false;
+        self.scroll_to_current_match();
+    }
 
-fn load_all_logs() -> Vec<LogEntry> {
-    log_service::get_log_history("365d").unwrap_or_default()
+    /// Recompute `search.matches` from the current query against the cached
+    /// line text, without moving the viewport. A line matches if the query
+    /// occurs as a literal case-insensitive substring, or failing that, as a
+    /// fuzzy subsequence (so `"cfgerr"` still finds `"config error"`), with a
+    /// `CharBag` pre-filter keeping the fuzzy fallback cheap. Must be called
+    /// after any `line_cache.rebuild` to keep match indices valid, since a
+    /// width change can change how many lines each entry wraps to.
+    pub fn recompute_matches(&mut self) {
+        if self.search.query.is_empty() {
+            self.search.matches.clear();
+            self.search.cursor = 0;
+            return;
+        }
+
+        let query = self.search.query.to_lowercase();
+        let query_bag = CharBag::of(&query);
+        self.search.matches = self
+            .line_cache
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| {
+                let lower = line.text.to_lowercase();
+                lower.contains(&query)
+                    || (CharBag::of(&lower).contains_all(query_bag)
+                        && fuzzy_subsequence_ranges(&query, &lower).is_some())
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        // First match at or after the current position, wrapping to the
+        // first match overall if the query only matches earlier lines.
+        self.search.cursor = self
+            .search
+            .matches
+            .iter()
+            .position(|&line| line >= self.scroll_offset)
+            .unwrap_or(0);
+    }
+
+    /// Jump to the next match, wrapping around, and scroll it into view.
+    pub fn jump_next_match(&mut self) {
+        if self.search.matches.is_empty() {
+            return;
+        }
+        self.search.cursor = (self.search.cursor + 1) % self.search.matches.len();
+        self.scroll_to_current_match();
+    }
+
+    /// Jump to the previous match, wrapping around, and scroll it into view.
+    pub fn jump_prev_match(&mut self) {
+        if self.search.matches.is_empty() {
+            return;
+        }
+        self.search.cursor = self
+            .search
+            .cursor
+            .checked_sub(1)
+            .unwrap_or(self.search.matches.len() - 1);
+        self.scroll_to_current_match();
+    }
+
+    /// Scroll so the current match is centered in the visible area, using
+    /// the same centering math as `handle_mouse_click`.
+    pub fn scroll_to_current_match(&mut self) {
+        if let Some(line) = self.search.current_match() {
+            let target_offset = line.saturating_sub(self.visible_height / 2);
+            let max_offset = self
+                .line_cache
+                .total_lines()
+                .saturating_sub(self.visible_height);
+            self.scroll_offset = target_offset.min(max_offset);
+            self.auto_scroll = false;
+        }
+    }
+
+    pub fn histogram_needs_rebuild(&self, columns: usize) -> bool {
+        self.histogram_dirty || self.histogram_columns != columns
+    }
+
+    /// Rebuilds the activity histogram from the currently loaded `entries`,
+    /// bucketing their timestamps into `columns` equal time-spans from the
+    /// oldest to the newest timestamp that parses. Collapses to a single
+    /// bucket when every parseable timestamp is equal (a single entry, or a
+    /// burst sharing the exact same timestamp), instead of dividing by a zero
+    /// span. Entries whose timestamp doesn't parse are left out of the
+    /// chart entirely.
+    pub fn rebuild_histogram(&mut self, columns: usize) {
+        self.histogram.clear();
+        self.histogram_dirty = false;
+        self.histogram_columns = columns;
+
+        if columns == 0 {
+            return;
+        }
+
+        let base = self.base_index();
+        let timestamps: Vec<(usize, f64, &str)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| parse_epoch_seconds(&e.full_timestamp).map(|ts| (base + i, ts, e.level.as_str())))
+            .collect();
+
+        let Some(&(_, first_ts, _)) = timestamps.first() else {
+            return;
+        };
+        let min_ts = timestamps.iter().fold(first_ts, |m, &(_, ts, _)| m.min(ts));
+        let max_ts = timestamps.iter().fold(first_ts, |m, &(_, ts, _)| m.max(ts));
+
+        let bucket_count = if max_ts > min_ts { columns } else { 1 };
+        let bucket_span = (max_ts - min_ts) / bucket_count as f64;
+
+        self.histogram = (0..bucket_count)
+            .map(|i| HistogramBucket {
+                start_ts: min_ts + bucket_span * i as f64,
+                end_ts: if i + 1 == bucket_count {
+                    max_ts
+                } else {
+                    min_ts + bucket_span * (i + 1) as f64
+                },
+                weight: 0,
+                first_entry_index: None,
+            })
+            .collect();
+
+        // `timestamps` is already in ascending entry-index order, so the
+        // first entry to land in a bucket is always its earliest.
+        for (entry_index, ts, level) in timestamps {
+            let col = if bucket_span > 0.0 {
+                (((ts - min_ts) / bucket_span) as usize).min(bucket_count - 1)
+            } else {
+                0
+            };
+            let bucket = &mut self.histogram[col];
+            bucket.weight += match level {
+                "Fault" => 3,
+                "Error" => 2,
+                _ => 1,
+            };
+            if bucket.first_entry_index.is_none() {
+                bucket.first_entry_index = Some(entry_index);
+            }
+        }
+    }
+
+    /// Scrolls the table to the first entry of whichever histogram bucket
+    /// covers column `x` of `area` (the rect the histogram was last drawn
+    /// in), as reported by a click on the widget.
+    pub fn scroll_to_histogram_bucket(&mut self, x: u16, area: Rect) {
+        if self.histogram.is_empty() {
+            return;
+        }
+        let col = x.saturating_sub(area.x) as usize;
+        let Some(bucket) = self.histogram.get(col.min(self.histogram.len() - 1)) else {
+            return;
+        };
+        let Some(entry_index) = bucket.first_entry_index else {
+            return;
+        };
+        self.scroll_offset = self.line_cache.first_line_of_entry(entry_index);
+        self.auto_scroll = false;
+    }
 }
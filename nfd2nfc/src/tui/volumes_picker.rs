@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use ratatui::style::Color;
+use ratatui::widgets::ListState;
+
+use nfd2nfc_core::volumes::{list_volumes, probe_unicode_behavior, VolumeInfo, VolumeUnicodeBehavior};
+
+use crate::tui::component::{next_index, prev_index};
+
+/// `UnicodeForm`-style label/color pair for a probed (or still-probing)
+/// volume, used for the badge rendered next to each row.
+pub fn behavior_badge(behavior: Option<VolumeUnicodeBehavior>) -> (&'static str, Color) {
+    match behavior {
+        None => ("…", Color::DarkGray),
+        Some(VolumeUnicodeBehavior::PreservesNfd) => ("NFD", Color::Yellow),
+        Some(VolumeUnicodeBehavior::NormalizesToNfc) => ("NFC", Color::Green),
+        Some(VolumeUnicodeBehavior::Other) => ("Mixed", Color::Magenta),
+        Some(VolumeUnicodeBehavior::Unknown) => ("?", Color::DarkGray),
+    }
+}
+
+/// One row in the volumes/mount-point picker. `behavior` is `None` until
+/// the background probe for this volume completes.
+pub struct VolumeEntry {
+    pub info: VolumeInfo,
+    pub behavior: Option<VolumeUnicodeBehavior>,
+}
+
+/// broot-style `:filesystems` view, specialized for this crate's purpose:
+/// besides the usual mount/type/free-space listing, every volume is probed
+/// by actually writing an NFD-named file to it and reading back whatever
+/// name the filesystem decided to store, which is what explains why NFD
+/// reappears on some mounts (SMB, exFAT) but not others (APFS).
+pub struct VolumesPicker {
+    pub show: bool,
+    pub entries: Vec<VolumeEntry>,
+    pub list_state: ListState,
+    probe_rx: Option<Receiver<(usize, VolumeUnicodeBehavior)>>,
+}
+
+impl VolumesPicker {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            entries: Vec::new(),
+            list_state: ListState::default(),
+            probe_rx: None,
+        }
+    }
+
+    /// List mounted volumes immediately and kick off a background thread
+    /// that probes each one's Unicode behavior in turn; each probe is a
+    /// real write+read round trip (slower still on network mounts), so it
+    /// never runs on the render thread.
+    pub fn open(&mut self) {
+        self.entries = list_volumes()
+            .into_iter()
+            .map(|info| VolumeEntry {
+                info,
+                behavior: None,
+            })
+            .collect();
+        self.list_state
+            .select(if self.entries.is_empty() { None } else { Some(0) });
+        self.show = true;
+
+        let roots: Vec<PathBuf> = self.entries.iter().map(|e| e.info.path.clone()).collect();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (index, root) in roots.into_iter().enumerate() {
+                let behavior = probe_unicode_behavior(&root);
+                if tx.send((index, behavior)).is_err() {
+                    return;
+                }
+            }
+        });
+        self.probe_rx = Some(rx);
+    }
+
+    pub fn close(&mut self) {
+        self.show = false;
+        self.probe_rx = None;
+    }
+
+    /// Drain any probe results that have completed since the last tick.
+    pub fn tick(&mut self) {
+        let Some(rx) = &self.probe_rx else {
+            return;
+        };
+        while let Ok((index, behavior)) = rx.try_recv() {
+            if let Some(entry) = self.entries.get_mut(index) {
+                entry.behavior = Some(behavior);
+            }
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if let Some(i) = next_index(self.list_state.selected(), self.entries.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(i) = prev_index(self.list_state.selected(), self.entries.len()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.info.path.clone())
+    }
+}
+
+impl Default for VolumesPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
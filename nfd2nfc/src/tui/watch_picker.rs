@@ -0,0 +1,397 @@
+//! Interactive PATH picker for `nfd2nfc watch add`, launched when it's
+//! invoked without a path (or with `--pick`) so a deep or Unicode-heavy path
+//! can be built by navigating instead of typed out by hand.
+//!
+//! Reuses the same [`DirBrowser`]-driven incremental fuzzy filtering as the
+//! Config tab's add-path modal (`tabs::config::modal`), plus a
+//! [`VolumesPicker`] overlay (broot's `:filesystems` list) for adding a
+//! mounted volume's root in one step. Runs as its own full-screen mini-app,
+//! the same way `interactive_plan::run` does for the commit-review screen,
+//! since `watch add` is a one-shot CLI invocation with no surrounding `App`.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyCode};
+use crossterm::execute;
+use futures::StreamExt;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::tui::app::events::MouseState;
+use crate::tui::component::{push_count_digit, take_count};
+use crate::tui::dir_browser::{DirBrowser, SelectionKind};
+use crate::tui::inputs::AppEvent;
+use crate::tui::shortcuts::{gap, nav_arrows, shortcut_bracketed, space, ShortcutBlock};
+use crate::tui::tabs::browser::render::name_spans;
+use crate::tui::tabs::config::modal::modal_area;
+use crate::tui::volumes_picker::{behavior_badge, VolumesPicker};
+use nfd2nfc_core::utils::abbreviate_home;
+
+enum PickerView {
+    Browser,
+    Volumes,
+}
+
+struct PickerState {
+    browser: DirBrowser,
+    volumes: VolumesPicker,
+    view: PickerView,
+    chosen: Option<PathBuf>,
+    quit: bool,
+    pending_count: Option<usize>,
+    pending_g: bool,
+    events_rx: tokio::sync::mpsc::UnboundedReceiver<AppEvent>,
+}
+
+impl PickerState {
+    fn new() -> Self {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        Self {
+            browser: DirBrowser::new(tx),
+            volumes: VolumesPicker::new(),
+            view: PickerView::Browser,
+            chosen: None,
+            quit: false,
+            pending_count: None,
+            pending_g: false,
+            events_rx: rx,
+        }
+    }
+
+    fn tick(&mut self) {
+        self.browser.tick(true);
+        self.volumes.tick();
+        while let Ok(event) = self.events_rx.try_recv() {
+            if let AppEvent::DirChanged(path) = event {
+                self.browser.handle_watch_event(path);
+            }
+        }
+    }
+}
+
+/// Runs the picker full-screen, returning the chosen directory, or `None`
+/// if the user cancelled with Esc.
+pub fn pick_path() -> io::Result<Option<PathBuf>> {
+    let mut terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture)?;
+
+    let mut state = PickerState::new();
+    let result = tokio::runtime::Runtime::new()
+        .expect("failed to start the async runtime backing the path picker")
+        .block_on(run_loop(&mut terminal, &mut state));
+
+    execute!(io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result.map(|()| state.chosen)
+}
+
+async fn run_loop(terminal: &mut DefaultTerminal, state: &mut PickerState) -> io::Result<()> {
+    let mut event_stream = EventStream::new();
+    let mut ticker = tokio::time::interval(Duration::from_millis(100));
+
+    loop {
+        terminal.draw(|f| render(state, f))?;
+
+        tokio::select! {
+            maybe_event = event_stream.next() => {
+                if let Some(Ok(Event::Key(key))) = maybe_event {
+                    handle_key(state, key.code);
+                }
+            }
+            _ = ticker.tick() => {}
+        }
+        state.tick();
+
+        if state.quit {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_key(state: &mut PickerState, key: KeyCode) {
+    match state.view {
+        PickerView::Volumes => handle_volumes_key(state, key),
+        PickerView::Browser if state.browser.filter.active => handle_filter_key(state, key),
+        PickerView::Browser => handle_browser_key(state, key),
+    }
+}
+
+fn handle_filter_key(state: &mut PickerState, key: KeyCode) {
+    match key {
+        KeyCode::Char(c) => state.browser.push_filter_char(c),
+        KeyCode::Backspace => state.browser.pop_filter_char(),
+        KeyCode::Enter => state.browser.confirm_filter(),
+        KeyCode::Esc => state.browser.cancel_filter(),
+        _ => {}
+    }
+}
+
+fn handle_browser_key(state: &mut PickerState, key: KeyCode) {
+    if let KeyCode::Char(c) = key {
+        if c.is_ascii_digit() {
+            if push_count_digit(&mut state.pending_count, c.to_digit(10).unwrap()) {
+                state.pending_g = false;
+            }
+            return;
+        }
+        if c == 'g' {
+            if state.pending_g {
+                state.pending_g = false;
+                state.pending_count = None;
+                state.browser.select_first_dir();
+            } else {
+                state.pending_g = true;
+            }
+            return;
+        }
+    }
+
+    // Any other key cancels a pending count or `g`, mirroring the add-path
+    // modal's vim-motion handling.
+    let count = take_count(&mut state.pending_count);
+    state.pending_g = false;
+
+    match key {
+        KeyCode::Esc => state.quit = true,
+        KeyCode::Up | KeyCode::Char('k') => {
+            for _ in 0..count {
+                state.browser.select_previous_dir();
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            for _ in 0..count {
+                state.browser.select_next_dir();
+            }
+        }
+        KeyCode::Char('G') => state.browser.select_last_dir(),
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Backspace => state.browser.go_parent(),
+        KeyCode::Right | KeyCode::Char('l') => state.browser.try_enter_selected(),
+        KeyCode::Char('.') => state.browser.toggle_hidden(),
+        KeyCode::Char('/') => state.browser.start_filter(),
+        KeyCode::Char('v') => {
+            state.volumes.open();
+            state.view = PickerView::Volumes;
+        }
+        KeyCode::Enter => {
+            if state.browser.selection_kind() == SelectionKind::Parent {
+                return;
+            }
+            let path = state
+                .browser
+                .selected_entry()
+                .map(|e| e.path.clone())
+                .unwrap_or_else(|| state.browser.current_dir.clone());
+            state.chosen = Some(path);
+            state.quit = true;
+        }
+        _ => {}
+    }
+}
+
+fn handle_volumes_key(state: &mut PickerState, key: KeyCode) {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => state.volumes.select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => state.volumes.select_next(),
+        KeyCode::Enter => {
+            if let Some(path) = state.volumes.selected_path() {
+                state.chosen = Some(path);
+                state.quit = true;
+            }
+        }
+        KeyCode::Esc | KeyCode::Char('v') => {
+            state.volumes.close();
+            state.view = PickerView::Browser;
+        }
+        _ => {}
+    }
+}
+
+fn render(state: &mut PickerState, f: &mut Frame) {
+    match state.view {
+        PickerView::Browser => render_browser(state, f),
+        PickerView::Volumes => render_volumes(state, f),
+    }
+}
+
+fn render_browser(state: &mut PickerState, f: &mut Frame) {
+    let full_area = f.area();
+    let area = modal_area(full_area);
+
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::Black).fg(Color::White)),
+        area,
+    );
+
+    let is_parent = state.browser.selection_kind() == SelectionKind::Parent;
+    let mut mouse = MouseState::default();
+
+    let mut items: Vec<(Vec<Span>, Option<KeyCode>)> = vec![space()];
+    if !is_parent {
+        items.push(shortcut_bracketed("↵", "Choose", KeyCode::Enter));
+        items.push(gap());
+    }
+    items.extend(nav_arrows());
+    items.extend(vec![
+        gap(),
+        shortcut_bracketed(".", "Hidden", KeyCode::Char('.')),
+        gap(),
+        shortcut_bracketed("/", "Filter", KeyCode::Char('/')),
+        gap(),
+        shortcut_bracketed("v", "Volumes", KeyCode::Char('v')),
+        gap(),
+        shortcut_bracketed("⎋", "Cancel", KeyCode::Esc),
+        space(),
+    ]);
+
+    let inner = ShortcutBlock::new(Line::from(Span::styled(
+        " Choose a Path to Watch ",
+        Style::default().fg(Color::White),
+    )))
+    .items(items)
+    .render(f, area, &mut mouse);
+
+    let current_path = abbreviate_home(
+        &state
+            .browser
+            .selected_entry()
+            .filter(|e| !e.is_parent)
+            .map(|e| e.path.to_string_lossy().to_string())
+            .unwrap_or_else(|| state.browser.current_dir.to_string_lossy().to_string()),
+    );
+
+    let chunks = Layout::vertical([Constraint::Length(3), Constraint::Min(5)]).split(inner);
+
+    let path_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Selected Path ");
+    let path_para = Paragraph::new(Line::from(Span::styled(
+        &current_path,
+        Style::default().fg(Color::Cyan),
+    )))
+    .block(path_block)
+    .wrap(Wrap { trim: false });
+    f.render_widget(path_para, chunks[0]);
+
+    let dir_indices = state.browser.dir_indices();
+    let selected_pos = state
+        .browser
+        .list_state
+        .selected()
+        .and_then(|selected_entry_idx| dir_indices.iter().position(|&i| i == selected_entry_idx));
+    let offset = state.browser.render_offset;
+
+    let items: Vec<ListItem> = state
+        .browser
+        .entries
+        .iter()
+        .filter(|e| e.is_dir)
+        .map(|entry| {
+            if entry.is_parent {
+                ListItem::new(Line::from(vec![
+                    Span::styled(" 📂", Style::default().fg(Color::Yellow)),
+                    Span::styled("..", Style::default().fg(Color::Yellow)),
+                ]))
+            } else {
+                let style = Style::default().fg(Color::White);
+                let mut spans = vec![Span::styled(" 📁", style)];
+                spans.extend(name_spans(&entry.name, style, &state.browser.filter.query));
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let mut dir_title = vec![Span::raw(" Directories ")];
+    if state.browser.filter.active || !state.browser.filter.query.is_empty() {
+        dir_title.push(Span::styled(
+            format!("/{}", state.browser.filter.query),
+            Style::default().fg(Color::Yellow),
+        ));
+        dir_title.push(Span::raw(" "));
+    }
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title(Line::from(dir_title)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut adjusted_state = ratatui::widgets::ListState::default();
+    *adjusted_state.offset_mut() = offset;
+    adjusted_state.select(selected_pos);
+
+    f.render_stateful_widget(list, chunks[1], &mut adjusted_state);
+    state.browser.render_offset = adjusted_state.offset();
+}
+
+/// broot-style `:filesystems` overlay, for adding a mounted volume's root in
+/// one step instead of navigating down to it by hand.
+fn render_volumes(state: &mut PickerState, f: &mut Frame) {
+    let full_area = f.area();
+    let area = modal_area(full_area);
+
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::Black).fg(Color::White)),
+        area,
+    );
+
+    let mut mouse = MouseState::default();
+    let mut items: Vec<(Vec<Span>, Option<KeyCode>)> =
+        vec![space(), shortcut_bracketed("↵", "Add Volume", KeyCode::Enter), gap()];
+    items.extend(nav_arrows());
+    items.push(gap());
+    items.push(shortcut_bracketed("⎋", "Back", KeyCode::Esc));
+    items.push(space());
+
+    let inner = ShortcutBlock::new(Line::from(Span::styled(
+        " Mounted Volumes ",
+        Style::default().fg(Color::White),
+    )))
+    .items(items)
+    .render(f, area, &mut mouse);
+
+    let list_items: Vec<ListItem> = state
+        .volumes
+        .entries
+        .iter()
+        .map(|entry| {
+            let (badge_text, badge_color) = behavior_badge(entry.behavior);
+            let free_gb = entry.info.free_bytes as f64 / 1_073_741_824.0;
+            let total_gb = entry.info.total_bytes as f64 / 1_073_741_824.0;
+
+            let spans = vec![
+                Span::styled(format!("{:<20}", entry.info.name), Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                Span::styled(format!("{:<8}", entry.info.fs_type), Style::default().fg(Color::White)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{:>6.1}/{:<6.1} GB free", free_gb, total_gb),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw("  "),
+                Span::styled(format!("[{}]", badge_text), Style::default().fg(badge_color)),
+            ];
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(Block::default().borders(Borders::ALL).title(" Volumes "))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = state.volumes.list_state;
+    f.render_stateful_widget(list, inner, &mut list_state);
+}
@@ -0,0 +1,72 @@
+use crate::normalizer::{heuristic_normalize_name_to_nfc, heuristic_normalize_name_to_nfd};
+use log::error;
+use notify::event::ModifyKind;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// How long to wait after the last event for a path before normalizing it,
+/// so a file still being written isn't renamed mid-write.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `target_folder` in the foreground and heuristically normalizes
+/// any entry that's created or renamed, until the process is interrupted.
+/// Unlike the `nfd2nfc-watcher` background daemon, this runs inline for the
+/// duration of the command rather than being installed as a service.
+pub fn run(target_folder: &Path, recursive: bool, reverse_mode: bool) {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match RecommendedWatcher::new(tx, notify::Config::default()) {
+        Ok(w) => w,
+        Err(e) => {
+            error!("Failed to initialize file system event watcher: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let mode = if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    if let Err(e) = watcher.watch(target_folder, mode) {
+        error!("Failed to watch {}: {}", target_folder.display(), e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "nfd2nfc: watching {} (recursive: {}). Press Ctrl+C to stop.",
+        target_folder.display(),
+        recursive
+    );
+
+    let mut pending: HashSet<PathBuf> = HashSet::new();
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(Ok(event)) => {
+                if is_rename_or_create(&event.kind) {
+                    pending.extend(event.paths);
+                }
+            }
+            Ok(Err(e)) => error!("FS watcher error: {}", e),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                for path in pending.drain() {
+                    if reverse_mode {
+                        heuristic_normalize_name_to_nfd(&path);
+                    } else {
+                        heuristic_normalize_name_to_nfc(&path);
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn is_rename_or_create(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+    )
+}